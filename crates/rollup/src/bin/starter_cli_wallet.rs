@@ -1,13 +1,25 @@
 //! This binary defines a cli wallet for interacting
 //! with the rollup.
 
+use clap::Parser;
 use rollup_starter::rollup::StarterRollup;
+use rollup_starter::veritas_cli::VeritasCli;
 use sov_modules_api::cli::{FileNameArg, JsonStringArg};
-use sov_modules_rollup_blueprint::WalletBlueprint;
+use sov_modules_rollup_blueprint::{RollupBlueprint, WalletBlueprint};
+use sov_rollup_interface::execution_mode::Native;
 use stf_starter::runtime::RuntimeSubcommand;
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
+    // `veritas ...` builds Veritas call messages from plain arguments
+    if std::env::args().nth(1).as_deref() == Some("veritas") {
+        let cli = VeritasCli::parse_from(std::env::args().skip(1));
+        eprintln!("Call message for module `{}`:", cli.command.module_name());
+        let call = cli.command.call_json::<<StarterRollup<Native> as RollupBlueprint<Native>>::Spec>()?;
+        println!("{}", call);
+        return Ok(());
+    }
+
     StarterRollup::run_wallet::<
         RuntimeSubcommand<FileNameArg, _>,
         RuntimeSubcommand<JsonStringArg, _>,
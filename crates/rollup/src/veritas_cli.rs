@@ -0,0 +1,108 @@
+//! Wallet commands for the Veritas modules with human-friendly arguments
+//!
+//! The generated `RuntimeSubcommand` only imports call messages as JSON, which
+//! means writing fixed-point values by hand. `starter-cli-wallet veritas ...`
+//! takes plain arguments instead and prints the module call message as JSON,
+//! ready for the wallet's regular import commands:
+//!
+//!   starter-cli-wallet veritas register-agent --stake 1000
+//!   starter-cli-wallet veritas submit-belief --belief-id 3 --probability 0.75
+
+use sov_hyperlane_integration::HyperlaneAddress;
+use sov_modules_api::Spec;
+use stf_starter::runtime::RuntimeCall;
+use veritas_belief::{BeliefId, SCALE};
+
+/// `starter-cli-wallet veritas`
+#[derive(Debug, Clone, PartialEq, Eq, clap::Parser)]
+#[command(name = "veritas")]
+pub struct VeritasCli {
+    #[command(subcommand)]
+    pub command: VeritasCommand,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, clap::Subcommand)]
+pub enum VeritasCommand {
+    /// Register the sender as an agent with an initial stake
+    RegisterAgent {
+        #[arg(long)]
+        stake: u64,
+    },
+    /// Submit a probability estimate for a belief
+    SubmitBelief {
+        #[arg(long)]
+        belief_id: BeliefId,
+        /// Probability between 0 and 1, e.g. 0.75 (stored as fixed-point 7500)
+        #[arg(long, value_parser = parse_probability)]
+        probability: u64,
+    },
+}
+
+impl VeritasCommand {
+    /// Runtime field of the module the call goes to
+    pub fn module_name(&self) -> &'static str {
+        match self {
+            Self::RegisterAgent { .. } => "veritas_agent",
+            Self::SubmitBelief { .. } => "veritas_submission",
+        }
+    }
+
+    /// The module call message as JSON
+    pub fn call_json<S: Spec>(&self) -> serde_json::Result<String> {
+        match self {
+            Self::RegisterAgent { stake } => serde_json::to_string(
+                &veritas_agent::CallMessage::<S>::RegisterAgent { initial_stake: *stake },
+            ),
+            Self::SubmitBelief { belief_id, probability } => serde_json::to_string(
+                &veritas_submission::CallMessage::<S>::SubmitBelief { belief_id: *belief_id, value: *probability },
+            ),
+        }
+    }
+
+    /// The call wrapped for the runtime, as it is borsh-encoded into transactions
+    pub fn runtime_call<S: Spec>(&self) -> RuntimeCall<S>
+    where
+        S::Address: HyperlaneAddress,
+    {
+        match self {
+            Self::RegisterAgent { stake } => RuntimeCall::VeritasAgent(
+                veritas_agent::CallMessage::RegisterAgent { initial_stake: *stake },
+            ),
+            Self::SubmitBelief { belief_id, probability } => RuntimeCall::VeritasSubmission(
+                veritas_submission::CallMessage::SubmitBelief { belief_id: *belief_id, value: *probability },
+            ),
+        }
+    }
+}
+
+/// Parses a decimal probability ("0.75", "1", ".5") into SCALE fixed-point
+///
+/// Works on the decimal digits directly, so there is no binary float error:
+/// digits past SCALE's precision round half up ("0.12345" -> 1235).
+/// Anything that isn't between 0 and 1 after rounding is rejected.
+pub fn parse_probability(input: &str) -> Result<u64, String> {
+    let input = input.trim();
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return Err(format!("'{}' is not a decimal probability like 0.75", input));
+    }
+
+    let out_of_range = || format!("Probability {} must be between 0 and 1", input);
+    let whole: u64 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| out_of_range())? };
+
+    // SCALE = 10^precision; one extra digit decides the rounding
+    let precision = SCALE.ilog10() as usize;
+    let padded = format!("{:0<width$}", fraction, width = precision + 1);
+    let units: u64 = padded[..precision].parse().map_err(|_| out_of_range())?;
+    let round_up = padded.as_bytes()[precision] >= b'5';
+
+    let value = whole
+        .saturating_mul(SCALE)
+        .saturating_add(units)
+        .saturating_add(round_up as u64);
+    if value > SCALE {
+        return Err(out_of_range());
+    }
+    Ok(value)
+}
@@ -1,3 +1,5 @@
 mod bank;
+mod genesis;
+mod veritas_cli;
 // Add additional tests here
 mod test_helpers;
@@ -0,0 +1,59 @@
+use super::test_helpers::start_rollup;
+use rollup_starter::rollup::EthSpec;
+use sov_address::EthereumAddress;
+use sov_mock_da::{BlockProducingConfig, MockAddress, MockDaConfig, MockDaSpec};
+use sov_mock_zkvm::MockZkvm;
+use std::str::FromStr;
+use stf_starter::genesis_with_veritas;
+use veritas_submission::VeritasGenesisBuilder;
+
+type TestSpec = EthSpec<MockDaSpec, MockZkvm, MockZkvm>;
+
+const ADMIN: &str = "0xA6edfca3AA985Dd3CC728BFFB700933a986aC085";
+const AGENT: &str = "0x23B6445f524daDee9fb576627740AaD23Afbe8b7";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn combined_genesis_starts_rollup() -> Result<(), anyhow::Error> {
+    let template: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string("../../configs/mock/genesis.json")?)?;
+
+    let admin = EthereumAddress::from_str(ADMIN)?;
+    let veritas = VeritasGenesisBuilder::<TestSpec>::new()
+        .with_admin(admin)
+        .with_agent(EthereumAddress::from_str(AGENT)?, 1000, 100)
+        .with_belief("Will ETH exceed $5000?", 5000)
+        .build();
+
+    let genesis = genesis_with_veritas::<TestSpec>(template, veritas)?;
+    assert_eq!(genesis.veritas_belief.admin, Some(admin));
+    assert_eq!(genesis.veritas_belief.initial_beliefs.len(), 1);
+    assert_eq!(genesis.veritas_agent.initial_agents.len(), 1);
+
+    let genesis_dir = tempfile::tempdir()?;
+    let genesis_path = genesis_dir.path().join("genesis.json");
+    std::fs::write(&genesis_path, serde_json::to_vec_pretty(&genesis)?)?;
+
+    let (rest_port_tx, rest_port_rx) = tokio::sync::oneshot::channel();
+    let rollup_task = tokio::spawn(async move {
+        start_rollup(
+            rest_port_tx,
+            genesis_path,
+            None,
+            MockDaConfig {
+                connection_string: MockDaConfig::sqlite_in_memory(),
+                sender_address: MockAddress::new([0; 32]),
+                finalization_blocks: 3,
+                block_producing: BlockProducingConfig::Periodic { block_time_ms: 300 },
+                da_layer: None,
+                randomization: None,
+            },
+        )
+        .await;
+    });
+
+    // The REST server only comes up after genesis was applied successfully
+    rest_port_rx.await?;
+    rollup_task.abort();
+    genesis_dir.close()?;
+    Ok(())
+}
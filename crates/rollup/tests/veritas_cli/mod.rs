@@ -0,0 +1,46 @@
+use clap::Parser;
+use rollup_starter::rollup::EthSpec;
+use rollup_starter::veritas_cli::{parse_probability, VeritasCli, VeritasCommand};
+use sov_mock_da::MockDaSpec;
+use sov_mock_zkvm::MockZkvm;
+use stf_starter::runtime::RuntimeCall;
+
+type TestSpec = EthSpec<MockDaSpec, MockZkvm, MockZkvm>;
+
+#[test]
+fn submit_belief_encodes_fixed_point_probability() {
+    let cli = VeritasCli::try_parse_from([
+        "veritas", "submit-belief", "--belief-id", "3", "--probability", "0.75",
+    ])
+    .unwrap();
+    assert_eq!(cli.command, VeritasCommand::SubmitBelief { belief_id: 3, probability: 7500 });
+    assert_eq!(cli.command.module_name(), "veritas_submission");
+    assert_eq!(
+        cli.command.call_json::<TestSpec>().unwrap(),
+        r#"{"submit_belief":{"belief_id":3,"value":7500}}"#
+    );
+
+    // Module call: variant 0 (SubmitBelief), then belief_id and value as little-endian u64s
+    let message = veritas_submission::CallMessage::<TestSpec>::SubmitBelief { belief_id: 3, value: 7500 };
+    let expected: Vec<u8> = [vec![0u8], 3u64.to_le_bytes().to_vec(), 7500u64.to_le_bytes().to_vec()].concat();
+    assert_eq!(borsh::to_vec(&message).unwrap(), expected);
+
+    // The runtime prefixes the module's variant index and decodes back to the same call
+    let encoded = borsh::to_vec(&cli.command.runtime_call::<TestSpec>()).unwrap();
+    assert_eq!(encoded.len(), expected.len() + 1);
+    assert!(encoded.ends_with(&expected));
+    match borsh::from_slice::<RuntimeCall<TestSpec>>(&encoded).unwrap() {
+        RuntimeCall::VeritasSubmission(decoded) => assert_eq!(decoded, message),
+        _ => panic!("Decoded into the wrong module"),
+    }
+}
+
+#[test]
+fn probability_parsing_rounds_and_validates() {
+    for (input, expected) in [("0", 0), ("1", 10000), ("1.0", 10000), (".5", 5000), ("0.12344", 1234), ("0.12345", 1235)] {
+        assert_eq!(parse_probability(input), Ok(expected), "{}", input);
+    }
+    for input in ["", ".", "1.5", "1.00005", "-0.1", "0.7a", "75%", "1e-3"] {
+        assert!(parse_probability(input).is_err(), "{}", input);
+    }
+}
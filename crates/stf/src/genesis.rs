@@ -0,0 +1,43 @@
+//! Programmatic construction of the full runtime genesis.
+//!
+//! The runtime's `GenesisConfig` has one section per module and every section
+//! must be present and well-formed for the node to start. This helper takes a
+//! complete genesis JSON (e.g. `configs/mock/genesis.json`, which sets up bank,
+//! accounts and the other standard modules) as a template and replaces its
+//! Veritas sections with ones produced by `VeritasGenesisBuilder`, so the three
+//! Veritas configs always agree with each other.
+use anyhow::{bail, Context};
+use sov_hyperlane_integration::HyperlaneAddress;
+use sov_modules_api::Spec;
+use veritas_submission::VeritasGenesisConfig;
+
+use crate::GenesisConfig;
+
+/// Merges `veritas` into a genesis `template` and validates the result against the runtime.
+///
+/// `value_setter` takes no configuration; it is added as `null` if the template omits it.
+pub fn genesis_with_veritas<S>(
+    template: serde_json::Value,
+    veritas: VeritasGenesisConfig<S>,
+) -> anyhow::Result<GenesisConfig<S>>
+where
+    S: Spec,
+    S::Address: HyperlaneAddress + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let serde_json::Value::Object(mut sections) = template else {
+        bail!("Genesis template must be a JSON object");
+    };
+
+    sections.insert("veritas_agent".to_string(), serde_json::to_value(&veritas.agent)?);
+    sections.insert("veritas_belief".to_string(), serde_json::to_value(&veritas.belief)?);
+    sections.insert(
+        "veritas_submission".to_string(),
+        serde_json::to_value(&veritas.submission)?,
+    );
+    sections
+        .entry("value_setter")
+        .or_insert(serde_json::Value::Null);
+
+    serde_json::from_value(serde_json::Value::Object(sections))
+        .context("Combined genesis does not match the runtime's GenesisConfig")
+}
@@ -1,8 +1,12 @@
 //! The rollup State Transition Function.
 
 mod delegation;
+#[cfg(feature = "native")]
+mod genesis;
 pub mod runtime;
 
+#[cfg(feature = "native")]
+pub use genesis::*;
 pub use runtime::*;
 use sov_modules_stf_blueprint::StfBlueprint;
 use sov_rollup_interface::stf::StateTransitionVerifier;
@@ -0,0 +1,199 @@
+//! Delegation - passive holders backing active agents
+//!
+//! FILE PURPOSE:
+//! Registered agents that don't want to predict themselves can delegate part
+//! of their stake to another agent. Delegated stake adds to the delegate's own
+//! stake wherever weight is computed (`Agent::weight`), so it buys the delegate
+//! influence without handing it the stake.
+//!
+//! HOW IT WORKS:
+//! - Delegating moves the amount out of the delegator's own stake (and weight)
+//!   into its `Agent::delegated_out`; it leaves like a withdrawal: not during
+//!   the withdrawal cooldown, never below locked stake or `min_stake`, but it
+//!   may lend out everything
+//! - `delegations` records every delegator → delegate amount; the delegate's
+//!   total is kept on the agent (`Agent::delegated`) so weights stay one read
+//! - `delegators` indexes each delegate's delegators, so all of a delegate's
+//!   delegations can be found without scanning `delegations`
+//! - Undelegating moves the amount back into the delegator's own stake and
+//!   lowers the delegate's weight by it
+//! - An agent with stake delegated out can't deregister, so there is always an
+//!   agent to give it back to
+//! - An agent that deregisters first moves every delegation it holds back into
+//!   its delegator's own stake, with a StakeUndelegated event each
+//! - Like an agent's own stake, delegated stake is accounted here and not
+//!   moved through the bank; `total_stake` counts it once, on the delegator's side
+//!
+//! Delegated stake is not covered by stake locks or slashing, which only
+//! apply to an agent's own stake.
+
+use anyhow::{bail, Result};
+use sov_modules_api::{Context, EventEmitter, Spec, TxState};
+
+use crate::{Agent, AgentError, AgentModule, Event, StakeChange};
+
+impl<S: Spec> AgentModule<S> {
+    /// Moves `amount` of the sender's own stake into a delegation to the registered agent `to`
+    pub fn delegate_stake(
+        &mut self,
+        to: S::Address,
+        amount: u64,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let sender = context.sender();
+        if amount == 0 {
+            bail!("Delegated amount must be greater than zero");
+        }
+        if *sender == to {
+            bail!("Agents can't delegate to themselves; add stake instead");
+        }
+        let delegator = self.agents.get(sender, state)?.ok_or(AgentError::NotRegistered)?;
+        let delegate = self.agents.get(&to, state)?
+            .ok_or_else(|| anyhow::anyhow!("Can't delegate to an unregistered agent"))?;
+
+        let remaining = delegator.stake_after(StakeChange::Remove(amount))?;
+        let new_delegated = delegate.delegated.checked_add(amount).ok_or(AgentError::StakeOverflow)?;
+        self.ensure_withdrawal_cooldown_over(sender, state)?;
+        let locked = self.locked_stake(sender, state)?;
+        if remaining < locked {
+            bail!("Stake is locked: {} of {} backs open submissions", locked, delegator.stake);
+        }
+        let min_stake = self.min_stake.get(state)?.unwrap_or(0);
+        if remaining > 0 && remaining < min_stake {
+            bail!(
+                "Delegation would leave {} staked, below the minimum of {}; delegate everything or less",
+                remaining,
+                min_stake
+            );
+        }
+
+        let key = (sender.clone(), to.clone());
+        let delegation = self.delegations.get(&key, state)?.unwrap_or(0);
+        self.delegations.set(&key, &delegation.saturating_add(amount), state)?;
+        if delegation == 0 {
+            let mut delegators = self.delegators.get(&to, state)?.unwrap_or_default();
+            delegators.push(sender.clone());
+            self.delegators.set(&to, &delegators, state)?;
+        }
+
+        let updated = Agent {
+            stake: remaining,
+            delegated_out: delegator.delegated_out.saturating_add(amount),
+            ..delegator.clone()
+        };
+        self.save_agent(sender, Some(&delegator), updated, state)?;
+
+        let updated = Agent { delegated: new_delegated, ..delegate.clone() };
+        self.save_agent(&to, Some(&delegate), updated, state)?;
+
+        self.emit_event(
+            state,
+            Event::StakeDelegated { delegator: sender.clone(), delegate: to, amount, new_delegated },
+        );
+
+        Ok(())
+    }
+
+    /// Moves `amount` of the sender's stake delegated to `from` back into its own stake
+    pub fn undelegate_stake(
+        &mut self,
+        from: S::Address,
+        amount: u64,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let sender = context.sender();
+        let key = (sender.clone(), from.clone());
+        let delegation = self.delegations.get(&key, state)?.unwrap_or(0);
+        if amount == 0 || amount > delegation {
+            bail!("Can't undelegate {}: {} is delegated to this agent", amount, delegation);
+        }
+        // Agents with stake delegated out can't deregister, so the delegator is still here
+        let delegator = self.agents.get(sender, state)?.ok_or(AgentError::NotRegistered)?;
+        let updated = Agent {
+            stake: delegator.stake_after(StakeChange::Add(amount))?,
+            delegated_out: delegator.delegated_out.saturating_sub(amount),
+            ..delegator.clone()
+        };
+        self.save_agent(sender, Some(&delegator), updated, state)?;
+
+        let remaining = delegation - amount;
+        if remaining == 0 {
+            self.delegations.delete(&key, state)?;
+            let mut delegators = self.delegators.get(&from, state)?.unwrap_or_default();
+            delegators.retain(|delegator| delegator != sender);
+            if delegators.is_empty() {
+                self.delegators.delete(&from, state)?;
+            } else {
+                self.delegators.set(&from, &delegators, state)?;
+            }
+        } else {
+            self.delegations.set(&key, &remaining, state)?;
+        }
+
+        // A deregistered delegate has no weight left to lower
+        let mut new_delegated = 0;
+        if let Some(delegate) = self.agents.get(&from, state)? {
+            let updated = Agent {
+                delegated: delegate.delegated.saturating_sub(amount),
+                ..delegate.clone()
+            };
+            new_delegated = updated.delegated;
+            self.save_agent(&from, Some(&delegate), updated, state)?;
+        }
+
+        self.emit_event(
+            state,
+            Event::StakeUndelegated { delegator: sender.clone(), delegate: from, amount, new_delegated },
+        );
+
+        Ok(())
+    }
+
+    /// Moves every delegation to `delegate` back into its delegator's own stake,
+    /// for a deregistering agent
+    ///
+    /// Leaves the agent's `delegated` total alone: the agent is about to be removed.
+    /// Returns the total amount given back.
+    pub(crate) fn return_delegations(
+        &mut self,
+        delegate: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let mut returned = 0u64;
+        for delegator in self.delegators.get(delegate, state)?.unwrap_or_default() {
+            let key = (delegator.clone(), delegate.clone());
+            let amount = self.delegations.get(&key, state)?.unwrap_or(0);
+            self.delegations.delete(&key, state)?;
+            returned = returned.saturating_add(amount);
+
+            // Agents with stake delegated out can't deregister, so the delegator is still here
+            let owner = self.agents.get(&delegator, state)?.ok_or(AgentError::NotRegistered)?;
+            let updated = Agent {
+                stake: owner.stake_after(StakeChange::Add(amount))?,
+                delegated_out: owner.delegated_out.saturating_sub(amount),
+                ..owner.clone()
+            };
+            self.save_agent(&delegator, Some(&owner), updated, state)?;
+
+            self.emit_event(
+                state,
+                Event::StakeUndelegated { delegator, delegate: delegate.clone(), amount, new_delegated: 0 },
+            );
+        }
+        self.delegators.delete(delegate, state)?;
+
+        Ok(returned)
+    }
+
+    /// Stake `delegator` currently delegates to `delegate` (0 if none)
+    pub fn get_delegation(
+        &self,
+        delegator: &S::Address,
+        delegate: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        Ok(self.delegations.get(&(delegator.clone(), delegate.clone()), state)?.unwrap_or(0))
+    }
+}
@@ -0,0 +1,41 @@
+//! Typed errors returned by AgentModule
+//!
+//! Surfaced as `anyhow::Error` like every other module error; callers recover
+//! the exact kind with `err.downcast_ref::<AgentError>()` (SubmissionModule
+//! maps them onto its own `SubmissionError`).
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AgentError {
+    /// The address never registered, or deregistered since
+    NotRegistered,
+
+    /// The agent is registered but has no stake (own or delegated) left, e.g. after slashing
+    ZeroStake,
+
+    /// The agent is registered but its score is 0
+    ZeroScore,
+
+    /// Adding stake would take the agent's stake past u64::MAX
+    StakeOverflow,
+
+    /// The agent tried to take out more stake than it has
+    InsufficientStake { stake: u64, requested: u64 },
+}
+
+impl fmt::Display for AgentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AgentError::NotRegistered => write!(f, "Agent not registered"),
+            AgentError::ZeroStake => write!(f, "Agent has no stake, so its weight is 0"),
+            AgentError::ZeroScore => write!(f, "Agent has a score of 0, so its weight is 0"),
+            AgentError::StakeOverflow => write!(f, "Stake would exceed the maximum of {}", u64::MAX),
+            AgentError::InsufficientStake { stake, requested } => {
+                write!(f, "Insufficient stake balance: {} staked, {} requested", stake, requested)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AgentError {}
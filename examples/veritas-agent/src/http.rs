@@ -0,0 +1,73 @@
+//! Custom REST endpoints for AgentModule
+//!
+//! Served next to the auto-generated state API (`ModuleRestApi`).
+//!
+//! - `GET /agents?offset=&limit=`: a page of registered agents in registration
+//!   order (see `AgentModule::list_agents`)
+//! - `GET /agents/{address}/profile`: stake, score and weight in one response
+//!   (see `AgentModule::get_agent_profile`), 404 for unregistered addresses
+//!
+//! Native-only: the zk guest never serves HTTP.
+
+use axum::extract::Query;
+use axum::routing::get;
+use sov_modules_api::prelude::utoipa::openapi::OpenApi;
+use sov_modules_api::rest::utils::{errors, ApiResult, Path};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::{ApiStateAccessor, Spec};
+
+use crate::{AgentModule, AgentPage, AgentProfile, MAX_AGENT_PAGE_SIZE};
+
+#[derive(serde::Deserialize)]
+struct PageParams {
+    #[serde(default)]
+    offset: u64,
+    limit: Option<u64>,
+}
+
+impl<S: Spec> AgentModule<S> {
+    async fn route_agents(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Query(params): Query<PageParams>,
+    ) -> ApiResult<AgentPage<S>> {
+        let limit = params.limit.unwrap_or(MAX_AGENT_PAGE_SIZE).min(MAX_AGENT_PAGE_SIZE);
+        let agents = state
+            .list_agents(params.offset, limit, &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?;
+        let total = state
+            .agent_index
+            .len(&mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?;
+
+        Ok(AgentPage { total, offset: params.offset, limit, agents }.into())
+    }
+
+    async fn route_profile(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Path(address): Path<S::Address>,
+    ) -> ApiResult<AgentProfile<S>> {
+        let profile = state
+            .get_agent_profile(&address, &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?
+            .ok_or_else(|| errors::not_found_404("Agent", address))?;
+
+        Ok(profile.into())
+    }
+}
+
+impl<S: Spec> HasCustomRestApi for AgentModule<S> {
+    type Spec = S;
+
+    fn custom_rest_api(&self, state: ApiState<S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route("/agents", get(Self::route_agents))
+            .route("/agents/:address/profile", get(Self::route_profile))
+            .with_state(state.with(self.clone()))
+    }
+
+    fn custom_openapi_spec(&self) -> Option<OpenApi> {
+        None
+    }
+}
@@ -14,6 +14,31 @@
 //! - Implements Module trait for Sovereign SDK integration
 //! - Uses StateMap for persistent storage of agents
 //! - Exposes register/stake management via CallMessage
+//! - Optional stake locks on participation against stake cycling (see stake_lock.rs)
+//! - Configurable initial score as a function of initial stake
+//! - Tracks the total registered weight (sum of stake × score over all agents)
+//! - Assigns agent tiers from score thresholds, with admin override (see tiers.rs)
+//! - `update_score` only accepts calls from the registered score updater module
+//! - Optional square-root stake weighting against whale dominance (see weighting.rs)
+//! - Optional withdrawal cooldown after each submission (see stake_lock.rs)
+//! - Emits events for registrations, stake changes and score updates
+//! - Scores are capped at a configurable `max_score`, bounding weight at stake × max_score
+//! - Added an agent profile query (stake, score, weight), also served over REST (see http.rs)
+//! - Configurable minimum stake; withdrawing the whole stake deregisters the agent
+//! - Added `slash_agent` for stake penalties, collected in `slashed_pool`
+//! - Maintains agent count and total stake counters for system stats
+//! - Holders can delegate stake to registered agents, adding to their weight (see delegation.rs)
+//! - Added paginated agent listing over the registration index, also served as `GET /agents`
+//! - `get_weight` fails with a typed `AgentError` telling unregistered, zero-stake and zero-score agents apart
+//! - Agents can transfer own stake to other registered agents (see transfer.rs)
+//! - Stake additions and removals share `Agent::stake_after`, failing with StakeOverflow / InsufficientStake
+//! - Every "Agent not registered" failure is a typed `AgentError::NotRegistered`
+//! - Optional genesis `ignore_score`: weight by stake alone while reputation bootstraps (see weighting.rs)
+//! - Genesis rejects agents without stake, with a score outside [1, max_score] or listed twice
+//! - Deregistering returns every delegation to its delegator instead of being refused (see delegation.rs)
+//! - Delegating now moves stake out of the delegator's own stake, which must be registered and cover it
+//! - Admin tier overrides are reachable on-chain through `SetTierOverride`
+//! - Transferring the whole stake deregisters the sender, like withdrawing it
 //!
 //! This module handles:
 //! - Agent registration with initial stake
@@ -23,17 +48,85 @@
 //!
 //! Each agent has:
 //! - stake: Amount of tokens locked (influences voting power)
-//! - score: Reputation score (starts at 100, increases with accurate predictions)
+//! - score: Reputation score (starts at 100 by default, increases with accurate predictions)
 
 #![allow(unused_imports)]
 use anyhow::{bail, Result};
 use schemars::JsonSchema;
 use sov_modules_api::macros::{serialize, UniversalWallet};
 use sov_modules_api::{
-    Context, Module, ModuleId, ModuleInfo, ModuleRestApi, Spec,
-    StateMap, TxState,
+    Context, EventEmitter, Module, ModuleId, ModuleInfo, ModuleRestApi, Spec,
+    StateMap, StateValue, StateVec, TxState,
 };
 use std::marker::PhantomData;
+use veritas_belief::{CallPermission, CallPermissionEntry};
+
+pub use veritas_belief::AgentTier;
+
+#[cfg(feature = "native")]
+mod http;
+
+mod delegation;
+pub use delegation::*;
+
+mod error;
+pub use error::*;
+
+mod stake_lock;
+pub use stake_lock::*;
+
+mod tiers;
+pub use tiers::*;
+
+mod transfer;
+pub use transfer::*;
+
+mod weighting;
+pub use weighting::*;
+
+/// Fixed-point scale used for averaged scores (same convention as veritas_belief::SCALE)
+/// A mean score of 123.4567 is returned as 1234567
+pub const SCORE_SCALE: u64 = 10000;
+
+/// Score ceiling when genesis doesn't configure one
+pub const DEFAULT_MAX_SCORE: u64 = 10000;
+
+fn default_max_score() -> u64 {
+    DEFAULT_MAX_SCORE
+}
+
+/// Upper bound on agents visited by a single full-index scan
+/// Keeps dashboard queries from degrading unboundedly as the agent set grows
+pub const MAX_AGENT_SCAN: u64 = 10_000;
+
+/// Largest page `list_agents` returns; bigger requests are clamped to it
+pub const MAX_AGENT_PAGE_SIZE: u64 = 100;
+
+/// Checks genesis agents: a stake, a score in [1, max_score] and a unique address each
+///
+/// Fails on the first bad entry, naming its position and address, instead of
+/// letting genesis start with agents that can never carry weight.
+pub fn validate_genesis_agents<A>(agents: &[(A, Agent)], max_score: u64) -> Result<()>
+where
+    A: Eq + std::hash::Hash + std::fmt::Display,
+{
+    let mut seen = std::collections::HashSet::new();
+    for (index, (address, agent)) in agents.iter().enumerate() {
+        if agent.stake == 0 {
+            bail!("Genesis agent #{} ({}) has no stake", index, address);
+        }
+        if agent.score == 0 || agent.score > max_score {
+            bail!(
+                "Genesis agent #{} ({}) has score {}, outside [1, {}]",
+                index, address, agent.score, max_score
+            );
+        }
+        if !seen.insert(address) {
+            bail!("Genesis agent #{} ({}) is listed more than once", index, address);
+        }
+    }
+    Ok(())
+}
 
 /// Agent represents a participant in the belief aggregation system
 /// The agent's influence on belief aggregation is determined by stake × score
@@ -46,6 +139,109 @@ pub struct Agent {
     /// Reputation score (starts at 100)
     /// Increases when agent's predictions are close to consensus
     pub score: u64,
+
+    /// Reputation tier, derived from score unless pinned by the admin
+    #[serde(default)]
+    pub tier: AgentTier,
+
+    /// Whether `tier` was set by the admin rather than by score thresholds
+    #[serde(default)]
+    pub tier_overridden: bool,
+
+    /// Stake delegated to this agent by others (see delegation.rs)
+    /// Counts towards weight like the agent's own stake
+    #[serde(default)]
+    pub delegated: u64,
+
+    /// Own stake this agent has delegated to others (see delegation.rs)
+    /// Still the agent's, but no longer part of `stake` or its weight
+    #[serde(default)]
+    pub delegated_out: u64,
+}
+
+impl Agent {
+    pub fn new(stake: u64, score: u64) -> Self {
+        Self {
+            stake,
+            score,
+            tier: AgentTier::default(),
+            tier_overridden: false,
+            delegated: 0,
+            delegated_out: 0,
+        }
+    }
+
+    /// Stake the agent owns: its own stake plus what it delegated out
+    /// (what `total_stake` sums)
+    pub fn owned_stake(&self) -> u128 {
+        self.stake as u128 + self.delegated_out as u128
+    }
+
+    /// Influence on belief aggregation: stake × score (saturating)
+    /// Delegated stake counts as stake here
+    /// Under `StakeWeighting::Sqrt` the stake counts as isqrt(stake); with
+    /// `ignore_score` the score counts as 1 (see weighting.rs)
+    pub fn weight(&self, rule: WeightRule) -> u64 {
+        self.weight_for_stake(self.stake.saturating_add(self.delegated), rule)
+    }
+
+    /// Influence when only `amount` of the stake backs a submission (saturating)
+    pub fn weight_for_stake(&self, amount: u64, rule: WeightRule) -> u64 {
+        rule.weight(amount, self.score)
+    }
+
+    /// The agent's own stake after `change`, checked instead of saturating
+    ///
+    /// Every path that moves own stake in or out goes through here, so a
+    /// stake can neither silently cap at u64::MAX nor go below zero.
+    pub fn stake_after(&self, change: StakeChange) -> Result<u64, AgentError> {
+        match change {
+            StakeChange::Add(amount) => self.stake.checked_add(amount).ok_or(AgentError::StakeOverflow),
+            StakeChange::Remove(amount) => self.stake.checked_sub(amount)
+                .ok_or(AgentError::InsufficientStake { stake: self.stake, requested: amount }),
+        }
+    }
+}
+
+/// A change to an agent's own stake, see `Agent::stake_after`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StakeChange {
+    Add(u64),
+    Remove(u64),
+}
+
+/// How a registering agent's initial score is derived from its initial stake
+///
+/// Lets deployments ramp influence with commitment instead of starting
+/// everyone at the same score. The log curve keeps the advantage modest:
+/// doubling the stake adds a fixed number of points, up to a ceiling.
+#[derive(Debug, Clone, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InitialScorePolicy {
+    /// Every agent starts with the same score
+    Flat { score: u64 },
+    /// score = base + per_doubling × floor(log2(initial_stake)), at most max_score
+    CappedLog { base: u64, per_doubling: u64, max_score: u64 },
+}
+
+impl Default for InitialScorePolicy {
+    fn default() -> Self {
+        Self::Flat { score: 100 }
+    }
+}
+
+impl InitialScorePolicy {
+    /// Initial score for an agent registering with `initial_stake` (must be > 0)
+    pub fn score_for(&self, initial_stake: u64) -> u64 {
+        match self {
+            Self::Flat { score } => *score,
+            Self::CappedLog { base, per_doubling, max_score } => {
+                let doublings = initial_stake.max(1).ilog2() as u64;
+                base.saturating_add(per_doubling.saturating_mul(doublings))
+                    .min(*max_score)
+            }
+        }
+    }
 }
 
 /// AgentModule manages all agents in the system
@@ -65,6 +261,94 @@ pub struct AgentModule<S: Spec> {
     #[state]
     pub agents: StateMap<S::Address, Agent>,
 
+    /// Append-only list of every registered address, in registration order
+    /// StateMap can't be iterated, so this index enables scans over all agents
+    #[state]
+    pub agent_index: StateVec<S::Address>,
+
+    /// Stake lock settings (see stake_lock.rs), disabled by default
+    #[state]
+    pub stake_lock: StateValue<StakeLockConfig>,
+
+    /// Initial score policy for newly registering agents
+    #[state]
+    pub initial_score: StateValue<InitialScorePolicy>,
+
+    /// Sum of every registered agent's weight (stake × score)
+    /// Kept up to date on registration, stake and score changes
+    #[state]
+    pub total_registered_weight: StateValue<u128>,
+
+    /// Number of currently registered agents
+    /// Kept up to date on registration and deregistration
+    #[state]
+    pub total_agents: StateValue<u64>,
+
+    /// Sum of every registered agent's stake
+    /// Kept up to date on registration, stake changes, slashing and deregistration
+    #[state]
+    pub total_stake: StateValue<u128>,
+
+    /// How stake scales into weight (see weighting.rs), linear by default
+    #[state]
+    pub stake_weighting: StateValue<StakeWeighting>,
+
+    /// Whether weight ignores the score, i.e. pure stake voting (see weighting.rs)
+    #[state]
+    pub ignore_score: StateValue<bool>,
+
+    /// Score thresholds for automatic tier assignment (see tiers.rs)
+    #[state]
+    pub tier_thresholds: StateValue<TierThresholds>,
+
+    /// Stake committed by each agent to the beliefs it submitted to
+    /// NOTE: grows with the number of open beliefs the agent participates in
+    #[state]
+    pub stake_commitments: StateMap<S::Address, Vec<StakeCommitment>>,
+
+    /// Blocks a withdrawal cooldown lasts after each submission (0 = disabled)
+    #[state]
+    pub withdrawal_cooldown: StateValue<u64>,
+
+    /// Block height from which each agent may withdraw again (see stake_lock.rs)
+    #[state]
+    pub locked_until: StateMap<S::Address, u64>,
+
+    /// Smallest stake a registered agent may hold (0 = any non-zero stake)
+    #[state]
+    pub min_stake: StateValue<u64>,
+
+    /// Stake delegated by each (delegator, delegate) pair (see delegation.rs)
+    #[state]
+    pub delegations: StateMap<(S::Address, S::Address), u64>,
+
+    /// Everyone delegating to each delegate, in delegation order (see delegation.rs)
+    #[state]
+    pub delegators: StateMap<S::Address, Vec<S::Address>>,
+
+    /// Agents that withdrew their whole stake and were removed from `agents`
+    /// Their `agent_index` entry stays, so re-registering must not add another
+    #[state]
+    pub deregistered: StateMap<S::Address, bool>,
+
+    /// Highest score any agent can reach (registration and `update_score` clamp to it)
+    #[state]
+    pub max_score: StateValue<u64>,
+
+    /// Stake removed by `slash_agent` so far, held for later redistribution
+    #[state]
+    pub slashed_pool: StateValue<u64>,
+
+    /// The only module allowed to call `update_score` and `slash_agent` (SubmissionModule)
+    /// Registered by that module's genesis; unset means no one may change scores
+    #[state]
+    pub score_updater: StateValue<ModuleId>,
+
+    /// Reference to BeliefModule, to release locks once a belief closes
+    /// and to read the current block height
+    #[module]
+    pub belief_module: veritas_belief::BeliefModule<S>,
+
     /// PhantomData is needed to satisfy Rust's type system
     /// since we use generic type S but don't store it directly
     #[phantom]
@@ -85,10 +369,10 @@ impl<S: Spec> Module for AgentModule<S> {
     type Config = GenesisConfig<S>;
     
     /// Enum of all possible transactions this module can process
-    type CallMessage = CallMessage;
+    type CallMessage = CallMessage<S>;
     
-    /// Events emitted by this module (we're not using events currently)
-    type Event = ();
+    /// Events emitted by this module, so indexers can follow agents without polling state
+    type Event = Event<S>;
 
     /// Initialize the module's state from genesis configuration
     fn genesis(
@@ -97,10 +381,35 @@ impl<S: Spec> Module for AgentModule<S> {
         config: &Self::Config,
         state: &mut impl sov_modules_api::GenesisState<S>,
     ) -> Result<()> {
-        // Initialize agents from genesis config
+        // Initialize agents from genesis config, rejecting bad entries up front
+        validate_genesis_agents(&config.initial_agents, config.max_score)?;
+        let rule = WeightRule { weighting: config.stake_weighting, ignore_score: config.ignore_score };
+        let mut total_weight: u128 = 0;
+        let mut total_stake: u128 = 0;
         for (address, agent) in &config.initial_agents {
-            self.agents.set(address, agent, state)?;
+            let mut agent = agent.clone();
+            // Genesis has no delegations to back a delegated amount
+            agent.delegated = 0;
+            agent.delegated_out = 0;
+            if !agent.tier_overridden {
+                agent.tier = config.tier_thresholds.tier_for(agent.score);
+            }
+            self.agents.set(address, &agent, state)?;
+            self.agent_index.push(address, state)?;
+            total_weight += agent.weight(rule) as u128;
+            total_stake += agent.owned_stake();
         }
+        self.total_registered_weight.set(&total_weight, state)?;
+        self.total_agents.set(&(config.initial_agents.len() as u64), state)?;
+        self.total_stake.set(&total_stake, state)?;
+        self.stake_weighting.set(&config.stake_weighting, state)?;
+        self.ignore_score.set(&config.ignore_score, state)?;
+        self.withdrawal_cooldown.set(&config.withdrawal_cooldown, state)?;
+        self.max_score.set(&config.max_score, state)?;
+        self.min_stake.set(&config.min_stake, state)?;
+        self.stake_lock.set(&config.stake_lock, state)?;
+        self.initial_score.set(&config.initial_score, state)?;
+        self.tier_thresholds.set(&config.tier_thresholds, state)?;
         Ok(())
     }
 
@@ -127,6 +436,18 @@ impl<S: Spec> Module for AgentModule<S> {
             CallMessage::WithdrawStake { amount } => {
                 self.withdraw_stake(amount, context, state)
             }
+            CallMessage::DelegateStake { to, amount } => {
+                self.delegate_stake(to, amount, context, state)
+            }
+            CallMessage::UndelegateStake { from, amount } => {
+                self.undelegate_stake(from, amount, context, state)
+            }
+            CallMessage::TransferStake { to, amount } => {
+                self.transfer_stake(to, amount, context, state)
+            }
+            CallMessage::SetTierOverride { address, tier } => {
+                self.set_tier_override(&address, tier, context, state)
+            }
         }
     }
 }
@@ -138,6 +459,38 @@ where
     S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
 {
     pub initial_agents: Vec<(S::Address, Agent)>,
+
+    /// Lock stake backing submissions (disabled by default)
+    #[serde(default)]
+    pub stake_lock: StakeLockConfig,
+
+    /// Initial score of agents registering after genesis (flat 100 by default)
+    #[serde(default)]
+    pub initial_score: InitialScorePolicy,
+
+    /// Score thresholds for automatic tiers (everyone Novice by default)
+    #[serde(default)]
+    pub tier_thresholds: TierThresholds,
+
+    /// Stake weighting mode (linear by default)
+    #[serde(default)]
+    pub stake_weighting: StakeWeighting,
+
+    /// Weigh by stake alone, treating every score as 1 (off by default)
+    #[serde(default)]
+    pub ignore_score: bool,
+
+    /// Blocks during which an agent can't withdraw after submitting (disabled by default)
+    #[serde(default)]
+    pub withdrawal_cooldown: u64,
+
+    /// Score ceiling; `initial_agents` above it fail genesis (DEFAULT_MAX_SCORE by default)
+    #[serde(default = "default_max_score")]
+    pub max_score: u64,
+
+    /// Smallest stake a registered agent may hold (no minimum by default)
+    #[serde(default)]
+    pub min_stake: u64,
 }
 
 impl<S: Spec> AgentModule<S> {
@@ -150,7 +503,7 @@ impl<S: Spec> AgentModule<S> {
     /// 1. Extract sender address from transaction context
     /// 2. Check if agent already exists (prevent double registration)
     /// 3. Validate stake is non-zero
-    /// 4. Create new Agent with default score of 100
+    /// 4. Create new Agent with the configured initial score (flat 100 by default)
     /// 5. Store in StateMap
     pub fn register_agent(
         &mut self,
@@ -172,15 +525,28 @@ impl<S: Spec> AgentModule<S> {
         if initial_stake == 0 {
             bail!("Initial stake must be greater than zero");
         }
+        let min_stake = self.min_stake.get(state)?.unwrap_or(0);
+        if initial_stake < min_stake {
+            bail!("Initial stake {} is below the minimum stake of {}", initial_stake, min_stake);
+        }
 
-        let agent = Agent {
-            stake: initial_stake,
-            score: 100, // Everyone starts with score 100
-        };
+        let policy = self.initial_score.get(state)?.unwrap_or_default();
+        let max_score = self.max_score.get(state)?.unwrap_or(DEFAULT_MAX_SCORE);
+        let agent = Agent::new(initial_stake, policy.score_for(initial_stake).min(max_score));
 
-        // StateMap.set persists the agent to blockchain state
+        // Persists the agent to blockchain state, assigns its tier and counts its weight
         // The ? operator propagates any storage errors
-        self.agents.set(sender, &agent, state)?;
+        self.save_agent(sender, None, agent, state)?;
+        if self.deregistered.get(sender, state)?.unwrap_or(false) {
+            self.deregistered.delete(sender, state)?;
+        } else {
+            self.agent_index.push(sender, state)?;
+        }
+
+        self.emit_event(
+            state,
+            Event::AgentRegistered { address: sender.clone(), stake: initial_stake },
+        );
         
         Ok(())
     }
@@ -193,14 +559,19 @@ impl<S: Spec> AgentModule<S> {
     ) -> Result<()> {
         let sender = context.sender();
         let agent = self.agents.get(sender, state)?
-            .ok_or_else(|| anyhow::anyhow!("Agent not registered"))?;
+            .ok_or(AgentError::NotRegistered)?;
         
         let updated_agent = Agent {
-            stake: agent.stake.saturating_add(amount),
-            score: agent.score,
+            stake: agent.stake_after(StakeChange::Add(amount))?,
+            ..agent.clone()
         };
+        let new_total = updated_agent.stake;
         
-        self.agents.set(sender, &updated_agent, state)?;
+        self.save_agent(sender, Some(&agent), updated_agent, state)?;
+        self.emit_event(
+            state,
+            Event::StakeAdded { address: sender.clone(), amount, new_total },
+        );
         
         Ok(())
     }
@@ -213,61 +584,430 @@ impl<S: Spec> AgentModule<S> {
     ) -> Result<()> {
         let sender = context.sender();
         let agent = self.agents.get(sender, state)?
-            .ok_or_else(|| anyhow::anyhow!("Agent not registered"))?;
-        
-        if agent.stake < amount {
-            bail!("Insufficient stake balance");
+            .ok_or(AgentError::NotRegistered)?;
+        let remaining = agent.stake_after(StakeChange::Remove(amount))?;
+
+        // Recent submitters wait out the cooldown first (see stake_lock.rs)
+        self.ensure_withdrawal_cooldown_over(sender, state)?;
+
+        // Stake backing open submissions can't leave (see stake_lock.rs)
+        let locked = self.locked_stake(sender, state)?;
+        if remaining < locked {
+            bail!(
+                "Stake is locked: {} of {} backs open submissions",
+                locked,
+                agent.stake
+            );
+        }
+
+        // MINIMUM STAKE:
+        // A withdrawal leaves either nothing (the agent deregisters) or at least min_stake
+        let min_stake = self.min_stake.get(state)?.unwrap_or(0);
+        if remaining > 0 && remaining < min_stake {
+            bail!(
+                "Withdrawal would leave {} staked, below the minimum of {}; withdraw everything to deregister",
+                remaining,
+                min_stake
+            );
+        }
+        if remaining == 0 {
+            self.deregister_agent(sender, agent, state)?;
+            self.emit_event(
+                state,
+                Event::StakeWithdrawn { address: sender.clone(), amount, new_total: 0 },
+            );
+            return Ok(());
         }
 
         let updated_agent = Agent {
-            stake: agent.stake.saturating_sub(amount),
-            score: agent.score,
+            stake: remaining,
+            ..agent.clone()
         };
+        let new_total = updated_agent.stake;
         
-        self.agents.set(sender, &updated_agent, state)?;
+        self.save_agent(sender, Some(&agent), updated_agent, state)?;
+        self.emit_event(
+            state,
+            Event::StakeWithdrawn { address: sender.clone(), amount, new_total },
+        );
         
         Ok(())
     }
 
+    /// Removes an agent that withdrew or transferred its whole stake
+    ///
+    /// Its score and tier are dropped with it: registering again starts over.
+    /// Stake locks were already released (or the withdrawal would have failed).
+    /// Stake delegated to it goes back to its delegators first; stake it
+    /// delegated out must be taken back before it can leave (see delegation.rs).
+    pub(crate) fn deregister_agent(
+        &mut self,
+        address: &S::Address,
+        agent: Agent,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if agent.delegated_out > 0 {
+            bail!(
+                "Can't deregister with {} stake delegated out; undelegate it first",
+                agent.delegated_out
+            );
+        }
+
+        let weighting = self.weight_rule(state)?;
+        let total = self.total_registered_weight.get(state)?.unwrap_or(0);
+        let total = total.saturating_sub(agent.weight(weighting) as u128);
+        self.total_registered_weight.set(&total, state)?;
+
+        let total_stake = self.total_stake.get(state)?.unwrap_or(0);
+        self.total_stake.set(&total_stake.saturating_sub(agent.owned_stake()), state)?;
+        let agents = self.total_agents.get(state)?.unwrap_or(0);
+        self.total_agents.set(&agents.saturating_sub(1), state)?;
+
+        self.return_delegations(address, state)?;
+        self.agents.delete(address, state)?;
+        self.locked_until.delete(address, state)?;
+        self.deregistered.set(address, &true, state)?;
+
+        self.emit_event(state, Event::AgentDeregistered { address: address.clone() });
+
+        Ok(())
+    }
+
+    /// Stores an agent and moves `total_registered_weight` by its weight change
+    ///
+    /// `total_stake` and `total_agents` follow the same way (None = newly registered).
+    /// Also refreshes the agent's tier from its score unless the admin pinned it.
+    fn save_agent(
+        &mut self,
+        address: &S::Address,
+        previous: Option<&Agent>,
+        mut agent: Agent,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if !agent.tier_overridden {
+            agent.tier = self.tier_thresholds.get(state)?.unwrap_or_default().tier_for(agent.score);
+        }
+
+        let weighting = self.weight_rule(state)?;
+        let previous_weight = previous.map_or(0, |a| a.weight(weighting)) as u128;
+        let total = self.total_registered_weight.get(state)?.unwrap_or(0);
+        let total = total.saturating_sub(previous_weight) + agent.weight(weighting) as u128;
+
+        self.agents.set(address, &agent, state)?;
+        self.total_registered_weight.set(&total, state)?;
+
+        let total_stake = self.total_stake.get(state)?.unwrap_or(0);
+        let total_stake = total_stake.saturating_sub(previous.map_or(0, Agent::owned_stake)) + agent.owned_stake();
+        self.total_stake.set(&total_stake, state)?;
+        if previous.is_none() {
+            let agents = self.total_agents.get(state)?.unwrap_or(0);
+            self.total_agents.set(&(agents + 1), state)?;
+        }
+
+        Ok(())
+    }
+
+    /// Raises an agent's score by `delta`, up to `max_score`
+    ///
+    /// A score already above the cap (e.g. after the cap was lowered) is kept, not cut.
+    /// `caller` is the ID of the calling module and must match `score_updater`.
+    /// There is no CallMessage for this: users can't move their own score.
     pub fn update_score(
         &mut self,
+        caller: &ModuleId,
         address: S::Address,
         delta: u64,
         state: &mut impl TxState<S>,
     ) -> Result<()> {
+        if self.score_updater.get(state)?.as_ref() != Some(caller) {
+            bail!("Caller is not allowed to update scores");
+        }
+
         let agent = self.agents.get(&address, state)?
-            .ok_or_else(|| anyhow::anyhow!("Agent not registered"))?;
+            .ok_or(AgentError::NotRegistered)?;
         
+        let max_score = self.max_score.get(state)?.unwrap_or(DEFAULT_MAX_SCORE);
         let updated_agent = Agent {
-            stake: agent.stake,
-            score: agent.score.saturating_add(delta),
+            score: agent.score.saturating_add(delta).min(max_score).max(agent.score),
+            ..agent.clone()
         };
+        let new_score = updated_agent.score;
         
-        self.agents.set(&address, &updated_agent, state)?;
+        self.save_agent(&address, Some(&agent), updated_agent, state)?;
+        self.emit_event(state, Event::ScoreUpdated { address, delta, new_score });
 
         Ok(())
     }
 
+    /// Removes up to `amount` from an agent's stake into `slashed_pool`
+    ///
+    /// `caller` must match `score_updater`, as for `update_score`.
+    /// Never takes more than the agent has staked, ignores stake locks and
+    /// min_stake, and leaves the agent registered even at zero stake.
+    /// Returns the amount actually slashed.
+    pub fn slash_agent(
+        &mut self,
+        caller: &ModuleId,
+        address: &S::Address,
+        amount: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        if self.score_updater.get(state)?.as_ref() != Some(caller) {
+            bail!("Caller is not allowed to slash stake");
+        }
+
+        let agent = self.agents.get(address, state)?
+            .ok_or(AgentError::NotRegistered)?;
+        let slashed = amount.min(agent.stake);
+        if slashed == 0 {
+            return Ok(0);
+        }
+
+        let updated_agent = Agent {
+            stake: agent.stake - slashed,
+            ..agent.clone()
+        };
+        let new_total = updated_agent.stake;
+        self.save_agent(address, Some(&agent), updated_agent, state)?;
+
+        let pool = self.slashed_pool.get(state)?.unwrap_or(0);
+        self.slashed_pool.set(&pool.saturating_add(slashed), state)?;
+
+        self.emit_event(
+            state,
+            Event::AgentSlashed { address: address.clone(), amount: slashed, new_total },
+        );
+
+        Ok(slashed)
+    }
+
+    /// Sums stake and stake × score over a page of the agent index
+    ///
+    /// Returns (total_stake, total_stake_times_score) for agents at positions
+    /// [offset, offset + limit). Building block for paginated reputation metrics.
+    pub fn stake_weighted_score_totals(
+        &self,
+        offset: u64,
+        limit: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<(u128, u128)> {
+        let len = self.agent_index.len(state)?;
+        let end = offset.saturating_add(limit).min(len);
+
+        // u128 accumulators: u64 stake × u64 score summed over many agents can't overflow
+        let mut total_stake: u128 = 0;
+        let mut total_weighted: u128 = 0;
+
+        for i in offset..end {
+            let Some(address) = self.agent_index.get(i, state)? else {
+                continue;
+            };
+            if let Some(agent) = self.agents.get(&address, state)? {
+                total_stake += agent.stake as u128;
+                total_weighted += (agent.stake as u128) * (agent.score as u128);
+            }
+        }
+
+        Ok((total_stake, total_weighted))
+    }
+
+    /// Returns the agents at `agent_index` positions offset ..< offset+limit
+    ///
+    /// `limit` is clamped to MAX_AGENT_PAGE_SIZE. Deregistration leaves an
+    /// agent's index entry in place as a tombstone (see `deregistered`), so
+    /// positions never shift and pages stay disjoint; tombstones are skipped.
+    pub fn list_agents(
+        &self,
+        offset: u64,
+        limit: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<(S::Address, Agent)>> {
+        let limit = limit.min(MAX_AGENT_PAGE_SIZE);
+        let end = offset.saturating_add(limit).min(self.agent_index.len(state)?);
+
+        let mut agents = Vec::new();
+        for i in offset..end {
+            let Some(address) = self.agent_index.get(i, state)? else {
+                continue;
+            };
+            if let Some(agent) = self.agents.get(&address, state)? {
+                agents.push((address, agent));
+            }
+        }
+
+        Ok(agents)
+    }
+
+    /// Computes the stake-weighted mean score of all agents
+    ///
+    /// mean = Σ(stake × score) / Σ(stake), returned in SCORE_SCALE fixed-point
+    /// Unlike a plain average, a large low-score agent isn't hidden by many
+    /// small high-score ones.
+    ///
+    /// Returns:
+    /// - Ok(None) when the total stake is zero (no agents or all unstaked)
+    /// - Err if the index is larger than MAX_AGENT_SCAN; use
+    ///   `stake_weighted_score_totals` page by page instead
+    pub fn get_stake_weighted_mean_score(&self, state: &mut impl TxState<S>) -> Result<Option<u64>> {
+        let len = self.agent_index.len(state)?;
+        if len > MAX_AGENT_SCAN {
+            bail!(
+                "Too many agents to scan at once ({} > {}), paginate with stake_weighted_score_totals",
+                len,
+                MAX_AGENT_SCAN
+            );
+        }
+
+        let (total_stake, total_weighted) = self.stake_weighted_score_totals(0, len, state)?;
+        if total_stake == 0 {
+            return Ok(None);
+        }
+
+        let mean = total_weighted * (SCORE_SCALE as u128) / total_stake;
+        Ok(Some(u64::try_from(mean).unwrap_or(u64::MAX)))
+    }
+
+    /// Lists every CallMessage variant with the permission `call` enforces for it
+    #[cfg(feature = "native")]
+    pub fn call_permissions(&self) -> Vec<CallPermissionEntry> {
+        vec![
+            CallPermissionEntry::new("register_agent", CallPermission::Public),
+            CallPermissionEntry::new("add_stake", CallPermission::Public),
+            CallPermissionEntry::new("withdraw_stake", CallPermission::Public),
+            CallPermissionEntry::new("delegate_stake", CallPermission::Public),
+            CallPermissionEntry::new("undelegate_stake", CallPermission::Public),
+            CallPermissionEntry::new("transfer_stake", CallPermission::Public),
+            CallPermissionEntry::new("set_tier_override", CallPermission::Admin),
+        ]
+    }
+
     /// Calculates an agent's weight for belief aggregation
-    /// Weight = stake × score
+    /// Weight = stake × score (isqrt(stake) × score in Sqrt mode)
     /// 
     /// This is a helper method used by SubmissionModule to determine
     /// how much influence an agent's prediction should have
     /// 
     /// Uses saturating_mul to prevent overflow (caps at u64::MAX)
+    ///
+    /// Never returns a zero weight: fails with `AgentError` instead, telling
+    /// an unregistered address from an agent without stake or without score.
     pub fn get_weight(&self, address: &S::Address, state: &mut impl TxState<S>) -> Result<u64> {
-        let agent = self.agents.get(address, state)?
-            .ok_or_else(|| anyhow::anyhow!("Agent not registered"))?;
-        Ok(agent.stake.saturating_mul(agent.score))
+        let Some(agent) = self.agents.get(address, state)? else {
+            return Err(AgentError::NotRegistered.into());
+        };
+        if agent.stake == 0 && agent.delegated == 0 {
+            return Err(AgentError::ZeroStake.into());
+        }
+        let weighting = self.weight_rule(state)?;
+        if agent.score == 0 && !weighting.ignore_score {
+            return Err(AgentError::ZeroScore.into());
+        }
+        Ok(agent.weight(weighting))
     }
+
+    /// The active stake weighting and score handling
+    pub fn weight_rule(&self, state: &mut impl TxState<S>) -> Result<WeightRule> {
+        Ok(WeightRule {
+            weighting: self.stake_weighting.get(state)?.unwrap_or_default(),
+            ignore_score: self.ignore_score.get(state)?.unwrap_or(false),
+        })
+    }
+
+    /// An agent's stake, score and weight under the active stake weighting
+    ///
+    /// Returns Ok(None) for unregistered addresses.
+    pub fn get_agent_profile(
+        &self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<Option<AgentProfile<S>>> {
+        let Some(agent) = self.agents.get(address, state)? else {
+            return Ok(None);
+        };
+        let weighting = self.weight_rule(state)?;
+
+        Ok(Some(AgentProfile {
+            address: address.clone(),
+            stake: agent.stake,
+            delegated: agent.delegated,
+            score: agent.score,
+            weight: agent.weight(weighting),
+        }))
+    }
+}
+
+/// Everything that determines an agent's influence, in one response
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+pub struct AgentProfile<S: Spec> {
+    pub address: S::Address,
+    pub stake: u64,
+    /// Stake delegated to the agent by others
+    pub delegated: u64,
+    pub score: u64,
+    /// Computed from stake (own and delegated) and score with the active stake weighting
+    pub weight: u64,
+}
+
+/// One page of agents, in registration order
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+pub struct AgentPage<S: Spec> {
+    /// Number of `agent_index` entries, deregistered ones included
+    pub total: u64,
+    /// Index entries skipped before this page
+    pub offset: u64,
+    /// Page size actually applied (after clamping)
+    pub limit: u64,
+    /// Registered agents at index positions offset ..< offset+limit
+    /// Deregistered agents are left out, so a page can hold fewer than `limit`
+    pub agents: Vec<(S::Address, Agent)>,
+}
+
+/// Events emitted by AgentModule
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+#[serde(rename_all = "snake_case")]
+pub enum Event<S: Spec> {
+    /// A new agent registered with its initial stake
+    AgentRegistered { address: S::Address, stake: u64 },
+    /// An agent added stake; `new_total` is its stake afterwards
+    StakeAdded { address: S::Address, amount: u64, new_total: u64 },
+    /// An agent withdrew stake; `new_total` is its stake afterwards
+    StakeWithdrawn { address: S::Address, amount: u64, new_total: u64 },
+    /// An agent withdrew its whole stake and is no longer registered
+    AgentDeregistered { address: S::Address },
+    /// Stake was slashed into the slashed pool (by SubmissionModule)
+    AgentSlashed { address: S::Address, amount: u64, new_total: u64 },
+    /// An agent's score was raised (by SubmissionModule)
+    ScoreUpdated { address: S::Address, delta: u64, new_score: u64 },
+    /// Stake was delegated to an agent; `new_delegated` is its delegated total afterwards
+    StakeDelegated { delegator: S::Address, delegate: S::Address, amount: u64, new_delegated: u64 },
+    /// Delegated stake was taken back; `new_delegated` is the delegate's total afterwards
+    StakeUndelegated { delegator: S::Address, delegate: S::Address, amount: u64, new_delegated: u64 },
+    /// Own stake changed hands; the totals are both agents' stakes afterwards
+    StakeTransferred { from: S::Address, to: S::Address, amount: u64, sender_total: u64, recipient_total: u64 },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, JsonSchema, UniversalWallet)]
 #[serialize(Borsh, Serde)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
 #[serde(rename_all = "snake_case")]
-pub enum CallMessage {
+pub enum CallMessage<S: Spec> {
     RegisterAgent { initial_stake: u64 },
     AddStake { amount: u64 },
     WithdrawStake { amount: u64 },
+    /// Delegate stake to a registered agent (see delegation.rs)
+    DelegateStake { to: S::Address, amount: u64 },
+    /// Take back stake delegated to an agent
+    UndelegateStake { from: S::Address, amount: u64 },
+    /// Give own stake to another registered agent (see transfer.rs)
+    TransferStake { to: S::Address, amount: u64 },
+    /// Pin an agent's tier, or None to follow its score again (admin only, see tiers.rs)
+    SetTierOverride { address: S::Address, tier: Option<AgentTier> },
 }
 
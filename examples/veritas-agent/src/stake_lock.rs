@@ -0,0 +1,249 @@
+//! Stake locks - protection against aggregate manipulation via stake cycling
+//!
+//! FILE PURPOSE:
+//! Without locks an attacker can add stake, submit with the inflated weight,
+//! then withdraw immediately, moving an aggregate for the price of a few
+//! transactions. When enabled, every submission records a commitment of the
+//! agent's stake at that moment; the stake stays locked until the belief is
+//! closed (resolved, voided or archived) or `lock_blocks` blocks have passed.
+//!
+//! HOW IT WORKS:
+//! - SubmissionModule calls `commit_stake` after each accepted submission
+//! - Commitments are tracked per (agent, belief); resubmitting to the same
+//!   belief refreshes its commitment
+//! - `withdraw_stake` refuses to take the stake below the largest active commitment
+//!   (the same stake backs every belief, so locks don't add up)
+//! - Expired/closed commitments are pruned lazily on withdrawal
+//!
+//! STAKE AT RISK:
+//! When SubmissionModule weighs submissions by stake at risk, each submission
+//! commits an explicit amount instead (`commit_stake_at_risk`). Those commitments
+//! ignore the lock settings above: they always hold until the belief is closed,
+//! and they do add up, since each one carves its own amount out of the stake.
+//! The locked amount is the larger of the two kinds.
+//!
+//! WITHDRAWAL COOLDOWN:
+//! A blunter, optional guard: every submission also starts a cooldown of
+//! `withdrawal_cooldown` blocks during which the agent can't withdraw any stake
+//! at all, whatever it committed. Resubmitting restarts the cooldown.
+//!
+//! Block height is ChainState's rollup height (`BeliefModule::current_block`).
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+use veritas_belief::BeliefId;
+
+use crate::{AgentError, AgentModule};
+
+/// Settings for locking stake that backs submissions
+#[derive(Clone, Debug, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct StakeLockConfig {
+    /// Whether submissions lock stake at all
+    pub enabled: bool,
+
+    /// Blocks after which a commitment expires even if its belief is still open
+    pub lock_blocks: u64,
+}
+
+/// Stake an agent committed to a belief by submitting to it
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct StakeCommitment {
+    pub belief_id: BeliefId,
+
+    /// Agent's stake at the time of the (latest) submission
+    pub amount: u64,
+
+    /// Block height of the (latest) submission
+    pub committed_at: u64,
+
+    /// Explicit stake-at-risk commitment (held until the belief closes)
+    #[serde(default)]
+    pub at_risk: bool,
+}
+
+impl<S: Spec> AgentModule<S> {
+    /// Records that the agent's current stake backs a submission to `belief_id`
+    ///
+    /// No-op while stake locks are disabled.
+    pub fn commit_stake(
+        &mut self,
+        address: &S::Address,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let config = self.stake_lock.get(state)?.unwrap_or_default();
+        if !config.enabled {
+            return Ok(());
+        }
+
+        let agent = self.agents.get(address, state)?
+            .ok_or(AgentError::NotRegistered)?;
+        let height = self.belief_module.current_block(state)?;
+
+        let mut commitments = self.stake_commitments.get(address, state)?
+            .unwrap_or_default();
+        let commitment = StakeCommitment {
+            belief_id,
+            amount: agent.stake,
+            committed_at: height,
+            at_risk: false,
+        };
+        match commitments.iter_mut().find(|c| c.belief_id == belief_id && !c.at_risk) {
+            Some(existing) => *existing = commitment,
+            None => commitments.push(commitment),
+        }
+        self.stake_commitments.set(address, &commitments, state)?;
+
+        Ok(())
+    }
+
+    /// Locks `amount` of the agent's free stake behind a submission to `belief_id`
+    ///
+    /// The amount must be non-zero and fit in the stake not already locked by
+    /// other commitments. Resubmitting to the same belief replaces its amount.
+    pub fn commit_stake_at_risk(
+        &mut self,
+        address: &S::Address,
+        belief_id: BeliefId,
+        amount: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if amount == 0 {
+            bail!("Stake at risk must be greater than zero");
+        }
+
+        let agent = self.agents.get(address, state)?
+            .ok_or(AgentError::NotRegistered)?;
+        let height = self.belief_module.current_block(state)?;
+
+        // This belief's previous commitment is being replaced, so it doesn't count
+        let mut others = self.active_commitments(address, state)?;
+        others.retain(|c| !(c.at_risk && c.belief_id == belief_id));
+        let free = agent.stake.saturating_sub(Self::locked_amount(&others));
+        if amount > free {
+            bail!("Stake at risk {} exceeds free stake {}", amount, free);
+        }
+
+        others.push(StakeCommitment {
+            belief_id,
+            amount,
+            committed_at: height,
+            at_risk: true,
+        });
+        self.stake_commitments.set(address, &others, state)?;
+
+        Ok(())
+    }
+
+    /// Blocks all withdrawals by the agent for the configured cooldown
+    ///
+    /// Called by SubmissionModule after each accepted submission.
+    /// No-op while the cooldown is 0 (disabled).
+    pub fn start_withdrawal_cooldown(
+        &mut self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let cooldown = self.withdrawal_cooldown.get(state)?.unwrap_or(0);
+        if cooldown == 0 {
+            return Ok(());
+        }
+
+        let height = self.belief_module.current_block(state)?;
+        self.locked_until.set(address, &height.saturating_add(cooldown), state)?;
+
+        Ok(())
+    }
+
+    /// Fails while the agent's withdrawal cooldown is running
+    pub fn ensure_withdrawal_cooldown_over(
+        &self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let Some(locked_until) = self.locked_until.get(address, state)? else {
+            return Ok(());
+        };
+        let height = self.belief_module.current_block(state)?;
+        if height < locked_until {
+            bail!(
+                "Stake can't be withdrawn until block {} (current block {}): cooldown after submitting",
+                locked_until,
+                height
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the agent's commitments that still lock stake
+    ///
+    /// Every commitment is released once its belief is closed or gone.
+    /// Stake-lock commitments additionally lock nothing while stake locks are
+    /// disabled, and expire once `lock_blocks` blocks have passed since they were made.
+    pub fn active_commitments(
+        &self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<StakeCommitment>> {
+        let config = self.stake_lock.get(state)?.unwrap_or_default();
+        let height = self.belief_module.current_block(state)?;
+        let commitments = self.stake_commitments.get(address, state)?
+            .unwrap_or_default();
+
+        let mut active = Vec::new();
+        for commitment in commitments {
+            if !commitment.at_risk
+                && (!config.enabled || height >= commitment.committed_at.saturating_add(config.lock_blocks))
+            {
+                continue;
+            }
+            // Archived beliefs are deleted from hot state, which also releases the lock
+            let Some(belief) = self.belief_module.beliefs.get(&commitment.belief_id, state)? else {
+                continue;
+            };
+            if belief.resolved || belief.voided {
+                continue;
+            }
+            active.push(commitment);
+        }
+
+        Ok(active)
+    }
+
+    /// Amount of the agent's stake that can't currently be withdrawn
+    ///
+    /// Side effect: drops released commitments from storage.
+    pub fn locked_stake(
+        &mut self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let active = self.active_commitments(address, state)?;
+        let locked = Self::locked_amount(&active);
+
+        if active.is_empty() {
+            self.stake_commitments.delete(address, state)?;
+        } else {
+            self.stake_commitments.set(address, &active, state)?;
+        }
+
+        Ok(locked)
+    }
+
+    /// Stake held by a set of active commitments
+    ///
+    /// Stake-lock commitments overlap (the largest wins), stake-at-risk ones add up.
+    fn locked_amount(commitments: &[StakeCommitment]) -> u64 {
+        let overlapping = commitments.iter()
+            .filter(|c| !c.at_risk)
+            .map(|c| c.amount)
+            .max()
+            .unwrap_or(0);
+        let at_risk = commitments.iter()
+            .filter(|c| c.at_risk)
+            .fold(0u64, |sum, c| sum.saturating_add(c.amount));
+        overlapping.max(at_risk)
+    }
+}
@@ -0,0 +1,79 @@
+//! Tiers - automatic and admin-assigned agent tiers
+//!
+//! FILE PURPOSE:
+//! Structured communities want to restrict some beliefs to proven agents.
+//! Each agent carries an `AgentTier` derived from its score using the
+//! thresholds configured at genesis, recomputed whenever the agent is saved
+//! (registration, stake or score changes). The admin can pin a tier (`SetTierOverride`), which
+//! then sticks regardless of score until cleared.
+//!
+//! Beliefs declare a minimum tier (`Belief::min_tier`), enforced by SubmissionModule.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{Context, Spec, TxState};
+use veritas_belief::AgentTier;
+
+use crate::{AgentError, AgentModule};
+
+/// Minimum scores for automatic promotion
+/// The defaults never promote anyone, so every agent stays Novice
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct TierThresholds {
+    pub verified_min_score: u64,
+    pub expert_min_score: u64,
+}
+
+impl Default for TierThresholds {
+    fn default() -> Self {
+        Self {
+            verified_min_score: u64::MAX,
+            expert_min_score: u64::MAX,
+        }
+    }
+}
+
+impl TierThresholds {
+    /// Tier earned by `score`
+    pub fn tier_for(&self, score: u64) -> AgentTier {
+        if score >= self.expert_min_score {
+            AgentTier::Expert
+        } else if score >= self.verified_min_score {
+            AgentTier::Verified
+        } else {
+            AgentTier::Novice
+        }
+    }
+}
+
+impl<S: Spec> AgentModule<S> {
+    /// Pins an agent's tier (admin only); None returns it to score-based assignment
+    pub fn set_tier_override(
+        &mut self,
+        address: &S::Address,
+        tier: Option<AgentTier>,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+
+        let agent = self.agents.get(address, state)?
+            .ok_or(AgentError::NotRegistered)?;
+
+        let mut updated_agent = agent.clone();
+        updated_agent.tier_overridden = tier.is_some();
+        if let Some(tier) = tier {
+            updated_agent.tier = tier;
+        }
+
+        // save_agent recomputes the tier when the override was cleared
+        self.save_agent(address, Some(&agent), updated_agent, state)
+    }
+
+    /// Returns an agent's current tier
+    pub fn get_tier(&self, address: &S::Address, state: &mut impl TxState<S>) -> Result<AgentTier> {
+        let agent = self.agents.get(address, state)?
+            .ok_or(AgentError::NotRegistered)?;
+        Ok(agent.tier)
+    }
+}
@@ -0,0 +1,89 @@
+//! Stake transfers - moving stake ownership between agents
+//!
+//! FILE PURPOSE:
+//! Delegation (delegation.rs) lends weight while the delegator keeps the
+//! stake. Some arrangements, e.g. one agent backing another's reputation
+//! off-chain, need the stake itself to change hands. `transfer_stake` moves
+//! own stake from the sender to another registered agent.
+//!
+//! HOW IT WORKS:
+//! - Both agents must be registered; the recipient's stake and weight grow,
+//!   the sender's shrink, and `total_stake` stays the same
+//! - The sender's stake leaves like a withdrawal: not during its withdrawal
+//!   cooldown, never below its locked stake, and never below `min_stake`
+//!   unless it transfers everything
+//! - Transferring everything deregisters the sender, exactly as withdrawing
+//!   everything does (see `withdraw_stake`)
+//! - Only own stake moves: stake delegated to the sender stays with it
+//!
+//! Like all stake here, the transfer is accounted in this module and not
+//! moved through the bank.
+
+use anyhow::{bail, Result};
+use sov_modules_api::{Context, EventEmitter, Spec, TxState};
+
+use crate::{Agent, AgentError, AgentModule, Event, StakeChange};
+
+impl<S: Spec> AgentModule<S> {
+    /// Moves `amount` of the sender's own stake to the registered agent `to`
+    pub fn transfer_stake(
+        &mut self,
+        to: S::Address,
+        amount: u64,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let sender = context.sender();
+        if amount == 0 {
+            bail!("Transferred amount must be greater than zero");
+        }
+        if *sender == to {
+            bail!("Agents can't transfer stake to themselves");
+        }
+        let agent = self.agents.get(sender, state)?.ok_or(AgentError::NotRegistered)?;
+        if self.agents.get(&to, state)?.is_none() {
+            bail!("Can't transfer stake to an unregistered agent");
+        }
+
+        let remaining = agent.stake_after(StakeChange::Remove(amount))?;
+        self.ensure_withdrawal_cooldown_over(sender, state)?;
+        let locked = self.locked_stake(sender, state)?;
+        if remaining < locked {
+            bail!("Stake is locked: {} of {} backs open submissions", locked, agent.stake);
+        }
+        let min_stake = self.min_stake.get(state)?.unwrap_or(0);
+        if remaining > 0 && remaining < min_stake {
+            bail!(
+                "Transfer would leave {} staked, below the minimum of {}; transfer everything or less",
+                remaining,
+                min_stake
+            );
+        }
+
+        if remaining == 0 {
+            self.deregister_agent(sender, agent, state)?;
+        } else {
+            let updated = Agent { stake: remaining, ..agent.clone() };
+            self.save_agent(sender, Some(&agent), updated, state)?;
+        }
+
+        // Read only now: deregistering may have handed the recipient back stake it delegated
+        let recipient = self.agents.get(&to, state)?.ok_or(AgentError::NotRegistered)?;
+        let recipient_total = recipient.stake_after(StakeChange::Add(amount))?;
+        let updated = Agent { stake: recipient_total, ..recipient.clone() };
+        self.save_agent(&to, Some(&recipient), updated, state)?;
+
+        self.emit_event(
+            state,
+            Event::StakeTransferred {
+                from: sender.clone(),
+                to,
+                amount,
+                sender_total: remaining,
+                recipient_total,
+            },
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! Stake weighting - how an agent's stake turns into influence
+//!
+//! FILE PURPOSE:
+//! With the default linear weight (stake × score) a single large staker can
+//! outweigh everyone else. The Sqrt mode weighs isqrt(stake) × score instead,
+//! so a 100× larger stake only buys 10× the influence.
+//!
+//! The mode is deployment-wide (set at genesis) and applies everywhere a weight
+//! is derived from stake: submissions, stake at risk and the total registered weight.
+//! Integer math only, so every node computes identical weights.
+//!
+//! STAKE ONLY:
+//! The genesis `ignore_score` flag treats every score as 1, so weight is the
+//! effective stake alone: pure stake voting while reputation has too little
+//! history to mean anything. A score of 0 then no longer zeroes the weight.
+//! `WeightRule` carries both settings to every weight computation.
+
+use schemars::JsonSchema;
+
+/// How stake is scaled before it is multiplied by the score
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeWeighting {
+    /// weight = stake × score
+    #[default]
+    Linear,
+    /// weight = isqrt(stake) × score
+    Sqrt,
+}
+
+impl StakeWeighting {
+    /// Stake as it counts towards weight under this mode
+    pub fn effective_stake(&self, stake: u64) -> u64 {
+        match self {
+            Self::Linear => stake,
+            Self::Sqrt => isqrt(stake),
+        }
+    }
+}
+
+/// The deployment-wide settings that turn stake and score into weight
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WeightRule {
+    pub weighting: StakeWeighting,
+
+    /// Whether scores count as 1, leaving the effective stake alone
+    pub ignore_score: bool,
+}
+
+impl WeightRule {
+    /// effective stake × score (or × 1 when scores are ignored), saturating
+    pub fn weight(&self, stake: u64, score: u64) -> u64 {
+        let score = if self.ignore_score { 1 } else { score };
+        self.weighting.effective_stake(stake).saturating_mul(score)
+    }
+}
+
+/// Largest integer r with r × r <= n
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method from an initial guess above the root; the sequence
+    // decreases monotonically until it reaches floor(sqrt(n))
+    let mut x = 1u64 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
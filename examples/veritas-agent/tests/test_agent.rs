@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use veritas_agent::{Agent, AgentModule, CallMessage};
+    use veritas_agent::{isqrt, validate_genesis_agents, Agent, AgentError, AgentModule, AgentTier, CallMessage, InitialScorePolicy, StakeWeighting, TierThresholds};
     use sov_modules_api::test_utils::generate_address;
     use sov_modules_api::{Context, Module, WorkingSet};
     use sov_address::{EthereumAddress, EvmCryptoSpec};
@@ -123,4 +123,560 @@ mod tests {
         let weight = module.get_weight(&sender, &mut working_set).unwrap();
         assert_eq!(weight, 100_000);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_sqrt_weighting_vs_linear() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut linear_set = WorkingSet::<TestSpec>::new(Default::default());
+        let mut sqrt_set = WorkingSet::<TestSpec>::new(Default::default());
+        module.stake_weighting.set(&StakeWeighting::Sqrt, &mut sqrt_set).unwrap();
+
+        // (stake, isqrt(stake)), registered at the default score of 100 on both chains
+        for (name, stake, root) in [("a", 100, 10), ("b", 10_000, 100), ("c", 1_000_000, 1000)] {
+            let sender = generate_address::<TestSpec>(name);
+            let context = Context::new(sender.clone(), Default::default(), 1);
+            for working_set in [&mut linear_set, &mut sqrt_set] {
+                module.call(
+                    CallMessage::RegisterAgent { initial_stake: stake },
+                    &context,
+                    working_set,
+                ).unwrap();
+            }
+
+            assert_eq!(module.get_weight(&sender, &mut linear_set).unwrap(), stake * 100);
+            assert_eq!(module.get_weight(&sender, &mut sqrt_set).unwrap(), root * 100);
+        }
+
+        // The total registered weight follows the mode too
+        assert_eq!(module.total_registered_weight.get(&mut linear_set).unwrap(), Some(101_010_000));
+        assert_eq!(module.total_registered_weight.get(&mut sqrt_set).unwrap(), Some(111_000));
+    }
+
+    #[test]
+    fn test_ignore_score_weighs_stake_only() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut reputation_set = WorkingSet::<TestSpec>::new(Default::default());
+        let mut stake_only_set = WorkingSet::<TestSpec>::new(Default::default());
+        module.ignore_score.set(&true, &mut stake_only_set).unwrap();
+
+        // Stake 1000 at the default score of 100 on both chains
+        let sender = generate_address::<TestSpec>("agent");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+        for working_set in [&mut reputation_set, &mut stake_only_set] {
+            module.call(CallMessage::RegisterAgent { initial_stake: 1000 }, &context, working_set).unwrap();
+        }
+
+        assert_eq!(module.get_weight(&sender, &mut reputation_set).unwrap(), 100_000);
+        assert_eq!(module.get_weight(&sender, &mut stake_only_set).unwrap(), 1000);
+        assert_eq!(module.total_registered_weight.get(&mut reputation_set).unwrap(), Some(100_000));
+        assert_eq!(module.total_registered_weight.get(&mut stake_only_set).unwrap(), Some(1000));
+    }
+
+    #[test]
+    fn test_isqrt_is_exact() {
+        for n in [0, 1, 2, 3, 4, 15, 16, 17, 99, 100, 101, 999_999, 1_000_000, u64::MAX] {
+            let r = isqrt(n);
+            assert!(r * r <= n, "{}", n);
+            assert!((r + 1).checked_mul(r + 1).map_or(true, |sq| sq > n), "{}", n);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_stake_weighted_mean_score() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+
+        // No agents: total stake is zero
+        assert_eq!(module.get_stake_weighted_mean_score(&mut working_set).unwrap(), None);
+
+        // (stake, score) pairs: a large low-score agent and two smaller ones
+        for (name, stake, score) in [("a", 1000, 100), ("b", 3000, 200), ("c", 1000, 500)] {
+            let sender = generate_address::<TestSpec>(name);
+            let context = Context::new(sender.clone(), Default::default(), 1);
+            module.call(
+                CallMessage::RegisterAgent { initial_stake: stake },
+                &context,
+                &mut working_set,
+            ).unwrap();
+            module.agents.set(&sender, &Agent::new(stake, score), &mut working_set).unwrap();
+        }
+
+        // (1000×100 + 3000×200 + 1000×500) / 5000 = 240.0000
+        let mean = module.get_stake_weighted_mean_score(&mut working_set).unwrap();
+        assert_eq!(mean, Some(240 * veritas_agent::SCORE_SCALE));
+    }
+
+    #[test]
+    fn test_initial_score_follows_capped_log_policy() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+
+        // 100 points plus 5 per doubling of stake, never above 150
+        module.initial_score.set(
+            &InitialScorePolicy::CappedLog { base: 100, per_doubling: 5, max_score: 150 },
+            &mut working_set,
+        ).unwrap();
+
+        // (stake, expected score): floor(log2(stake)) = 0, 4, 6, 10, 20
+        let cases = [("a", 1, 100), ("b", 16, 120), ("c", 100, 130), ("d", 1024, 150), ("e", 1 << 20, 150)];
+        for (name, stake, expected) in cases {
+            let sender = generate_address::<TestSpec>(name);
+            let context = Context::new(sender.clone(), Default::default(), 1);
+            module.call(
+                CallMessage::RegisterAgent { initial_stake: stake },
+                &context,
+                &mut working_set,
+            ).unwrap();
+
+            let agent = module.agents.get(&sender, &mut working_set).unwrap().unwrap();
+            assert_eq!(agent.score, expected, "stake {}", stake);
+        }
+    }
+
+    #[test]
+    fn test_initial_score_policy_curve() {
+        let policy = InitialScorePolicy::CappedLog { base: 100, per_doubling: 2, max_score: 130 };
+        assert_eq!(policy.score_for(1), 100);
+        assert_eq!(policy.score_for(2), 102);
+        assert_eq!(policy.score_for(3), 102);
+        assert_eq!(policy.score_for(1000), 118);
+        assert_eq!(policy.score_for(u64::MAX), 130);
+
+        // Default keeps everyone at 100
+        assert_eq!(InitialScorePolicy::default().score_for(1), 100);
+        assert_eq!(InitialScorePolicy::default().score_for(1_000_000), 100);
+    }
+
+    #[test]
+    fn test_tier_promotion_at_score_threshold() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+
+        module.tier_thresholds.set(
+            &TierThresholds { verified_min_score: 150, expert_min_score: 300 },
+            &mut working_set,
+        ).unwrap();
+        module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+        assert_eq!(module.get_tier(&sender, &mut working_set).unwrap(), AgentTier::Novice);
+
+        let updater = module.id;
+        module.score_updater.set(&updater, &mut working_set).unwrap();
+
+        // One point short of the threshold
+        module.update_score(&updater, sender.clone(), 49, &mut working_set).unwrap();
+        assert_eq!(module.get_tier(&sender, &mut working_set).unwrap(), AgentTier::Novice);
+
+        // Exactly at the threshold
+        module.update_score(&updater, sender.clone(), 1, &mut working_set).unwrap();
+        assert_eq!(module.get_tier(&sender, &mut working_set).unwrap(), AgentTier::Verified);
+
+        module.update_score(&updater, sender.clone(), 150, &mut working_set).unwrap();
+        let agent = module.agents.get(&sender, &mut working_set).unwrap().unwrap();
+        assert_eq!((agent.score, agent.tier), (300, AgentTier::Expert));
+    }
+
+    #[test]
+    fn test_submission_applies_accuracy_bonus() {
+        let mut module = veritas_submission::SubmissionModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+
+        // What SubmissionModule's genesis does
+        let submission_id = module.id;
+        module.agent_module.score_updater.set(&submission_id, &mut working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        module.agent_module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+
+        // First submission becomes the consensus: distance 0, full 100 point bonus
+        module.call(
+            veritas_submission::CallMessage::SubmitBelief { belief_id: 1, value: 8000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+        let agent = module.agent_module.agents.get(&sender, &mut working_set).unwrap().unwrap();
+        assert_eq!(agent.score, 200);
+
+        // The raised score feeds into the total registered weight
+        let total = module.agent_module.total_registered_weight.get(&mut working_set).unwrap();
+        assert_eq!(total, Some(1000 * 200));
+    }
+
+    #[test]
+    fn test_user_cannot_bump_own_score() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+        let submission_id = veritas_submission::SubmissionModule::<TestSpec>::default().id;
+        module.score_updater.set(&submission_id, &mut working_set).unwrap();
+
+        module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+
+        // Every transaction a user can send leaves the score alone
+        for msg in [CallMessage::AddStake { amount: 500 }, CallMessage::WithdrawStake { amount: 500 }] {
+            module.call(msg, &context, &mut working_set).unwrap();
+        }
+
+        // Any caller but the registered updater is refused
+        let own_id = module.id;
+        assert!(module.update_score(&own_id, sender.clone(), 1000, &mut working_set).is_err());
+
+        let agent = module.agents.get(&sender, &mut working_set).unwrap().unwrap();
+        assert_eq!(agent.score, 100);
+    }
+
+    #[test]
+    fn test_score_stops_at_max_score() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+        let updater = module.id;
+        module.score_updater.set(&updater, &mut working_set).unwrap();
+        module.max_score.set(&250, &mut working_set).unwrap();
+
+        module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+
+        // 100 -> 200 -> capped at 250, and further bumps change nothing
+        for expected in [200, 250, 250, 250] {
+            module.update_score(&updater, sender.clone(), 100, &mut working_set).unwrap();
+            let agent = module.agents.get(&sender, &mut working_set).unwrap().unwrap();
+            assert_eq!(agent.score, expected);
+        }
+        module.update_score(&updater, sender.clone(), u64::MAX, &mut working_set).unwrap();
+        assert_eq!(module.agents.get(&sender, &mut working_set).unwrap().unwrap().score, 250);
+
+        // Registration clamps the initial score too
+        module.max_score.set(&50, &mut working_set).unwrap();
+        let late = generate_address::<TestSpec>("late");
+        module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &Context::new(late.clone(), Default::default(), 1),
+            &mut working_set,
+        ).unwrap();
+        assert_eq!(module.agents.get(&late, &mut working_set).unwrap().unwrap().score, 50);
+
+        // Lowering the cap doesn't cut a score that is already higher
+        module.update_score(&updater, sender.clone(), 1, &mut working_set).unwrap();
+        assert_eq!(module.agents.get(&sender, &mut working_set).unwrap().unwrap().score, 250);
+    }
+
+    #[test]
+    fn test_weight_reflects_capped_score() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+        let updater = module.id;
+        module.score_updater.set(&updater, &mut working_set).unwrap();
+        module.max_score.set(&300, &mut working_set).unwrap();
+
+        module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+        module.update_score(&updater, sender.clone(), 1_000_000, &mut working_set).unwrap();
+
+        // Weight ceiling: stake × max_score
+        assert_eq!(module.get_weight(&sender, &mut working_set).unwrap(), 1000 * 300);
+        let total = module.total_registered_weight.get(&mut working_set).unwrap();
+        assert_eq!(total, Some(1000 * 300));
+    }
+
+    #[test]
+    fn test_agent_profile() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+
+        assert_eq!(module.get_agent_profile(&sender, &mut working_set).unwrap(), None);
+
+        module.call(
+            CallMessage::RegisterAgent { initial_stake: 1000 },
+            &context,
+            &mut working_set,
+        ).unwrap();
+
+        let profile = module.get_agent_profile(&sender, &mut working_set).unwrap().unwrap();
+        assert_eq!(profile.address, sender);
+        assert_eq!((profile.stake, profile.score, profile.weight), (1000, 100, 100_000));
+
+        // The weight follows the active stake weighting: isqrt(1000) × 100
+        module.stake_weighting.set(&StakeWeighting::Sqrt, &mut working_set).unwrap();
+        let profile = module.get_agent_profile(&sender, &mut working_set).unwrap().unwrap();
+        assert_eq!(profile.weight, 31 * 100);
+    }
+
+    #[test]
+    fn test_min_stake() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let sender = generate_address::<TestSpec>("test_sender");
+        let context = Context::new(sender.clone(), Default::default(), 1);
+        module.min_stake.set(&500, &mut working_set).unwrap();
+
+        // Registering below the minimum fails
+        assert!(module.call(CallMessage::RegisterAgent { initial_stake: 499 }, &context, &mut working_set).is_err());
+        assert!(module.agents.get(&sender, &mut working_set).unwrap().is_none());
+        module.call(CallMessage::RegisterAgent { initial_stake: 1000 }, &context, &mut working_set).unwrap();
+
+        // Withdrawing below the minimum fails, down to exactly the minimum succeeds
+        assert!(module.call(CallMessage::WithdrawStake { amount: 501 }, &context, &mut working_set).is_err());
+        module.call(CallMessage::WithdrawStake { amount: 500 }, &context, &mut working_set).unwrap();
+        assert_eq!(module.agents.get(&sender, &mut working_set).unwrap().unwrap().stake, 500);
+        assert!(module.call(CallMessage::WithdrawStake { amount: 1 }, &context, &mut working_set).is_err());
+
+        // Withdrawing everything deregisters
+        module.call(CallMessage::WithdrawStake { amount: 500 }, &context, &mut working_set).unwrap();
+        assert!(module.agents.get(&sender, &mut working_set).unwrap().is_none());
+        assert_eq!(module.total_registered_weight.get(&mut working_set).unwrap(), Some(0));
+
+        // Registering again reuses the agent's index entry
+        module.call(CallMessage::RegisterAgent { initial_stake: 600 }, &context, &mut working_set).unwrap();
+        assert_eq!(module.agent_index.len(&mut working_set).unwrap(), 1);
+        assert_eq!(module.get_weight(&sender, &mut working_set).unwrap(), 600 * 100);
+    }
+
+    #[test]
+    fn test_delegation() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let agent = generate_address::<TestSpec>("agent");
+        let holder = generate_address::<TestSpec>("holder");
+        let agent_context = Context::new(agent.clone(), Default::default(), 1);
+        let holder_context = Context::new(holder.clone(), Default::default(), 1);
+
+        let stake_of = |module: &AgentModule<TestSpec>, address, working_set: &mut WorkingSet<TestSpec>| {
+            module.agents.get(address, working_set).unwrap().unwrap().stake
+        };
+        module.call(CallMessage::RegisterAgent { initial_stake: 600 }, &holder_context, &mut working_set).unwrap();
+
+        // Only registered agents can receive delegations
+        let delegate = |amount| CallMessage::DelegateStake { to: agent.clone(), amount };
+        assert!(module.call(delegate(500), &holder_context, &mut working_set).is_err());
+        module.call(CallMessage::RegisterAgent { initial_stake: 1000 }, &agent_context, &mut working_set).unwrap();
+
+        module.call(delegate(300), &holder_context, &mut working_set).unwrap();
+        module.call(delegate(200), &holder_context, &mut working_set).unwrap();
+        assert_eq!(module.get_delegation(&holder, &agent, &mut working_set).unwrap(), 500);
+
+        // The delegated stake left the holder: its weight moved, the total stake didn't
+        assert_eq!(stake_of(&module, &holder, &mut working_set), 100);
+        assert_eq!(module.agents.get(&holder, &mut working_set).unwrap().unwrap().delegated_out, 500);
+        assert_eq!(module.get_weight(&agent, &mut working_set).unwrap(), 1500 * 100);
+        assert_eq!(module.total_registered_weight.get(&mut working_set).unwrap(), Some(1600 * 100));
+        assert_eq!(module.total_stake.get(&mut working_set).unwrap(), Some(1600));
+        let profile = module.get_agent_profile(&agent, &mut working_set).unwrap().unwrap();
+        assert_eq!((profile.stake, profile.delegated), (1000, 500));
+
+        // Only stake the holder still has can be delegated, and only by a registered agent
+        let err = module.call(delegate(101), &holder_context, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<AgentError>(), Some(&AgentError::InsufficientStake { stake: 100, requested: 101 }));
+        let stranger_context = Context::new(generate_address::<TestSpec>("stranger"), Default::default(), 1);
+        let err = module.call(delegate(1), &stranger_context, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<AgentError>(), Some(&AgentError::NotRegistered));
+        assert_eq!(module.get_weight(&agent, &mut working_set).unwrap(), 1500 * 100);
+
+        // Stake delegated out keeps the holder registered
+        assert!(module.call(CallMessage::WithdrawStake { amount: 100 }, &holder_context, &mut working_set).is_err());
+
+        // Undelegating more than was delegated fails, the rest returns in full
+        let undelegate = |amount| CallMessage::UndelegateStake { from: agent.clone(), amount };
+        assert!(module.call(undelegate(501), &holder_context, &mut working_set).is_err());
+        module.call(undelegate(200), &holder_context, &mut working_set).unwrap();
+        assert_eq!(module.get_delegation(&holder, &agent, &mut working_set).unwrap(), 300);
+        assert_eq!(module.get_weight(&agent, &mut working_set).unwrap(), 1300 * 100);
+        assert_eq!(stake_of(&module, &holder, &mut working_set), 300);
+
+        module.call(undelegate(300), &holder_context, &mut working_set).unwrap();
+        assert_eq!(module.get_delegation(&holder, &agent, &mut working_set).unwrap(), 0);
+        assert_eq!(module.get_weight(&agent, &mut working_set).unwrap(), 1000 * 100);
+        assert_eq!(module.total_registered_weight.get(&mut working_set).unwrap(), Some(1600 * 100));
+        assert_eq!(stake_of(&module, &agent, &mut working_set), 1000);
+        assert_eq!(stake_of(&module, &holder, &mut working_set), 600);
+        assert_eq!(module.total_stake.get(&mut working_set).unwrap(), Some(1600));
+
+        // With the delegation gone the agent may leave
+        module.call(CallMessage::WithdrawStake { amount: 1000 }, &agent_context, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_deregistering_returns_delegations() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let agent = generate_address::<TestSpec>("agent");
+        let agent_context = Context::new(agent.clone(), Default::default(), 1);
+        module.call(CallMessage::RegisterAgent { initial_stake: 1000 }, &agent_context, &mut working_set).unwrap();
+
+        let holders: Vec<_> = ["holder_a", "holder_b"].iter().map(|name| generate_address::<TestSpec>(name)).collect();
+        for (holder, amount) in holders.iter().zip([300, 700]) {
+            let context = Context::new(holder.clone(), Default::default(), 1);
+            module.call(CallMessage::RegisterAgent { initial_stake: 1000 }, &context, &mut working_set).unwrap();
+            module.call(CallMessage::DelegateStake { to: agent.clone(), amount }, &context, &mut working_set).unwrap();
+        }
+        assert_eq!(module.delegators.get(&agent, &mut working_set).unwrap(), Some(holders.clone()));
+
+        module.call(CallMessage::WithdrawStake { amount: 1000 }, &agent_context, &mut working_set).unwrap();
+
+        // Both delegations are back in their delegators' own stake
+        for holder in &holders {
+            assert_eq!(module.get_delegation(holder, &agent, &mut working_set).unwrap(), 0);
+            let restored = module.agents.get(holder, &mut working_set).unwrap().unwrap();
+            assert_eq!((restored.stake, restored.delegated_out), (1000, 0));
+        }
+        assert_eq!(module.delegators.get(&agent, &mut working_set).unwrap(), None);
+        assert!(module.agents.get(&agent, &mut working_set).unwrap().is_none());
+
+        // Only the deregistered agent's own stake and weight left the totals
+        assert_eq!(module.total_stake.get(&mut working_set).unwrap(), Some(2000));
+        assert_eq!(module.total_registered_weight.get(&mut working_set).unwrap(), Some(2000 * 100));
+
+        // Nothing remains to undelegate
+        let context = Context::new(holders[0].clone(), Default::default(), 1);
+        let undelegate = CallMessage::UndelegateStake { from: agent.clone(), amount: 300 };
+        assert!(module.call(undelegate, &context, &mut working_set).is_err());
+    }
+
+    #[test]
+    fn test_transfer_stake() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let alice = generate_address::<TestSpec>("alice");
+        let bob = generate_address::<TestSpec>("bob");
+        let stranger = generate_address::<TestSpec>("stranger");
+        let alice_context = Context::new(alice.clone(), Default::default(), 1);
+        let bob_context = Context::new(bob.clone(), Default::default(), 1);
+        module.call(CallMessage::RegisterAgent { initial_stake: 1000 }, &alice_context, &mut working_set).unwrap();
+        module.call(CallMessage::RegisterAgent { initial_stake: 500 }, &bob_context, &mut working_set).unwrap();
+
+        let transfer = |to: &EthereumAddress, amount| CallMessage::TransferStake { to: to.clone(), amount };
+        module.call(transfer(&bob, 300), &alice_context, &mut working_set).unwrap();
+
+        fn stakes(
+            module: &AgentModule<TestSpec>,
+            addresses: [&EthereumAddress; 2],
+            working_set: &mut WorkingSet<TestSpec>,
+        ) -> [u64; 2] {
+            addresses.map(|address| module.agents.get(address, working_set).unwrap().unwrap().stake)
+        }
+        assert_eq!(stakes(&module, [&alice, &bob], &mut working_set), [700, 800]);
+        assert_eq!(module.total_stake.get(&mut working_set).unwrap(), Some(1500));
+        assert_eq!(module.total_registered_weight.get(&mut working_set).unwrap(), Some(1500 * 100));
+
+        // Unregistered recipient, more than the sender has, or to itself
+        assert!(module.call(transfer(&stranger, 100), &alice_context, &mut working_set).is_err());
+        assert!(module.call(transfer(&bob, 701), &alice_context, &mut working_set).is_err());
+        assert!(module.call(transfer(&alice, 100), &alice_context, &mut working_set).is_err());
+        assert_eq!(stakes(&module, [&alice, &bob], &mut working_set), [700, 800]);
+
+        // Handing over everything deregisters the sender, as a full withdrawal would
+        module.call(transfer(&bob, 700), &alice_context, &mut working_set).unwrap();
+        assert!(module.agents.get(&alice, &mut working_set).unwrap().is_none());
+        assert_eq!(module.deregistered.get(&alice, &mut working_set).unwrap(), Some(true));
+        assert_eq!(module.agents.get(&bob, &mut working_set).unwrap().unwrap().stake, 1500);
+        assert_eq!(module.total_agents.get(&mut working_set).unwrap(), Some(1));
+        assert_eq!(module.total_stake.get(&mut working_set).unwrap(), Some(1500));
+        assert_eq!(module.total_registered_weight.get(&mut working_set).unwrap(), Some(1500 * 100));
+    }
+
+    #[test]
+    fn test_stake_overflow_and_insufficient_stake() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let alice = generate_address::<TestSpec>("alice");
+        let context = Context::new(alice.clone(), Default::default(), 1);
+        module.call(CallMessage::RegisterAgent { initial_stake: u64::MAX - 10 }, &context, &mut working_set).unwrap();
+
+        // The first addition fits, the second would pass u64::MAX and is rejected, not capped
+        module.call(CallMessage::AddStake { amount: 5 }, &context, &mut working_set).unwrap();
+        let error = module.call(CallMessage::AddStake { amount: 10 }, &context, &mut working_set).unwrap_err();
+        assert_eq!(error.downcast_ref::<AgentError>(), Some(&AgentError::StakeOverflow));
+        let stake = module.agents.get(&alice, &mut working_set).unwrap().unwrap().stake;
+        assert_eq!(stake, u64::MAX - 5);
+
+        let error = module.call(CallMessage::WithdrawStake { amount: u64::MAX }, &context, &mut working_set).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<AgentError>(),
+            Some(&AgentError::InsufficientStake { stake: u64::MAX - 5, requested: u64::MAX })
+        );
+    }
+
+    #[test]
+    fn test_genesis_agent_validation() {
+        let alice = generate_address::<TestSpec>("alice");
+        let bob = generate_address::<TestSpec>("bob");
+
+        let valid = vec![(alice.clone(), Agent::new(1000, 100)), (bob.clone(), Agent::new(500, 10_000))];
+        validate_genesis_agents(&valid, 10_000).unwrap();
+
+        let duplicate = vec![(alice.clone(), Agent::new(1000, 100)), (alice.clone(), Agent::new(500, 100))];
+        let error = validate_genesis_agents(&duplicate, 10_000).unwrap_err().to_string();
+        assert!(error.contains("#1") && error.contains("more than once"), "{}", error);
+
+        let zero_stake = vec![(alice.clone(), Agent::new(1000, 100)), (bob.clone(), Agent::new(0, 100))];
+        let error = validate_genesis_agents(&zero_stake, 10_000).unwrap_err().to_string();
+        assert!(error.contains("#1") && error.contains("no stake"), "{}", error);
+
+        // Scores of 0 and above max_score are both rejected
+        for score in [0, 10_001] {
+            let agents = vec![(bob.clone(), Agent::new(1000, score))];
+            assert!(validate_genesis_agents(&agents, 10_000).is_err());
+        }
+    }
+
+    #[test]
+    fn test_list_agents() {
+        let mut module = AgentModule::<TestSpec>::default();
+        let mut working_set = WorkingSet::<TestSpec>::new(Default::default());
+        let mut contexts = Vec::new();
+        for (name, stake) in [("first", 1000), ("second", 2000), ("third", 3000)] {
+            let context = Context::new(generate_address::<TestSpec>(name), Default::default(), 1);
+            module.call(CallMessage::RegisterAgent { initial_stake: stake }, &context, &mut working_set).unwrap();
+            contexts.push(context);
+        }
+        let address = |i: usize| contexts[i].sender().clone();
+
+        fn page(
+            module: &AgentModule<TestSpec>,
+            offset: u64,
+            limit: u64,
+            working_set: &mut WorkingSet<TestSpec>,
+        ) -> Vec<(EthereumAddress, u64)> {
+            module.list_agents(offset, limit, working_set).unwrap()
+                .into_iter()
+                .map(|(address, agent)| (address, agent.stake))
+                .collect()
+        }
+        assert_eq!(page(&module, 0, 2, &mut working_set), vec![(address(0), 1000), (address(1), 2000)]);
+        assert_eq!(page(&module, 2, 2, &mut working_set), vec![(address(2), 3000)]);
+        assert!(page(&module, 3, 2, &mut working_set).is_empty());
+
+        // Deregistering leaves a tombstone: positions stay put, the agent is skipped
+        module.call(CallMessage::WithdrawStake { amount: 2000 }, &contexts[1], &mut working_set).unwrap();
+        assert_eq!(module.agent_index.len(&mut working_set).unwrap(), 3);
+        assert_eq!(page(&module, 0, 2, &mut working_set), vec![(address(0), 1000)]);
+        assert_eq!(page(&module, 0, 10, &mut working_set), vec![(address(0), 1000), (address(2), 3000)]);
+    }
+}
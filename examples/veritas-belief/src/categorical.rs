@@ -0,0 +1,187 @@
+//! Categorical beliefs - questions with more than two outcomes
+//!
+//! FILE PURPOSE:
+//! A binary belief tracks one probability. A categorical belief ("which team
+//! wins the league?") tracks one probability per outcome instead, as a
+//! distribution in SCALE units that always sums to exactly SCALE.
+//!
+//! AGGREGATION:
+//! Each submission is a full distribution. The aggregate is the weighted
+//! average of all of them, entry by entry:
+//!   new[i] = (old[i] × old_weight + submitted[i] × weight) / total_weight
+//! The first weighted submission replaces the uniform starting distribution.
+//! Integer division would lose a few units per update, so the averaged
+//! entries are renormalized to sum to SCALE (largest remainder, ties to the
+//! lowest index), computed straight from the u128 numerators.
+//!
+//! Categorical beliefs share the ID counter and submission counts with binary
+//! beliefs but live in their own map; resolution, deadlines and weighting modes
+//! only apply to binary beliefs for now.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+
+use crate::{BeliefError, BeliefId, BeliefModule, SCALE};
+
+/// Fewest outcomes a categorical belief can have
+pub const MIN_CATEGORICAL_OUTCOMES: usize = 2;
+
+/// Most outcomes a categorical belief can have, bounding every update's cost
+pub const MAX_CATEGORICAL_OUTCOMES: usize = 32;
+
+/// A question with several mutually exclusive outcomes
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct CategoricalBelief {
+    pub id: BeliefId,
+
+    pub question: String,
+
+    /// Outcome labels, in the order distributions list them
+    pub outcomes: Vec<String>,
+
+    /// Current probability of each outcome (SCALE units, sums to SCALE)
+    pub aggregates: Vec<u64>,
+
+    /// Sum of all weights that have contributed to this belief
+    pub total_weight: u128,
+}
+
+impl CategoricalBelief {
+    /// Creates a belief with a uniform distribution over `outcomes`
+    pub fn new(id: BeliefId, question: String, outcomes: Vec<String>) -> Self {
+        let uniform = vec![1u128; outcomes.len()];
+        Self {
+            id,
+            question,
+            aggregates: normalize_to_scale(&uniform),
+            outcomes,
+            total_weight: 0,
+        }
+    }
+
+    /// Checks that `distribution` has one entry per outcome and sums to SCALE
+    ///
+    /// Clients rounding each entry independently can be off by up to one unit
+    /// per outcome, so the sum may miss SCALE by at most the number of outcomes.
+    pub fn validate_distribution(&self, distribution: &[u64]) -> Result<()> {
+        if distribution.len() != self.outcomes.len() {
+            bail!(
+                "Distribution has {} entries, belief {} has {} outcomes",
+                distribution.len(),
+                self.id,
+                self.outcomes.len()
+            );
+        }
+        if distribution.iter().any(|p| *p > SCALE) {
+            bail!("Distribution entries must be between 0 and {}", SCALE);
+        }
+
+        let sum: u64 = distribution.iter().sum();
+        if sum.abs_diff(SCALE) > self.outcomes.len() as u64 {
+            bail!("Distribution sums to {}, expected {}", sum, SCALE);
+        }
+        Ok(())
+    }
+
+    /// Folds one weighted distribution into the aggregates
+    fn fold_distribution(&mut self, distribution: &[u64], weight: u64) {
+        let new_total_weight = self.total_weight + weight as u128;
+        if new_total_weight == 0 {
+            return;
+        }
+
+        let numerators: Vec<u128> = self.aggregates.iter()
+            .zip(distribution)
+            .map(|(old, new)| *old as u128 * self.total_weight + *new as u128 * weight as u128)
+            .collect();
+
+        // An all-zero distribution with no earlier weight has nothing to average
+        if numerators.iter().any(|n| *n > 0) {
+            self.aggregates = normalize_to_scale(&numerators);
+        }
+        self.total_weight += weight as u128;
+    }
+}
+
+/// Scales non-negative `values` to integers summing to exactly SCALE
+///
+/// Largest remainder method; ties go to the lowest index so every node
+/// rounds identically. `values` must not all be zero.
+pub fn normalize_to_scale(values: &[u128]) -> Vec<u64> {
+    let sum: u128 = values.iter().sum();
+    let scale = SCALE as u128;
+
+    let mut shares: Vec<u64> = values.iter().map(|v| (v * scale / sum) as u64).collect();
+    let mut remainders: Vec<(u128, usize)> = values.iter()
+        .enumerate()
+        .map(|(i, v)| (v * scale % sum, i))
+        .collect();
+    // Largest remainder first, lowest index first among equals
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let missing = SCALE - shares.iter().sum::<u64>();
+    for (_, i) in remainders.iter().take(missing as usize) {
+        shares[*i] += 1;
+    }
+    shares
+}
+
+impl<S: Spec> BeliefModule<S> {
+    /// Creates a categorical belief with a uniform starting distribution
+    ///
+    /// Takes the next ID from the counter shared with binary beliefs.
+    pub fn create_categorical_belief(
+        &mut self,
+        question: String,
+        outcomes: Vec<String>,
+        state: &mut impl TxState<S>,
+    ) -> Result<BeliefId> {
+        self.validate_question(&question, state)?;
+        if outcomes.len() < MIN_CATEGORICAL_OUTCOMES || outcomes.len() > MAX_CATEGORICAL_OUTCOMES {
+            bail!(
+                "A categorical belief needs between {} and {} outcomes",
+                MIN_CATEGORICAL_OUTCOMES,
+                MAX_CATEGORICAL_OUTCOMES
+            );
+        }
+
+        let current_id = self.next_belief_id.get(state)?.unwrap_or(1);
+        let next_id = current_id.checked_add(1).ok_or(BeliefError::IdsExhausted)?;
+        let belief = CategoricalBelief::new(current_id, question, outcomes);
+
+        self.categorical_beliefs.set(&current_id, &belief, state)?;
+        self.submission_counts.set(&current_id, &0, state)?;
+        self.next_belief_id.set(&next_id, state)?;
+
+        Ok(current_id)
+    }
+
+    /// Averages a weighted distribution into a categorical belief
+    ///
+    /// The categorical counterpart of `update_aggregate`, called by SubmissionModule.
+    /// Returns the new aggregates (summing to SCALE).
+    pub fn update_categorical_aggregate(
+        &mut self,
+        belief_id: BeliefId,
+        distribution: &[u64],
+        weight: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<u64>> {
+        // As in `update_aggregate`: no weight, no effect, so it mustn't be counted either
+        if weight == 0 {
+            return Err(BeliefError::ZeroWeight.into());
+        }
+        let mut belief = self.categorical_beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        belief.validate_distribution(distribution)?;
+
+        belief.fold_distribution(distribution, weight);
+        self.categorical_beliefs.set(&belief_id, &belief, state)?;
+
+        let count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
+        self.submission_counts.set(&belief_id, &(count + 1), state)?;
+
+        Ok(belief.aggregates)
+    }
+}
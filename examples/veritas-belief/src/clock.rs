@@ -0,0 +1,29 @@
+//! Clock - the single deterministic time source for Veritas
+//!
+//! FILE PURPOSE:
+//! Anything time-dependent (submission timestamps, future time decay) must
+//! produce identical results on the native node and in the zk guest, so
+//! nothing in Veritas may read the wall clock. Height and time instead come
+//! from the SDK's ChainState module, which BeliefModule references:
+//!
+//! - `current_block` is ChainState's rollup height
+//! - `block_timestamp` is ChainState's slot time, taken from the DA block header
+//!
+//! Both are part of the state every node and prover agree on, so the result
+//! never depends on when a node happened to process the block.
+
+use anyhow::Result;
+use sov_modules_api::{Spec, TxState};
+
+use crate::BeliefModule;
+
+impl<S: Spec> BeliefModule<S> {
+    /// Authoritative timestamp (Unix seconds) of the current block
+    ///
+    /// Route every time-dependent Veritas computation through this accessor.
+    /// Times before the Unix epoch read as 0.
+    pub fn block_timestamp(&self, state: &mut impl TxState<S>) -> Result<u64> {
+        let time = self.chain_state.get_time(state)?;
+        Ok(u64::try_from(time.secs()).unwrap_or(0))
+    }
+}
@@ -0,0 +1,81 @@
+//! Aggregate decay - letting stale submissions fade
+//!
+//! FILE PURPOSE:
+//! In a plain running average an early submission keeps its full weight
+//! forever, so opinions from long ago can anchor the consensus. With decay
+//! enabled, the weight already behind an aggregate shrinks by a fixed
+//! fraction per block before each new submission is folded in:
+//!   behind = behind × (retain / SCALE)^(blocks since the last update)
+//! A fresh submission therefore moves an aggregate more the longer it sat idle.
+//!
+//! HOW IT WORKS:
+//! - The mode is deployment-wide (set at genesis) and off by default
+//! - Only Raw weighting folds running totals; MeanMultipleCap and weighted-median
+//!   beliefs recompute from every contribution and ignore decay
+//! - The decayed weight lives in `Belief::decayed_weight`; `total_weight` stays
+//!   the raw sum because quorums, rewards and integrity checks rely on it
+//! - A prior decays like any other weight behind the aggregate
+//! - Replays (overwritten or retracted submissions, integrity repairs) don't
+//!   know when each submission was made, so they rebuild the undecayed average
+//!
+//! Integer math only: the factor is fixed-point exponentiation by squaring,
+//! rounding down at every step, so every node computes the same weights.
+
+use schemars::JsonSchema;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+
+use crate::{AggregationMode, Belief, WeightingMode, SCALE};
+
+/// How weight behind a belief's aggregate fades between submissions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateDecay {
+    /// Submissions keep their full weight forever
+    #[default]
+    None,
+    /// Each block keeps `retain` / SCALE of the weight (9900 = 1% decay per block)
+    /// Must be at most SCALE
+    PerBlock { retain: u64 },
+}
+
+/// (retain / SCALE)^blocks in SCALE units
+pub fn decay_factor(retain: u64, blocks: u64) -> u128 {
+    let scale = SCALE as u128;
+    let mut factor = scale;
+    let mut base = (retain as u128).min(scale);
+    let mut remaining = blocks;
+
+    while remaining > 0 && factor > 0 {
+        if remaining & 1 == 1 {
+            factor = factor * base / scale;
+        }
+        base = base * base / scale;
+        remaining >>= 1;
+    }
+    factor
+}
+
+impl Belief {
+    /// Decays the weight behind the aggregate up to `height`
+    ///
+    /// Called right before a submission is folded in; the caller records
+    /// `height` as the belief's `last_update_height` afterwards.
+    pub fn apply_decay(&mut self, decay: AggregateDecay, height: u64) {
+        let AggregateDecay::PerBlock { retain } = decay else {
+            return;
+        };
+        if self.weighting_mode != WeightingMode::Raw || self.aggregation != AggregationMode::Mean {
+            return;
+        }
+
+        let prior_weight = self.prior.as_ref().map_or(0, |prior| prior.weight) as u128;
+        let behind = self.decayed_weight.unwrap_or(self.total_weight + prior_weight);
+        let factor = decay_factor(retain, height.saturating_sub(self.last_update_height));
+        let scale = SCALE as u128;
+
+        self.decayed_weight = Some(behind.saturating_mul(factor) / scale);
+        // The spread fades with the weight it was measured over
+        self.weighted_sq_dev = self.weighted_sq_dev.saturating_mul(factor) / scale;
+    }
+}
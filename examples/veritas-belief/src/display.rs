@@ -0,0 +1,83 @@
+//! Display - human-readable renderings of fixed-point probabilities
+//!
+//! FILE PURPOSE:
+//! Aggregates are stored as integers scaled by SCALE (7525 = 0.7525), and the
+//! auto-generated state API hands them out as-is. Clients that don't know the
+//! convention misread them, so the aggregate endpoint (see http.rs) also
+//! returns preformatted decimal and percentage strings derived from SCALE.
+//! Clients doing their own formatting read the scale from `Precision`
+//! (served as `GET /config/precision`) instead of hardcoding it.
+
+use schemars::JsonSchema;
+
+use crate::{BeliefId, SCALE};
+
+/// A belief's aggregate in raw and preformatted forms
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct AggregateDisplay {
+    pub belief_id: BeliefId,
+
+    /// Fixed-point value as stored (0 to SCALE)
+    pub raw: u64,
+
+    /// The value as a decimal with full SCALE precision, e.g. "0.7525"
+    pub decimal: String,
+
+    /// The value as a percentage without trailing zeros, e.g. "75.25%"
+    pub percent: String,
+}
+
+impl AggregateDisplay {
+    pub fn new(belief_id: BeliefId, raw: u64) -> Self {
+        Self {
+            belief_id,
+            raw,
+            decimal: format_decimal(raw),
+            percent: format_percent(raw),
+        }
+    }
+}
+
+/// The fixed-point scale of every probability, for code that shouldn't name SCALE
+pub const fn scale() -> u64 {
+    SCALE
+}
+
+/// Number of decimal places SCALE provides (SCALE is a power of ten)
+fn scale_decimals() -> usize {
+    SCALE.ilog10() as usize
+}
+
+/// How to read fixed-point values: `value / scale`, with `decimals` decimal places
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct Precision {
+    pub scale: u64,
+    pub decimals: u32,
+}
+
+impl Precision {
+    /// The precision this build uses
+    pub fn current() -> Self {
+        Self { scale: scale(), decimals: scale_decimals() as u32 }
+    }
+}
+
+/// Renders a fixed-point value as a decimal with every SCALE digit, e.g. 10000 -> "1.0000"
+pub fn format_decimal(value: u64) -> String {
+    format!("{}.{:0width$}", value / SCALE, value % SCALE, width = scale_decimals())
+}
+
+/// Renders a fixed-point value as a percentage, e.g. 7520 -> "75.2%", 0 -> "0%"
+pub fn format_percent(value: u64) -> String {
+    // Percent keeps two fewer decimal places than the fraction
+    let places = scale_decimals().saturating_sub(2);
+    let unit = 10u64.pow(places as u32);
+    let whole = value / unit;
+    let fraction = value % unit;
+
+    if fraction == 0 {
+        return format!("{}%", whole);
+    }
+    let digits = format!("{:0width$}", fraction, width = places);
+    format!("{}.{}%", whole, digits.trim_end_matches('0'))
+}
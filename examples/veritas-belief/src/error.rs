@@ -0,0 +1,80 @@
+//! Typed errors returned by BeliefModule
+//!
+//! Surfaced as `anyhow::Error` like every other module error; callers recover
+//! the exact kind with `err.downcast_ref::<BeliefError>()`. The Display texts
+//! match the messages these cases had before they were typed.
+//!
+//! The admin check lives here too, so AgentModule and SubmissionModule report
+//! unauthorized governance calls as BeliefErrors as well. Clients wanting one
+//! type for every module use `VeritasError` (veritas-submission's error.rs).
+
+use std::fmt;
+
+use crate::{BeliefId, SCALE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BeliefError {
+    /// No live belief has this ID (never created, or archived)
+    NotFound { belief_id: BeliefId },
+
+    /// The belief was settled with an outcome and is frozen
+    Resolved { belief_id: BeliefId },
+
+    /// The belief was closed without an outcome
+    Voided { belief_id: BeliefId },
+
+    /// The current block is past the belief's submission deadline
+    PastDeadline { belief_id: BeliefId, deadline_block: u64 },
+
+    /// A submitted value is above SCALE
+    ValueOutOfRange { value: u64 },
+
+    /// An admin-only action was sent by someone else
+    NotAdmin,
+
+    /// An admin-only action was sent but genesis configured no admin
+    NoAdmin,
+
+    /// A question is longer than the configured maximum, both in bytes
+    QuestionTooLong { len: u64, max: u64 },
+
+    /// A submission carried no weight, so it couldn't move the aggregate
+    ZeroWeight,
+
+    /// A value isn't a multiple of the belief's `value_step` (its tick size)
+    /// and the belief rejects rather than rounds such values
+    ValueOffStep { belief_id: BeliefId, value: u64, value_step: u64 },
+
+    /// Every belief ID has been handed out; the counter can't advance past u64::MAX
+    IdsExhausted,
+}
+
+impl fmt::Display for BeliefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BeliefError::NotFound { .. } => write!(f, "Belief not found"),
+            BeliefError::Resolved { belief_id } => write!(f, "Belief {} is already resolved", belief_id),
+            BeliefError::Voided { belief_id } => write!(f, "Belief {} has been voided", belief_id),
+            BeliefError::PastDeadline { belief_id, deadline_block } => write!(
+                f,
+                "Belief {} stopped accepting submissions at block {}",
+                belief_id, deadline_block
+            ),
+            BeliefError::ValueOutOfRange { .. } => write!(f, "Value must be between 0 and {}", SCALE),
+            BeliefError::NotAdmin => write!(f, "Only the admin can perform this action"),
+            BeliefError::NoAdmin => write!(f, "No admin configured"),
+            BeliefError::QuestionTooLong { len, max } => {
+                write!(f, "Question is {} bytes long, above the maximum of {}", len, max)
+            }
+            BeliefError::ZeroWeight => write!(f, "Submission weight must be above 0"),
+            BeliefError::ValueOffStep { belief_id, value, value_step } => write!(
+                f,
+                "Value {} is not a multiple of belief {}'s value step {}",
+                value, belief_id, value_step
+            ),
+            BeliefError::IdsExhausted => write!(f, "No belief IDs left to assign"),
+        }
+    }
+}
+
+impl std::error::Error for BeliefError {}
@@ -0,0 +1,88 @@
+//! Aggregate history - one point per belief per rollup block, for charts
+//!
+//! FILE PURPOSE:
+//! Beliefs only store their current aggregate, so a chart of how consensus
+//! moved over time would need to replay every submission. With history
+//! enabled, BeliefModule's `end_rollup_block_hook` records the aggregate of
+//! every open belief at the end of each block.
+//!
+//! HOW IT WORKS:
+//! - Off by default; the genesis `aggregate_history` flag enables it
+//! - Points go to ACCESSORY state, keyed by rollup height: readable
+//!   natively (REST, indexers) but never part of provable state or the zk proof
+//! - The height is the block that just ended, i.e. `current_block` during its transactions
+//! - Only blocks ending with at least one open belief get an entry
+//! - Resolved, voided and archived beliefs are left out; categorical beliefs too
+//!
+//! OPEN-BELIEFS INDEX:
+//! The hook reads `open_beliefs`, the ascending IDs of open binary beliefs,
+//! rather than scanning every ID ever assigned. Creation and genesis add to it;
+//! resolution, voiding and merging remove from it; a rollback puts the belief
+//! back if it reopens. Archiving needs no update, as only closed beliefs can be
+//! archived. A block's cost is therefore one read per open belief.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+#[cfg(feature = "native")]
+use sov_modules_api::ApiStateAccessor;
+
+use crate::{BeliefId, BeliefModule};
+
+/// A belief's aggregate at the end of a block
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct AggregatePoint {
+    pub belief_id: BeliefId,
+    pub aggregate: u64,
+}
+
+impl<S: Spec> BeliefModule<S> {
+    /// Records every open belief's aggregate under the current block height
+    ///
+    /// Called from `end_rollup_block_hook`, while ChainState still reports the ending block.
+    /// No-op while history is disabled.
+    pub(crate) fn record_aggregate_history(&mut self, state: &mut impl TxState<S>) -> Result<()> {
+        if !self.aggregate_history_enabled.get(state)?.unwrap_or(false) {
+            return Ok(());
+        }
+
+        let mut points = Vec::new();
+        for belief_id in self.open_beliefs.get(state)?.unwrap_or_default() {
+            if let Some(belief) = self.beliefs.get(&belief_id, state)? {
+                points.push(AggregatePoint { belief_id, aggregate: belief.aggregate });
+            }
+        }
+        if !points.is_empty() {
+            let height = self.current_block(state)?;
+            self.aggregate_history.set(&height, &points, state)?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds `belief_id` to the open-beliefs index, or removes it, keeping it sorted
+    pub(crate) fn set_open(&mut self, belief_id: BeliefId, open: bool, state: &mut impl TxState<S>) -> Result<()> {
+        let mut open_ids = self.open_beliefs.get(state)?.unwrap_or_default();
+        match (open_ids.binary_search(&belief_id), open) {
+            (Err(at), true) => open_ids.insert(at, belief_id),
+            (Ok(at), false) => {
+                open_ids.remove(at);
+            }
+            _ => return Ok(()),
+        }
+        self.open_beliefs.set(&open_ids, state)?;
+        Ok(())
+    }
+
+    /// Aggregates of the beliefs open at the end of block `height` (native-only)
+    ///
+    /// None if no history was recorded for that block, e.g. because no belief was open.
+    #[cfg(feature = "native")]
+    pub fn get_aggregate_history(
+        &self,
+        height: u64,
+        state: &mut ApiStateAccessor<S>,
+    ) -> Result<Option<Vec<AggregatePoint>>> {
+        Ok(self.aggregate_history.get(&height, state)?)
+    }
+}
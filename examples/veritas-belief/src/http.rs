@@ -0,0 +1,130 @@
+//! Custom REST endpoints for BeliefModule
+//!
+//! Served next to the auto-generated state API (`ModuleRestApi`).
+//!
+//! - `GET /beliefs?offset=&limit=&status=&category=`: a page of belief states in ID order,
+//!   optionally only `open` or `resolved` ones, and only one category (`*` for all)
+//!   (see `BeliefModule::list_beliefs`)
+//! - `GET /beliefs/top?n=`: the `n` beliefs with the most total weight, ties by ID
+//!   (see `BeliefModule::top_beliefs_by_weight`)
+//! - `GET /beliefs/{beliefId}/aggregate`: the aggregate as raw fixed-point,
+//!   decimal and percentage (see display.rs)
+//! - `GET /history/{height}`: open beliefs' aggregates at the end of a block
+//!   (see history.rs; 404 if none were recorded)
+//! - `GET /beliefs/{beliefId}/history`: the belief's latest aggregates, oldest
+//!   first (see recent.rs; 404 for unknown beliefs)
+//!
+//! Native-only: the zk guest never serves HTTP.
+
+use axum::extract::Query;
+use axum::routing::get;
+use sov_modules_api::prelude::utoipa::openapi::OpenApi;
+use sov_modules_api::rest::utils::{errors, ApiResult, Path};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::{ApiStateAccessor, Spec};
+
+use crate::{
+    AggregateDisplay, AggregatePoint, BeliefError, BeliefId, BeliefModule, BeliefPage, BeliefState, BeliefStatus, MAX_BELIEF_PAGE_SIZE,
+    MAX_TOP_BELIEFS,
+};
+
+#[derive(serde::Deserialize)]
+struct PageParams {
+    #[serde(default)]
+    offset: u64,
+    limit: Option<u64>,
+    status: Option<BeliefStatus>,
+    category: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct TopParams {
+    n: Option<u64>,
+}
+
+impl<S: Spec> BeliefModule<S> {
+    async fn route_beliefs(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Query(params): Query<PageParams>,
+    ) -> ApiResult<BeliefPage> {
+        let limit = params.limit.unwrap_or(MAX_BELIEF_PAGE_SIZE);
+        let page = state
+            .list_beliefs(params.offset, limit, params.status, params.category.as_deref(), &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?;
+
+        Ok(page.into())
+    }
+
+    async fn route_top_beliefs(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Query(params): Query<TopParams>,
+    ) -> ApiResult<Vec<BeliefState>> {
+        let beliefs = state
+            .top_beliefs_by_weight(params.n.unwrap_or(MAX_TOP_BELIEFS), &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?;
+
+        Ok(beliefs.into())
+    }
+
+    async fn route_aggregate(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Path(belief_id): Path<BeliefId>,
+    ) -> ApiResult<AggregateDisplay> {
+        let belief = state
+            .beliefs
+            .get(&belief_id, &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?
+            .ok_or_else(|| errors::not_found_404("Belief", belief_id))?;
+
+        Ok(AggregateDisplay::new(belief_id, belief.aggregate).into())
+    }
+
+    async fn route_recent_aggregates(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Path(belief_id): Path<BeliefId>,
+    ) -> ApiResult<Vec<u64>> {
+        let aggregates = state
+            .get_recent_aggregates(belief_id, &mut accessor)
+            .map_err(|e| match e.downcast_ref::<BeliefError>() {
+                Some(BeliefError::NotFound { .. }) => errors::not_found_404("Belief", belief_id),
+                _ => errors::internal_server_error_response_500(e.to_string()),
+            })?;
+
+        Ok(aggregates.into())
+    }
+
+    async fn route_history(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Path(height): Path<u64>,
+    ) -> ApiResult<Vec<AggregatePoint>> {
+        let points = state
+            .get_aggregate_history(height, &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?
+            .ok_or_else(|| errors::not_found_404("Block", height))?;
+
+        Ok(points.into())
+    }
+}
+
+impl<S: Spec> HasCustomRestApi for BeliefModule<S> {
+    type Spec = S;
+
+    fn custom_rest_api(&self, state: ApiState<S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route("/beliefs", get(Self::route_beliefs))
+            .route("/beliefs/top", get(Self::route_top_beliefs))
+            .route("/beliefs/:beliefId/aggregate", get(Self::route_aggregate))
+            .route("/beliefs/:beliefId/history", get(Self::route_recent_aggregates))
+            .route("/history/:height", get(Self::route_history))
+            .with_state(state.with(self.clone()))
+    }
+
+    fn custom_openapi_spec(&self) -> Option<OpenApi> {
+        None
+    }
+}
@@ -0,0 +1,61 @@
+//! Legacy genesis values - reading f64-era probabilities as fixed-point
+//!
+//! FILE PURPOSE:
+//! Beliefs used to hold probabilities as f64 in 0.0..=1.0 before the switch
+//! to u64 fixed-point (see SCALE). Genesis files written back then still say
+//! `"aggregate": 0.75`. The genesis probability fields of `Belief` (`aggregate`
+//! and `initial_value`) accept both forms through `deserialize_probability`:
+//! - An integer is already fixed-point and is taken as is: `7500`
+//! - A float is a legacy probability: `0.75` becomes 0.75 × SCALE = 7500,
+//!   rounded to the nearest unit. It must lie in 0.0..=1.0
+//!
+//! Integers are never rescaled, so a legacy `1` (rather than `1.0`) reads as
+//! 1 / SCALE; such files need their certain and impossible values written as floats.
+//!
+//! DETERMINISM:
+//! Floats are only ever read here, while parsing genesis, never in state
+//! transitions. The conversion is a single IEEE multiplication and rounding,
+//! identical on every node.
+
+use serde::{Deserialize, Deserializer};
+
+use crate::SCALE;
+
+/// A probability as found in a genesis file
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GenesisProbability {
+    FixedPoint(u64),
+    Legacy(f64),
+}
+
+impl GenesisProbability {
+    fn to_fixed_point<E: serde::de::Error>(self) -> Result<u64, E> {
+        match self {
+            GenesisProbability::FixedPoint(value) => Ok(value),
+            GenesisProbability::Legacy(probability) => {
+                if !(0.0..=1.0).contains(&probability) {
+                    return Err(E::custom(format!(
+                        "Legacy probability {} must be between 0.0 and 1.0",
+                        probability
+                    )));
+                }
+                Ok((probability * SCALE as f64).round() as u64)
+            }
+        }
+    }
+}
+
+/// Reads a fixed-point value, or a legacy float probability converted to fixed-point
+pub fn deserialize_probability<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    GenesisProbability::deserialize(deserializer)?.to_fixed_point()
+}
+
+/// `deserialize_probability` for optional fields
+pub fn deserialize_optional_probability<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error> {
+    Option::<GenesisProbability>::deserialize(deserializer)?
+        .map(GenesisProbability::to_fixed_point)
+        .transpose()
+}
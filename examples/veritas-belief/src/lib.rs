@@ -8,7 +8,7 @@
 //! - Stores the questions and current consensus values
 //! - Implements the weighted average aggregation formula
 //! - Called by SubmissionModule to update aggregates
-//! - Does NOT expose public transactions (internal use only)
+//! - Only exposes admin (governance) transactions, e.g. resolution
 //!
 //! CHANGES MADE:
 //! - Created from scratch following spec
@@ -16,6 +16,56 @@
 //! - Genesis loads initial beliefs from config
 //! - CallMessage only has NoOp (update_aggregate is internal)
 //! - REFACTORED: Using u64 fixed-point math (scale 10000) instead of f64 for determinism
+//! - Added admin-gated resolution guarded by a configurable participation quorum
+//! - Added opt-in per-belief weighting modes (see weighting.rs)
+//! - Added a rollup block counter and optional per-belief submission deadlines
+//! - Added a deterministic block timestamp derived from the counter (see clock.rs)
+//! - Added an opt-in weighted interval (25th-75th percentile) and median per belief
+//! - Added a per-belief minimum agent tier
+//! - Added a REST endpoint rendering aggregates as decimals and percentages (see http.rs)
+//! - Made first-submission anchoring explicit and configurable (see weighting.rs)
+//! - Added admin-only CreateBelief, announcing the assigned ID in a BeliefCreated event
+//! - Added `recompute_aggregate` for submissions that overwrite an earlier one
+//! - Added paginated belief listing, also served as `GET /beliefs` (see http.rs)
+//! - Tracks a weighted variance of submissions next to the aggregate (Welford update)
+//! - Beliefs remember their initial value, which replays (e.g. after retractions) restart from
+//! - Added categorical (multi-outcome) beliefs aggregating whole distributions (see categorical.rs)
+//! - Widened `total_weight` to u128 so it can't saturate and skew the weighted average
+//! - Belief states expose resolution; `GET /beliefs?status=open|resolved` filters on it
+//! - Optional per-block decay of the weight behind aggregates (see decay.rs)
+//! - Beliefs carry a category set at creation; `GET /beliefs?category=` filters on it
+//! - Optional per-belief snapshots and admin-only rollback for disputes (see snapshot.rs)
+//! - Added a genesis-selected weighted median aggregation mode (see weighting.rs)
+//! - Added read-only `preview_update` / `preview_recompute` mirroring the aggregate updates
+//! - Genesis rejects duplicate belief IDs and starts new IDs past the largest one
+//! - Every aggregate update emits an AggregateUpdated event for indexers and charts
+//! - Aggregate updates fail with typed BeliefErrors for missing, resolved, voided and expired beliefs
+//! - Beliefs can require a minimum total weight before their aggregate counts as valid
+//! - Governance can amend a belief's question until its first submission
+//! - The WeightedMedian aggregate interpolates exact half-weight splits deterministically
+//! - Optional per-block aggregate history in accessory state, also served as `GET /history/{height}`
+//! - Genesis-selected rounding of the running mean: truncate (default) or round half up (see rounding.rs)
+//! - Added `top_beliefs_by_weight`, also served as `GET /beliefs/top?n=`
+//! - Added admin-only VoidBelief, closing a moot belief without an outcome
+//! - Missing beliefs, out-of-range values and failed admin checks are typed BeliefErrors too
+//! - Questions are capped at a genesis-configured byte length (DEFAULT_MAX_QUESTION_LEN by default)
+//! - Exposes the fixed-point precision as `scale()` / `Precision` (see display.rs)
+//! - Beliefs track `last_delta`, how far the latest change moved the aggregate, as a stability signal
+//! - Added `merge_beliefs`, rebuilding one belief from two logs and deleting the duplicate (driven by SubmissionModule)
+//! - Optional per-belief `min_aggregate` / `max_aggregate` bounds the aggregate is clamped into
+//! - Resolution emits a BeliefResolved event with the belief's final aggregate, weight and count
+//! - Genesis beliefs accept legacy f64 probabilities (0.0-1.0) besides fixed-point values (see legacy.rs)
+//! - `update_aggregate` rejects zero-weight submissions, which only inflated submission counts
+//! - Optional ring of each belief's last K aggregates, also served as `GET /beliefs/{id}/history` (see recent.rs)
+//! - Values off a belief's `value_step` fail with a typed `BeliefError::ValueOffStep`
+//! - Resolution that voids a belief below quorum emits BeliefVoided like VoidBelief does
+//! - CreateBelief carries every per-belief setting (BeliefOptions), not just the category
+//! - Rollbacks are driven by SubmissionModule's RollbackBelief, which rewinds the submission log too
+//! - Block height and timestamps are read from ChainState, replacing the Veritas block counter and ClockConfig
+//! - Creating a belief once the ID counter reaches u64::MAX fails with `BeliefError::IdsExhausted` instead of overflowing
+//! - Categorical updates reject zero-weight submissions like binary ones do
+//! - Aggregate history visits an index of open beliefs and skips blocks with none (see history.rs)
+//! - Added `get_belief_state_ref`, reading a belief's state through the SDK's read-only StateReader
 //!
 //! This module handles:
 //! - Storage of beliefs (questions about future events)
@@ -31,11 +81,56 @@ use anyhow::{bail, Result};
 use schemars::JsonSchema;
 use sov_modules_api::macros::{serialize, UniversalWallet};
 use sov_modules_api::{
-    Context, Module, ModuleId, ModuleInfo, ModuleRestApi, Spec,
-    StateMap, StateValue, TxState,
+    AccessoryStateMap, Context, EventEmitter, Module, ModuleId, ModuleInfo, ModuleRestApi, Spec,
+    StateCheckpoint, StateMap, StateReader, StateValue, TxState,
 };
+use sov_state::User;
+#[cfg(feature = "native")]
+use sov_modules_api::ApiStateAccessor;
 use std::marker::PhantomData;
 
+mod categorical;
+pub use categorical::*;
+
+mod clock;
+pub use clock::*;
+
+mod decay;
+pub use decay::*;
+
+mod display;
+pub use display::*;
+
+mod error;
+pub use error::*;
+
+mod history;
+pub use history::*;
+
+#[cfg(feature = "native")]
+mod http;
+
+mod legacy;
+pub use legacy::*;
+
+mod permissions;
+pub use permissions::*;
+
+mod recent;
+pub use recent::*;
+
+mod rounding;
+pub use rounding::*;
+
+mod snapshot;
+pub use snapshot::*;
+
+mod tier;
+pub use tier::*;
+
+mod weighting;
+pub use weighting::*;
+
 /// Type alias for belief identifiers
 /// Using u64 allows for up to 18 quintillion unique beliefs
 pub type BeliefId = u64;
@@ -46,6 +141,19 @@ pub type BeliefId = u64;
 /// Examples: 5000 = 0.5000, 7525 = 0.7525, 10000 = 1.0000
 pub const SCALE: u64 = 10000;
 
+/// Category of beliefs created without one, including genesis beliefs that predate categories
+pub const DEFAULT_CATEGORY: &str = "uncategorized";
+
+/// Category filter matching every belief; reserved, so no belief can be created in it
+pub const ANY_CATEGORY: &str = "*";
+
+/// Longest question (in bytes) when genesis doesn't configure a limit
+pub const DEFAULT_MAX_QUESTION_LEN: u64 = 512;
+
+fn default_max_question_len() -> u64 {
+    DEFAULT_MAX_QUESTION_LEN
+}
+
 /// Belief represents a prediction market/question
 /// Agents submit probability estimates which are aggregated into consensus
 #[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
@@ -55,24 +163,541 @@ pub struct Belief {
     
     /// The question being predicted (e.g., "Will ETH exceed $5000 by Dec 2024?")
     pub question: String,
+
+    /// Topic tag for listings (e.g. "crypto", "sports")
+    #[serde(default = "default_category")]
+    pub category: String,
     
     /// Current weighted average of all submissions (0 to 10000)
     /// This represents the collective probability estimate
     /// 10000 = 100% probability, 5000 = 50%, 0 = 0%
+    /// Genesis files may still give it as a legacy float (see legacy.rs)
+    #[serde(deserialize_with = "deserialize_probability")]
     pub aggregate: u64,
     
     /// Sum of all weights that have contributed to this belief
     /// Used in weighted average calculations
-    pub total_weight: u64,
+    /// u128: a sum of u64 weights can't overflow it, so the average never
+    /// divides by a pinned denominator
+    pub total_weight: u128,
+
+    /// Value the belief was created with; the aggregate returns to it once
+    /// every submission has been retracted
+    /// None for genesis beliefs that don't specify it (genesis fills in the aggregate)
+    #[serde(default, deserialize_with = "deserialize_optional_probability")]
+    pub initial_value: Option<u64>,
+
+    /// Weighted variance of submitted values around the aggregate, in SCALE² units
+    /// (0 = full agreement, 25_000_000 = an even split between 0 and SCALE)
+    #[serde(default)]
+    pub variance: u64,
+
+    /// Welford accumulator behind `variance`: Σ weight × (value - mean)²
+    #[serde(default)]
+    pub weighted_sq_dev: u128,
+
+    /// Whether the event has been settled with a final outcome
+    /// Resolved beliefs no longer accept submissions
+    #[serde(default)]
+    pub resolved: bool,
+
+    /// Final outcome once resolved: 0 (did not happen) or SCALE (happened)
+    #[serde(default)]
+    pub outcome: Option<u64>,
+
+    /// Whether the belief was closed without an outcome
+    /// (too little participation to resolve it meaningfully, or voided by governance)
+    #[serde(default)]
+    pub voided: bool,
+
+    /// Per-belief override of the deployment-wide resolution quorum
+    /// None means the genesis `default_resolution_quorum` applies
+    #[serde(default)]
+    pub resolution_quorum: Option<ResolutionQuorum>,
+
+    /// Submission granularity in SCALE units (e.g. 500 = 5% buckets)
+    /// 1 accepts every value in 0..=SCALE
+    #[serde(default = "default_value_step")]
+    pub value_step: u64,
+
+    /// What to do with a submitted value that isn't a multiple of `value_step`
+    #[serde(default)]
+    pub value_step_mode: ValueStepMode,
+
+    /// How submission weights are post-processed before aggregating
+    #[serde(default)]
+    pub weighting_mode: WeightingMode,
+
+    /// Statistic used as the aggregate, fixed when the belief is created
+    #[serde(default)]
+    pub aggregation: AggregationMode,
+
+    /// Rounding of the running mean, fixed when the belief is created (see rounding.rs)
+    #[serde(default)]
+    pub rounding: RoundingMode,
+
+    /// Last block height (inclusive) at which submissions are accepted
+    /// None means the belief is open-ended
+    #[serde(default)]
+    pub deadline_block: Option<u64>,
+
+    /// Whether `get_belief_state` reports a weighted median and interval
+    /// Requires keeping every contribution, like non-Raw weighting modes
+    #[serde(default)]
+    pub report_interval: bool,
+
+    /// Lowest agent tier allowed to submit (Novice admits everyone)
+    #[serde(default)]
+    pub min_tier: AgentTier,
+
+    /// Total weight below which the aggregate is reported as premature
+    /// (see `BeliefState::quorum_met`); 0 treats any aggregate as valid
+    #[serde(default)]
+    pub min_quorum_weight: u64,
+
+    /// Initial value kept in the average as a pseudo-submission
+    /// None: the first submission replaces the initial value (see weighting.rs)
+    #[serde(default)]
+    pub prior: Option<Contribution>,
+
+    /// Block height of the latest submission (creation height before any)
+    #[serde(default)]
+    pub last_update_height: u64,
+
+    /// Weight behind the aggregate after decay (see decay.rs)
+    /// None while no decay has applied: then it is total_weight plus the prior's weight
+    #[serde(default)]
+    pub decayed_weight: Option<u128>,
+
+    /// How far (SCALE units) the latest submission, overwrite or retraction moved the aggregate
+    /// Near 0 once new submissions barely move it, i.e. the consensus has settled
+    #[serde(default)]
+    pub last_delta: u64,
+
+    /// Lowest aggregate the belief may report, set at creation
+    #[serde(default)]
+    pub min_aggregate: u64,
+
+    /// Highest aggregate the belief may report, set at creation
+    /// Together with `min_aggregate`, keeps markets away from literal certainty
+    #[serde(default = "default_max_aggregate")]
+    pub max_aggregate: u64,
+}
+
+fn default_value_step() -> u64 {
+    1
+}
+
+fn default_max_aggregate() -> u64 {
+    SCALE
+}
+
+fn default_category() -> String {
+    DEFAULT_CATEGORY.to_string()
+}
+
+/// Handling of submitted values that fall between two buckets
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueStepMode {
+    /// Reject the submission
+    #[default]
+    Reject,
+    /// Snap to the nearest bucket (halfway rounds up, never above SCALE)
+    Round,
+}
+
+/// Optional settings chosen when a belief is created, e.g. by a CreateBelief transaction
+/// Defaults reproduce a plain belief with full SCALE precision
+#[derive(Debug, Clone, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub struct BeliefOptions {
+    pub value_step: u64,
+    pub value_step_mode: ValueStepMode,
+    pub weighting_mode: WeightingMode,
+    pub deadline_block: Option<u64>,
+    pub report_interval: bool,
+    pub min_tier: AgentTier,
+    pub first_submission: FirstSubmissionAnchor,
+    pub category: String,
+    pub min_quorum_weight: u64,
+    pub min_aggregate: u64,
+    pub max_aggregate: u64,
+}
+
+impl Default for BeliefOptions {
+    fn default() -> Self {
+        Self {
+            value_step: default_value_step(),
+            value_step_mode: ValueStepMode::default(),
+            weighting_mode: WeightingMode::default(),
+            deadline_block: None,
+            report_interval: false,
+            min_tier: AgentTier::default(),
+            first_submission: FirstSubmissionAnchor::default(),
+            category: default_category(),
+            min_quorum_weight: 0,
+            min_aggregate: 0,
+            max_aggregate: default_max_aggregate(),
+        }
+    }
+}
+
+/// Minimum participation required before a belief may be resolved
+///
+/// Prevents an admin from settling a thin market arbitrarily:
+/// both thresholds must be met for resolution to go through.
+/// The default (all zeros) imposes no quorum.
+#[derive(Debug, Clone, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub struct ResolutionQuorum {
+    /// Minimum number of submissions the belief must have received
+    pub min_submissions: u64,
+
+    /// Minimum sum of submission weights the belief must have accumulated
+    pub min_total_weight: u64,
+
+    /// What happens when resolution is attempted below quorum
+    pub below_quorum: BelowQuorumPolicy,
+}
+
+/// Behavior of a resolution attempt on a belief that hasn't reached quorum
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum BelowQuorumPolicy {
+    /// Reject the resolution; the belief stays open
+    #[default]
+    Block,
+    /// Close the belief without an outcome
+    Void,
+}
+
+/// Result of a resolution attempt that didn't fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionResult {
+    /// The belief was settled with the given outcome (0 or SCALE)
+    Resolved { outcome: u64 },
+    /// Quorum wasn't met and the belief was voided instead
+    Voided,
+}
+
+impl Belief {
+    /// Creates an open belief with no submissions yet
+    pub fn new(id: BeliefId, question: String, initial_value: u64) -> Self {
+        Self {
+            id,
+            question,
+            category: default_category(),
+            aggregate: initial_value,
+            total_weight: 0,  // No submissions yet
+            initial_value: Some(initial_value),
+            variance: 0,
+            weighted_sq_dev: 0,
+            resolved: false,
+            outcome: None,
+            voided: false,
+            resolution_quorum: None,
+            value_step: default_value_step(),
+            value_step_mode: ValueStepMode::default(),
+            weighting_mode: WeightingMode::default(),
+            aggregation: AggregationMode::default(),
+            rounding: RoundingMode::default(),
+            deadline_block: None,
+            report_interval: false,
+            min_tier: AgentTier::default(),
+            min_quorum_weight: 0,
+            prior: None,
+            last_update_height: 0,
+            decayed_weight: None,
+            last_delta: 0,
+            min_aggregate: 0,
+            max_aggregate: default_max_aggregate(),
+        }
+    }
+
+    /// A new belief with per-belief settings applied (not validated, see create_belief_with_options)
+    pub fn with_options(id: BeliefId, question: String, initial_value: u64, options: BeliefOptions) -> Self {
+        let mut belief = Self::new(id, question, initial_value);
+        belief.value_step = options.value_step;
+        belief.value_step_mode = options.value_step_mode;
+        belief.weighting_mode = options.weighting_mode;
+        belief.deadline_block = options.deadline_block;
+        belief.report_interval = options.report_interval;
+        belief.min_tier = options.min_tier;
+        belief.category = options.category;
+        belief.min_quorum_weight = options.min_quorum_weight;
+        belief.min_aggregate = options.min_aggregate;
+        belief.max_aggregate = options.max_aggregate;
+        belief.prior = match options.first_submission {
+            FirstSubmissionAnchor::Replace => None,
+            // A zero-weight prior would change nothing in Raw mode but still skew the capped mean
+            FirstSubmissionAnchor::Prior { weight: 0 } => None,
+            FirstSubmissionAnchor::Prior { weight } => Some(Contribution { value: initial_value, weight }),
+        };
+        belief
+    }
+
+    /// This belief rebuilt from scratch out of `records`, with the contributions it keeps
+    ///
+    /// Pure: the caller decides whether to store the result (see `replay_belief`).
+    fn replayed(&self, records: &[Contribution]) -> (Belief, Vec<Contribution>) {
+        let mut belief = self.clone();
+        belief.total_weight = 0;
+        belief.variance = 0;
+        belief.weighted_sq_dev = 0;
+        // Submission heights aren't recorded, so a replay can't redo decay
+        belief.decayed_weight = None;
+        // A prior always carries the initial value
+        if let Some(start) = belief.prior.as_ref().map(|prior| prior.value).or(belief.initial_value) {
+            belief.aggregate = start;
+        }
+        let mut contributions = Vec::new();
+        for record in records {
+            if belief.keeps_contributions() {
+                contributions.push(record.clone());
+            }
+            belief.fold_submission(record.value, record.weight, &contributions);
+        }
+        (belief, contributions)
+    }
+
+    /// Whether every (value, weight) pair has to be stored for this belief
+    pub fn keeps_contributions(&self) -> bool {
+        self.weighting_mode != WeightingMode::Raw
+            || self.aggregation != AggregationMode::Mean
+            || self.report_interval
+    }
+
+    /// Folds one submission into the aggregate and total weight
+    ///
+    /// `contributions` must already include it when the belief keeps contributions.
+    /// Shared by live updates and replays, so both produce identical results.
+    fn fold_submission(&mut self, value: u64, weight: u64, contributions: &[Contribution]) {
+        // WEIGHTED AVERAGE CALCULATION:
+        // This is the heart of the consensus mechanism
+        // Agents with higher weight (stake × score) have more influence
+        // Using integer math to ensure determinism across all nodes
+        // A prior counts as weight already behind the aggregate (see weighting.rs)
+        // Once decay applied, only the decayed weight is still behind the aggregate
+        let prior_weight = self.prior.as_ref().map_or(0, |prior| prior.weight) as u128;
+        let old_total_weight = self.decayed_weight.unwrap_or(self.total_weight + prior_weight);
+        let new_total_weight = old_total_weight + weight as u128;
+
+        if self.aggregation == AggregationMode::WeightedMedian {
+            // Needs every (value, weight) pair; capped weights when a cap applies
+            let mut all = Vec::with_capacity(contributions.len() + 1);
+            all.extend(self.prior.clone());
+            all.extend_from_slice(contributions);
+            if let WeightingMode::MeanMultipleCap { max_multiple } = self.weighting_mode {
+                all = cap_contributions(&all, max_multiple);
+            }
+            // All-zero weights leave the aggregate where it was
+            if let Some(median) = weighted_median(&all) {
+                self.aggregate = median;
+
+                // Spread around the aggregate, i.e. the median here
+                let (sq_dev, total) = weighted_sq_deviation(&all, median);
+                self.weighted_sq_dev = sq_dev;
+                self.variance = (sq_dev / total) as u64;
+            }
+        } else if let WeightingMode::MeanMultipleCap { max_multiple } = self.weighting_mode {
+            // Holistic control: needs the whole weight vector, not just the running totals
+            // All-zero weights leave the aggregate where it was
+            let mut all = Vec::with_capacity(contributions.len() + 1);
+            all.extend(self.prior.clone());
+            all.extend_from_slice(contributions);
+            if let Some(aggregate) = capped_weighted_mean(&all, max_multiple) {
+                self.aggregate = aggregate;
+
+                // The mean moved for everyone, so the spread is recomputed in full too
+                let (sq_dev, effective_total) = capped_sq_deviation(&all, max_multiple, aggregate);
+                self.weighted_sq_dev = sq_dev;
+                self.variance = (sq_dev / effective_total) as u64;
+            }
+        } else if new_total_weight > 0 {
+            let old_aggregate = self.aggregate;
+
+            // Fixed-point weighted average formula
+            // We use u128 for intermediate calculations to prevent overflow
+            // With no weight behind it yet, the old aggregate drops out and the
+            // first submission becomes the aggregate
+            let old_contribution = (self.aggregate as u128) * old_total_weight;
+            let new_contribution = (value as u128) * (weight as u128);
+            let total_contribution = old_contribution + new_contribution;
+
+            // Divide (rounded per the belief's mode) and convert back to u64
+            self.aggregate = self.rounding.divide(total_contribution, new_total_weight) as u64;
+
+            // WEIGHTED WELFORD UPDATE:
+            // M2 += weight × (value - old_mean) × (value - new_mean)
+            // The new mean lies between the old one and the value, so both
+            // factors share a sign and unsigned distances suffice.
+            // u128: weight (u64) × two distances (<= SCALE each) can't overflow
+            let deviation = weight as u128
+                * value.abs_diff(old_aggregate) as u128
+                * value.abs_diff(self.aggregate) as u128;
+            self.weighted_sq_dev = self.weighted_sq_dev.saturating_add(deviation);
+            self.variance = (self.weighted_sq_dev / new_total_weight) as u64;
+        }
+        // else: zero weight with nothing before it, the aggregate stays put
+
+        // AGGREGATE BOUNDS:
+        // The clamped aggregate is what the next fold starts from, so weight
+        // beyond a bound is absorbed rather than banked for later
+        // (max/min instead of clamp: never panics, even on inverted bounds)
+        self.aggregate = self.aggregate.max(self.min_aggregate).min(self.max_aggregate);
+
+        // Update total weight (u128, so this can't realistically overflow)
+        self.total_weight += weight as u128;
+        if self.decayed_weight.is_some() {
+            self.decayed_weight = Some(new_total_weight);
+        }
+    }
+
+    /// Whether the aggregate is bounded more tightly than 0..=SCALE
+    pub fn has_aggregate_bounds(&self) -> bool {
+        self.min_aggregate > 0 || self.max_aggregate < SCALE
+    }
+
+    /// Applies this belief's value granularity to a submitted value
+    ///
+    /// Aligned values pass through unchanged. Misaligned ones are rejected
+    /// or snapped to the nearest bucket depending on `value_step_mode`.
+    pub fn normalize_value(&self, value: u64) -> Result<u64> {
+        let step = self.value_step.max(1);
+        let remainder = value % step;
+        if remainder == 0 {
+            return Ok(value);
+        }
+
+        match self.value_step_mode {
+            ValueStepMode::Reject => Err(BeliefError::ValueOffStep {
+                belief_id: self.id,
+                value,
+                value_step: step,
+            }.into()),
+            ValueStepMode::Round => {
+                let lower = value - remainder;
+                let upper = lower + step;
+                // Round half up, but never past SCALE when SCALE isn't a multiple of the step
+                if remainder * 2 >= step && upper <= SCALE {
+                    Ok(upper)
+                } else {
+                    Ok(lower)
+                }
+            }
+        }
+    }
+}
+
+/// Final, historical view of a closed (resolved or voided) belief
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ResolvedBeliefView {
+    pub id: BeliefId,
+    pub question: String,
+    /// 0 or SCALE; None if the belief was voided
+    pub outcome: Option<u64>,
+    pub voided: bool,
+    /// Aggregate at the moment the belief was closed
+    pub final_aggregate: u64,
+    pub total_weight: u128,
+    /// Number of submissions the belief received
+    pub participant_count: u64,
+    /// true if served from the accessory archive rather than live state
+    pub archived: bool,
+}
+
+/// Consolidated status of a belief for front ends (countdowns, quorum bars)
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct BeliefProgress {
+    pub id: BeliefId,
+    pub current_block: u64,
+    pub deadline_block: Option<u64>,
+    /// Blocks left to submit (0 once the deadline has passed); None if open-ended
+    pub blocks_remaining: Option<u64>,
+    pub submission_count: u64,
+    pub total_weight: u128,
+    /// Effective quorum; None when no quorum applies (all thresholds zero)
+    pub quorum: Option<ResolutionQuorum>,
+    pub quorum_met: bool,
+    /// Whether the belief is still open to submissions
+    pub accepting_submissions: bool,
+    /// Whether an admin resolution would currently settle the belief with an outcome
+    pub resolution_permitted: bool,
 }
 
 #[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
 pub struct BeliefState {
     pub id: BeliefId,
     pub question: String,
+    pub category: String,
     pub aggregate: u64,  // Fixed-point: 0-10000 representing 0.0-1.0
-    pub total_weight: u64,
+    pub total_weight: u128,
     pub submission_count: u64,
+    /// Weighted variance of submissions in SCALE² units; its square root is
+    /// the standard deviation in SCALE units
+    pub variance: u64,
+    /// Weighted median of submissions (interval-reporting beliefs only)
+    pub median: Option<u64>,
+    /// Weighted 25th percentile; None without enough submissions or when not reported
+    pub interval_low: Option<u64>,
+    /// Weighted 75th percentile; None without enough submissions or when not reported
+    pub interval_high: Option<u64>,
+    /// Whether the belief has been settled with an outcome
+    pub resolved: bool,
+    /// 0 or SCALE once resolved
+    pub outcome: Option<u64>,
+    /// Whether `total_weight` reached the belief's `min_quorum_weight`
+    /// The aggregate is always filled in; while this is false it is premature
+    /// and UIs should label it as such
+    pub quorum_met: bool,
+    /// How far the latest change moved the aggregate; small values mean a stable consensus
+    pub last_delta: u64,
+}
+
+/// Which beliefs `list_beliefs` keeps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BeliefStatus {
+    /// Not resolved yet (voided beliefs are closed, so they don't count)
+    Open,
+    /// Settled with an outcome
+    Resolved,
+}
+
+impl BeliefStatus {
+    /// Whether `belief` is currently in this status
+    pub fn matches(&self, belief: &Belief) -> bool {
+        match self {
+            Self::Open => !belief.resolved && !belief.voided,
+            Self::Resolved => belief.resolved,
+        }
+    }
+}
+
+/// Largest page `list_beliefs` returns; bigger requests are clamped to it
+pub const MAX_BELIEF_PAGE_SIZE: u64 = 100;
+
+/// Most beliefs `top_beliefs_by_weight` returns; bigger requests are clamped to it
+pub const MAX_TOP_BELIEFS: u64 = 50;
+
+/// One page of beliefs, in ID order
+#[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct BeliefPage {
+    /// Number of IDs assigned so far; IDs run from 1 to `total`
+    pub total: u64,
+    /// IDs skipped before this page
+    pub offset: u64,
+    /// Page size actually applied (after clamping)
+    pub limit: u64,
+    /// Beliefs with IDs offset+1 ..= offset+limit
+    /// Archived beliefs and those not matching the filters are left out,
+    /// so a page can hold fewer than `limit`
+    pub beliefs: Vec<BeliefState>,
 }
 
 /// BeliefModule manages all prediction markets in the system
@@ -92,6 +717,10 @@ pub struct BeliefModule<S: Spec> {
     #[state]
     pub beliefs: StateMap<BeliefId, Belief>,
 
+    /// Multi-outcome beliefs (see categorical.rs), keyed by IDs from the shared counter
+    #[state]
+    pub categorical_beliefs: StateMap<BeliefId, CategoricalBelief>,
+
     /// Counter for generating sequential belief IDs
     /// StateValue stores a single value (not a map)
     #[state]
@@ -102,15 +731,91 @@ pub struct BeliefModule<S: Spec> {
     #[state]
     pub submission_counts: StateMap<BeliefId, u64>,
 
+    /// Governance address allowed to resolve beliefs
+    /// Unset means no one can perform admin actions
+    #[state]
+    pub admin: StateValue<S::Address>,
+
+    /// Resolution quorum applied to beliefs without their own override
+    #[state]
+    pub default_resolution_quorum: StateValue<ResolutionQuorum>,
+
+    /// Closed beliefs moved out of provable state
+    /// Accessory state is only readable natively (REST/indexers), never in-circuit,
+    /// so archived beliefs can't be touched by transactions anymore
+    #[state]
+    pub archived_beliefs: AccessoryStateMap<BeliefId, ResolvedBeliefView>,
+
+    /// Source of the rollup height and slot time (see clock.rs)
+    /// Veritas deadlines and stake locks are measured against its height
+    #[module]
+    pub chain_state: sov_chain_state::ChainState<S>,
+
+    /// Decay of the weight behind aggregates between submissions (see decay.rs)
+    #[state]
+    pub aggregate_decay: StateValue<AggregateDecay>,
+
+    /// Aggregation mode stamped on every belief created (see weighting.rs)
+    #[state]
+    pub aggregation_mode: StateValue<AggregationMode>,
+
+    /// Rounding mode stamped on every belief created (see rounding.rs)
+    #[state]
+    pub rounding_mode: StateValue<RoundingMode>,
+
+    /// Snapshot interval and retention (see snapshot.rs)
+    #[state]
+    pub snapshot_config: StateValue<SnapshotConfig>,
+
+    /// Past states of beliefs, keyed by (belief, block height they were taken at)
+    #[state]
+    pub snapshots: StateMap<(BeliefId, u64), BeliefSnapshot>,
+
+    /// Heights of each belief's retained snapshots, oldest first
+    #[state]
+    pub snapshot_heights: StateMap<BeliefId, Vec<u64>>,
+
+    /// Every (value, raw weight) pair folded into a belief
+    /// Only kept for beliefs that need the whole vector (see `Belief::keeps_contributions`)
+    /// NOTE: the Vec grows with every submission to the belief
+    #[state]
+    pub contributions: StateMap<BeliefId, Vec<Contribution>>,
+
+    /// Whether open beliefs' aggregates are recorded after every block (see history.rs)
+    #[state]
+    pub aggregate_history_enabled: StateValue<bool>,
+
+    /// Open beliefs' aggregates at the end of each block, keyed by block height
+    /// Accessory: serves charts natively without growing provable state
+    #[state]
+    pub aggregate_history: AccessoryStateMap<u64, Vec<AggregatePoint>>,
+
+    /// IDs of the binary beliefs that are still open, ascending
+    /// Lets the history hook visit only those instead of every ID ever assigned
+    #[state]
+    pub open_beliefs: StateValue<Vec<BeliefId>>,
+
+    /// Longest question text accepted, in bytes (DEFAULT_MAX_QUESTION_LEN if unset)
+    #[state]
+    pub max_question_len: StateValue<u64>,
+
+    /// Aggregates kept per belief in `recent_aggregates` (0 = none, see recent.rs)
+    #[state]
+    pub recent_aggregates_capacity: StateValue<u64>,
+
+    /// Each belief's latest aggregates, at most `recent_aggregates_capacity` of them
+    #[state]
+    pub recent_aggregates: StateMap<BeliefId, AggregateRing>,
+
     #[phantom]
     pub phantom: PhantomData<S>,
 }
 
 impl<S: Spec> Module for BeliefModule<S> {
     type Spec = S;
-    type Config = GenesisConfig;
+    type Config = GenesisConfig<S>;
     type CallMessage = CallMessage;
-    type Event = ();
+    type Event = Event;
 
     /// Initialize the module's state from genesis configuration
     fn genesis(
@@ -119,35 +824,148 @@ impl<S: Spec> Module for BeliefModule<S> {
         config: &Self::Config,
         state: &mut impl sov_modules_api::GenesisState<S>,
     ) -> Result<()> {
+        if let Some(admin) = &config.admin {
+            self.admin.set(admin, state)?;
+        }
+        self.default_resolution_quorum.set(&config.default_resolution_quorum, state)?;
+        if let AggregateDecay::PerBlock { retain } = config.aggregate_decay {
+            if retain > SCALE {
+                bail!("Decay retain factor must be at most {}", SCALE);
+            }
+        }
+        self.aggregate_decay.set(&config.aggregate_decay, state)?;
+        config.snapshots.validate()?;
+        self.snapshot_config.set(&config.snapshots, state)?;
+        self.aggregation_mode.set(&config.aggregation_mode, state)?;
+        self.rounding_mode.set(&config.rounding_mode, state)?;
+        self.aggregate_history_enabled.set(&config.aggregate_history, state)?;
+        self.max_question_len.set(&config.max_question_len, state)?;
+        validate_recent_aggregates(config.recent_aggregates)?;
+        self.recent_aggregates_capacity.set(&config.recent_aggregates, state)?;
+
         // Initialize beliefs from genesis config
+        let next_belief_id = genesis_next_belief_id(&config.initial_beliefs)?;
         for belief in &config.initial_beliefs {
-            self.beliefs.set(&belief.id, belief, state)?;
+            let mut belief = belief.clone();
+            if belief.initial_value.is_none() {
+                belief.initial_value = Some(belief.aggregate);
+            }
+            belief.aggregation = config.aggregation_mode;
+            belief.rounding = config.rounding_mode;
+            self.beliefs.set(&belief.id, &belief, state)?;
             self.submission_counts.set(&belief.id, &0, state)?;
-            
-            // Update next_belief_id to be higher than any initial belief
-            let next_id = self.next_belief_id.get(state)?.unwrap_or(1);
-            if belief.id >= next_id {
-                self.next_belief_id.set(&(belief.id + 1), state)?;
+            if BeliefStatus::Open.matches(&belief) {
+                self.set_open(belief.id, true, state)?;
             }
         }
+        self.next_belief_id.set(&next_belief_id, state)?;
         Ok(())
     }
 
+    /// Records aggregate history (if enabled)
+    fn end_rollup_block_hook(&mut self, state: &mut StateCheckpoint<S>) {
+        // Charting only: a failure must never halt block production
+        if let Err(e) = self.record_aggregate_history(state) {
+            tracing::warn!(error = %e, "Veritas aggregate history update failed");
+        }
+    }
+
     fn call(
         &mut self,
         msg: Self::CallMessage,
-        _context: &Context<Self::Spec>,
-        _state: &mut impl TxState<S>,
+        context: &Context<Self::Spec>,
+        state: &mut impl TxState<S>,
     ) -> Result<()> {
         match msg {
             CallMessage::NoOp => Ok(()), // Do nothing
+            CallMessage::CreateBelief { question, initial_value, options } => {
+                self.ensure_admin(context.sender(), state)?;
+                let belief_id = self.create_belief_with_options(question.clone(), initial_value, options, state)?;
+                self.emit_event(state, Event::BeliefCreated { belief_id, question });
+                Ok(())
+            }
+            CallMessage::CreateCategoricalBelief { question, outcomes } => {
+                self.ensure_admin(context.sender(), state)?;
+                let belief_id = self.create_categorical_belief(question.clone(), outcomes, state)?;
+                self.emit_event(state, Event::BeliefCreated { belief_id, question });
+                Ok(())
+            }
+            CallMessage::ResolveBelief { belief_id, outcome } => {
+                self.ensure_admin(context.sender(), state)?;
+                self.resolve_belief(belief_id, outcome, state)?;
+                Ok(())
+            }
+            CallMessage::SetResolutionQuorum { belief_id, quorum } => {
+                self.ensure_admin(context.sender(), state)?;
+                self.set_resolution_quorum(belief_id, quorum, state)
+            }
+            CallMessage::ArchiveBelief { belief_id } => {
+                self.ensure_admin(context.sender(), state)?;
+                self.archive_belief(belief_id, state)
+            }
+            CallMessage::AmendBeliefQuestion { belief_id, new_question } => {
+                self.ensure_admin(context.sender(), state)?;
+                self.amend_belief_question(belief_id, new_question.clone(), state)?;
+                self.emit_event(state, Event::BeliefQuestionAmended { belief_id, question: new_question });
+                Ok(())
+            }
+            CallMessage::VoidBelief { belief_id } => {
+                self.ensure_admin(context.sender(), state)?;
+                self.void_belief(belief_id, state)?;
+                self.emit_event(state, Event::BeliefVoided { belief_id });
+                Ok(())
+            }
         }
     }
 }
 
-#[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
-pub struct GenesisConfig {
+#[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, JsonSchema)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+pub struct GenesisConfig<S>
+where
+    S: Spec,
+    S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
     pub initial_beliefs: Vec<Belief>,
+
+    /// Governance address for admin-only actions (resolution)
+    #[serde(default)]
+    pub admin: Option<S::Address>,
+
+    /// Quorum required before any belief can be resolved
+    /// Individual beliefs may override it
+    #[serde(default)]
+    pub default_resolution_quorum: ResolutionQuorum,
+
+    /// Decay of old submissions' weight (none by default, see decay.rs)
+    #[serde(default)]
+    pub aggregate_decay: AggregateDecay,
+
+    /// Belief snapshots for rollbacks (disabled by default, see snapshot.rs)
+    #[serde(default)]
+    pub snapshots: SnapshotConfig,
+
+    /// Weighted mean (default) or weighted median aggregates (see weighting.rs)
+    #[serde(default)]
+    pub aggregation_mode: AggregationMode,
+
+    /// Truncating (default) or round-half-up running means (see rounding.rs)
+    #[serde(default)]
+    pub rounding_mode: RoundingMode,
+
+    /// Record open beliefs' aggregates after every block (off by default, see history.rs)
+    #[serde(default)]
+    pub aggregate_history: bool,
+
+    /// Longest question text, in bytes, that creation and amendment accept
+    #[serde(default = "default_max_question_len")]
+    pub max_question_len: u64,
+
+    /// Latest aggregates kept per belief for sparklines (0, the default, keeps none; see recent.rs)
+    #[serde(default)]
+    pub recent_aggregates: u64,
 }
 
 impl<S: Spec> BeliefModule<S> {
@@ -166,33 +984,76 @@ impl<S: Spec> BeliefModule<S> {
         question: String,
         initial_value: u64,
         state: &mut impl TxState<S>,
+    ) -> Result<BeliefId> {
+        self.create_belief_with_options(question, initial_value, BeliefOptions::default(), state)
+    }
+
+    /// Same as `create_belief`, with per-belief settings (see BeliefOptions)
+    pub fn create_belief_with_options(
+        &mut self,
+        question: String,
+        initial_value: u64,
+        options: BeliefOptions,
+        state: &mut impl TxState<S>,
     ) -> Result<BeliefId> {
         // Validate probability is in valid range
         if initial_value > SCALE {
             bail!("Initial value must be between 0 and {}", SCALE);
         }
 
-        if question.is_empty() {
-            bail!("Question cannot be empty");
+        self.validate_question(&question, state)?;
+
+        if options.category.is_empty() {
+            bail!("Category cannot be empty");
+        }
+        if options.category == ANY_CATEGORY {
+            bail!("Category {:?} is reserved for matching every category", ANY_CATEGORY);
+        }
+
+        if options.value_step == 0 || options.value_step > SCALE {
+            bail!("Value step must be between 1 and {}", SCALE);
+        }
+
+        if let WeightingMode::MeanMultipleCap { max_multiple } = options.weighting_mode {
+            if max_multiple < SCALE {
+                bail!("Mean multiple cap must be at least {} (1× the mean)", SCALE);
+            }
+        }
+
+        if options.min_aggregate > options.max_aggregate || options.max_aggregate > SCALE {
+            bail!("Aggregate bounds must satisfy min <= max <= {}", SCALE);
+        }
+        if initial_value < options.min_aggregate || initial_value > options.max_aggregate {
+            bail!("Initial value must lie within the aggregate bounds");
+        }
+
+        // A belief past its deadline at creation could never take a submission
+        if let Some(deadline_block) = options.deadline_block {
+            let current_block = self.current_block(state)?;
+            if deadline_block < current_block {
+                bail!("Deadline block {} has already passed (current block {})", deadline_block, current_block);
+            }
         }
 
         // Get next available ID, starting from 1 if this is the first belief
         // StateValue.get returns Result<Option<T>>
         let current_id = self.next_belief_id.get(state)?.unwrap_or(1);
+        // Checked before anything is stored, so the last ID is never half-assigned
+        let next_id = current_id.checked_add(1).ok_or(BeliefError::IdsExhausted)?;
         
-        let belief = Belief {
-            id: current_id,
-            question: question.clone(),
-            aggregate: initial_value,
-            total_weight: 0,  // No submissions yet
-        };
+        let mut belief = Belief::with_options(current_id, question, initial_value, options);
+        belief.aggregation = self.aggregation_mode.get(state)?.unwrap_or_default();
+        belief.rounding = self.rounding_mode.get(state)?.unwrap_or_default();
+        // Decay of the initial value (or prior) counts from creation
+        belief.last_update_height = self.current_block(state)?;
 
         // Store the belief and initialize submission count
         self.beliefs.set(&current_id, &belief, state)?;
         self.submission_counts.set(&current_id, &0, state)?;
+        self.set_open(current_id, true, state)?;
         
         // Increment ID counter for next belief
-        self.next_belief_id.set(&(current_id + 1), state)?;
+        self.next_belief_id.set(&next_id, state)?;
 
         Ok(current_id)
     }
@@ -204,11 +1065,15 @@ impl<S: Spec> BeliefModule<S> {
     /// 
     /// This method is called by SubmissionModule when an agent submits a prediction
     /// The weight parameter comes from the agent's stake × score
+    ///
+    /// Beliefs with a non-Raw weighting mode instead recompute the aggregate
+    /// from all of their contributions' effective weights. `total_weight`
+    /// always stays the sum of RAW weights (quorum and integrity checks rely on it).
     /// 
     /// Parameters:
     /// - belief_id: Which belief to update
     /// - value: The probability estimate (0 to 10000, representing 0.0 to 1.0)
-    /// - weight: The agent's weight (stake × score)
+    /// - weight: The agent's weight (stake × score), rejected if 0
     /// 
     /// Returns: The new aggregate value after update
     pub fn update_aggregate(
@@ -219,40 +1084,55 @@ impl<S: Spec> BeliefModule<S> {
         state: &mut impl TxState<S>,
     ) -> Result<u64> {
         if value > SCALE {
-            bail!("Value must be between 0 and {}", SCALE);
+            return Err(BeliefError::ValueOutOfRange { value }.into());
+        }
+        // A weightless submission can't move the aggregate but would still be counted
+        if weight == 0 {
+            return Err(BeliefError::ZeroWeight.into());
         }
 
         // Fetch the belief, error if it doesn't exist
         let mut belief = self.beliefs.get(&belief_id, state)?
-            .ok_or_else(|| anyhow::anyhow!("Belief not found"))?;
-        
-        // WEIGHTED AVERAGE CALCULATION:
-        // This is the heart of the consensus mechanism
-        // Agents with higher weight (stake × score) have more influence
-        // Using integer math to ensure determinism across all nodes
-        let old_total_weight = belief.total_weight;
-        let new_total_weight = old_total_weight.saturating_add(weight);
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        self.ensure_accepting_submissions(&belief, state)?;
+
+        // Enforce the belief's granularity (callers normally normalize first)
+        let value = belief.normalize_value(value)?;
+        self.record_snapshot(&belief, state)?;
         
-        if new_total_weight > 0 {
-            // Fixed-point weighted average formula
-            // We use u128 for intermediate calculations to prevent overflow
-            let old_contribution = (belief.aggregate as u128) * (old_total_weight as u128);
-            let new_contribution = (value as u128) * (weight as u128);
-            let total_contribution = old_contribution + new_contribution;
-            
-            // Divide and convert back to u64
-            belief.aggregate = (total_contribution / (new_total_weight as u128)) as u64;
-        } else {
-            // Edge case: first submission
-            belief.aggregate = value;
+        let mut contributions = Vec::new();
+        if belief.keeps_contributions() {
+            contributions = self.contributions.get(&belief_id, state)?
+                .unwrap_or_default();
+            contributions.push(Contribution { value, weight });
+            self.contributions.set(&belief_id, &contributions, state)?;
         }
-        
-        // Update total weight (using saturating_add to prevent overflow)
-        belief.total_weight = belief.total_weight.saturating_add(weight);
+
+        // Older weight fades first when decay is on (see decay.rs)
+        let height = self.current_block(state)?;
+        let decay = self.aggregate_decay.get(state)?.unwrap_or_default();
+        belief.apply_decay(decay, height);
+        belief.last_update_height = height;
+
+        let old_aggregate = belief.aggregate;
+        belief.fold_submission(value, weight, &contributions);
+        belief.last_delta = old_aggregate.abs_diff(belief.aggregate);
         
         // Persist updated belief
         self.beliefs.set(&belief_id, &belief, state)?;
+        // Emitted by this module even when SubmissionModule drives the update
+        self.emit_event(
+            state,
+            Event::AggregateUpdated {
+                belief_id,
+                old_aggregate,
+                new_aggregate: belief.aggregate,
+                total_weight: belief.total_weight,
+            },
+        );
         
+        self.record_recent_aggregate(belief_id, belief.aggregate, state)?;
+
         // Track submission count for analytics
         let count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
         self.submission_counts.set(&belief_id, &(count + 1), state)?;
@@ -260,28 +1140,720 @@ impl<S: Spec> BeliefModule<S> {
         Ok(belief.aggregate)
     }
 
+    /// Recomputes an open belief's aggregate from its full, updated submission list
+    ///
+    /// Used when an agent overwrites or retracts an earlier submission: `records`
+    /// holds every current (value, raw weight), in submission order, with the
+    /// replaced one already swapped out or the retracted one left out
+    /// (values already normalized).
+    /// Rejects closed beliefs and passed deadlines like `update_aggregate`.
+    /// Returns the new aggregate.
+    pub fn recompute_aggregate(
+        &mut self,
+        belief_id: BeliefId,
+        records: &[Contribution],
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        self.ensure_accepting_submissions(&belief, state)?;
+
+        let (before, mut belief) = self.replay_belief(belief_id, records, state)?;
+        belief.last_delta = before.aggregate.abs_diff(belief.aggregate);
+        self.beliefs.set(&belief_id, &belief, state)?;
+        self.emit_event(
+            state,
+            Event::AggregateUpdated {
+                belief_id,
+                old_aggregate: before.aggregate,
+                new_aggregate: belief.aggregate,
+                total_weight: belief.total_weight,
+            },
+        );
+        self.record_recent_aggregate(belief_id, belief.aggregate, state)?;
+        Ok(belief.aggregate)
+    }
+
+    /// The aggregate `update_aggregate` would produce, without writing anything
+    ///
+    /// Same checks, normalization, decay and weighting as the real update.
+    pub fn preview_update(
+        &self,
+        belief_id: BeliefId,
+        value: u64,
+        weight: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        if value > SCALE {
+            return Err(BeliefError::ValueOutOfRange { value }.into());
+        }
+        if weight == 0 {
+            return Err(BeliefError::ZeroWeight.into());
+        }
+
+        let mut belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        self.ensure_accepting_submissions(&belief, state)?;
+        let value = belief.normalize_value(value)?;
+
+        let mut contributions = Vec::new();
+        if belief.keeps_contributions() {
+            contributions = self.contributions.get(&belief_id, state)?
+                .unwrap_or_default();
+            contributions.push(Contribution { value, weight });
+        }
+
+        let height = self.current_block(state)?;
+        let decay = self.aggregate_decay.get(state)?.unwrap_or_default();
+        belief.apply_decay(decay, height);
+        belief.fold_submission(value, weight, &contributions);
+
+        Ok(belief.aggregate)
+    }
+
+    /// The aggregate `recompute_aggregate` would produce, without writing anything
+    pub fn preview_recompute(
+        &self,
+        belief_id: BeliefId,
+        records: &[Contribution],
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        self.ensure_accepting_submissions(&belief, state)?;
+
+        let (belief, _) = belief.replayed(records);
+        Ok(belief.aggregate)
+    }
+
+    /// Fails with a `BeliefError` unless a belief is still open to submissions
+    ///
+    /// The single enforcement point for every aggregate update, whichever
+    /// module drives it.
+    fn ensure_accepting_submissions(&self, belief: &Belief, state: &mut impl TxState<S>) -> Result<()> {
+        let belief_id = belief.id;
+        // Settled markets are frozen
+        if belief.resolved {
+            return Err(BeliefError::Resolved { belief_id }.into());
+        }
+        if belief.voided {
+            return Err(BeliefError::Voided { belief_id }.into());
+        }
+        if let Some(deadline_block) = belief.deadline_block {
+            if self.current_block(state)? > deadline_block {
+                return Err(BeliefError::PastDeadline { belief_id, deadline_block }.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Rebuilds a belief's aggregate, total weight and submission count from scratch
+    ///
+    /// Replays `records` (value, raw weight) in order through the same math as
+    /// `update_aggregate`, skipping its open/deadline checks so closed beliefs
+    /// can be repaired too. The aggregate restarts from the belief's prior or
+    /// initial value, which is where it ends up with no records (or only zero
+    /// weights). Beliefs without a recorded initial value keep their aggregate then.
+    /// Returns the belief as it was before and after the replay.
+    pub fn replay_belief(
+        &mut self,
+        belief_id: BeliefId,
+        records: &[Contribution],
+        state: &mut impl TxState<S>,
+    ) -> Result<(Belief, Belief)> {
+        let before = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        self.record_snapshot(&before, state)?;
+
+        let (belief, contributions) = before.replayed(records);
+
+        self.beliefs.set(&belief_id, &belief, state)?;
+        self.submission_counts.set(&belief_id, &(records.len() as u64), state)?;
+        if belief.keeps_contributions() {
+            self.contributions.set(&belief_id, &contributions, state)?;
+        }
+
+        Ok((before, belief))
+    }
+
+    /// Returns the weights actually used for a belief's aggregate, in submission order
+    ///
+    /// Beliefs that don't keep contributions (see `Belief::keeps_contributions`)
+    /// have no per-submission weights, so this is empty for them.
+    pub fn get_effective_weights(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<u64>> {
+        let belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        let contributions = self.effective_contributions(&belief, state)?;
+        Ok(contributions.iter().map(|c| c.weight).collect())
+    }
+
+    /// A belief's stored contributions with their weighting-mode weights applied
+    fn effective_contributions(
+        &self,
+        belief: &Belief,
+        state: &mut impl StateReader<User>,
+    ) -> Result<Vec<Contribution>> {
+        let mut contributions = self.contributions.get(&belief.id, state)?
+            .unwrap_or_default();
+
+        if let WeightingMode::MeanMultipleCap { max_multiple } = belief.weighting_mode {
+            let weights: Vec<u64> = contributions.iter().map(|c| c.weight).collect();
+            let effective = cap_weights_at_mean_multiple(&weights, max_multiple);
+            for (contribution, weight) in contributions.iter_mut().zip(effective) {
+                contribution.weight = weight;
+            }
+        }
+
+        Ok(contributions)
+    }
+
+    /// Applies a belief's value granularity (reject or round) to a submitted value
+    ///
+    /// SubmissionModule calls this before `update_aggregate` so the recorded
+    /// submission carries the same value that was folded into the aggregate.
+    pub fn normalize_submission_value(
+        &self,
+        belief_id: BeliefId,
+        value: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        belief.normalize_value(value)
+    }
+
+    /// Current rollup height, as tracked by ChainState
+    pub fn current_block(&self, state: &mut impl TxState<S>) -> Result<u64> {
+        Ok(self.chain_state.rollup_height(state)?.get())
+    }
+
+    /// Verifies that `sender` is the configured governance admin
+    pub fn ensure_admin(&self, sender: &S::Address, state: &mut impl TxState<S>) -> Result<()> {
+        match self.admin.get(state)? {
+            Some(admin) if &admin == sender => Ok(()),
+            Some(_) => Err(BeliefError::NotAdmin.into()),
+            None => Err(BeliefError::NoAdmin.into()),
+        }
+    }
+
+    /// Returns the quorum that applies to a belief (its override or the default)
+    pub fn effective_resolution_quorum(
+        &self,
+        belief: &Belief,
+        state: &mut impl TxState<S>,
+    ) -> Result<ResolutionQuorum> {
+        match &belief.resolution_quorum {
+            Some(quorum) => Ok(quorum.clone()),
+            None => Ok(self.default_resolution_quorum.get(state)?.unwrap_or_default()),
+        }
+    }
+
+    /// Sets (or clears, with None) a belief's resolution quorum override
+    pub fn set_resolution_quorum(
+        &mut self,
+        belief_id: BeliefId,
+        quorum: Option<ResolutionQuorum>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let mut belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+
+        if belief.resolved || belief.voided {
+            bail!("Belief {} is already closed", belief_id);
+        }
+
+        belief.resolution_quorum = quorum;
+        self.beliefs.set(&belief_id, &belief, state)?;
+
+        Ok(())
+    }
+
+    /// Rejects an empty question, or one longer than `max_question_len` bytes
+    ///
+    /// Counts bytes rather than chars, since bytes are what the question costs in state.
+    pub(crate) fn validate_question(&self, question: &str, state: &mut impl TxState<S>) -> Result<()> {
+        if question.is_empty() {
+            bail!("Question cannot be empty");
+        }
+        let max = self.max_question_len.get(state)?.unwrap_or(DEFAULT_MAX_QUESTION_LEN);
+        if question.len() as u64 > max {
+            return Err(BeliefError::QuestionTooLong { len: question.len() as u64, max }.into());
+        }
+        Ok(())
+    }
+
+    /// Replaces a belief's question text, e.g. to fix a typo or an ambiguity
+    ///
+    /// Only allowed before the first submission: once agents have submitted,
+    /// rewording the question could change what they submitted on.
+    pub fn amend_belief_question(
+        &mut self,
+        belief_id: BeliefId,
+        new_question: String,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let mut belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+
+        self.validate_question(&new_question, state)?;
+        let submission_count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
+        if submission_count > 0 {
+            bail!("Belief {} already has {} submissions; its question can no longer change", belief_id, submission_count);
+        }
+
+        belief.question = new_question;
+        self.beliefs.set(&belief_id, &belief, state)?;
+
+        Ok(())
+    }
+
+    /// Settles a belief with its real-world outcome
+    ///
+    /// Every resolution path (admin transaction or any future automatic one)
+    /// goes through here, so the quorum check can't be bypassed.
+    ///
+    /// FLOW:
+    /// 1. Belief must exist and still be open
+    /// 2. Check participation against the effective quorum
+    /// 3. Below quorum: either reject (Block) or close without outcome (Void)
+    /// 4. Otherwise record the outcome (0 or SCALE) and freeze the belief
+    pub fn resolve_belief(
+        &mut self,
+        belief_id: BeliefId,
+        outcome: bool,
+        state: &mut impl TxState<S>,
+    ) -> Result<ResolutionResult> {
+        let mut belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+
+        if belief.resolved {
+            return Err(BeliefError::Resolved { belief_id }.into());
+        }
+        if belief.voided {
+            return Err(BeliefError::Voided { belief_id }.into());
+        }
+
+        let quorum = self.effective_resolution_quorum(&belief, state)?;
+        let submission_count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
+        let quorum_met = submission_count >= quorum.min_submissions
+            && belief.total_weight >= quorum.min_total_weight as u128;
+        // A wrong outcome can be undone by rolling back to this snapshot
+        self.record_snapshot(&belief, state)?;
+
+        if !quorum_met {
+            match quorum.below_quorum {
+                BelowQuorumPolicy::Block => bail!(
+                    "Resolution quorum not met for belief {}: {} submissions / {} weight, need {} / {}",
+                    belief_id,
+                    submission_count,
+                    belief.total_weight,
+                    quorum.min_submissions,
+                    quorum.min_total_weight
+                ),
+                BelowQuorumPolicy::Void => {
+                    belief.voided = true;
+                    self.beliefs.set(&belief_id, &belief, state)?;
+                    self.set_open(belief_id, false, state)?;
+                    self.emit_event(state, Event::BeliefVoided { belief_id });
+                    return Ok(ResolutionResult::Voided);
+                }
+            }
+        }
+
+        let outcome = if outcome { SCALE } else { 0 };
+        belief.resolved = true;
+        belief.outcome = Some(outcome);
+        self.beliefs.set(&belief_id, &belief, state)?;
+        self.set_open(belief_id, false, state)?;
+
+        self.emit_event(
+            state,
+            Event::BeliefResolved {
+                belief_id,
+                outcome,
+                final_aggregate: belief.aggregate,
+                total_weight: belief.total_weight,
+                submission_count,
+            },
+        );
+
+        Ok(ResolutionResult::Resolved { outcome })
+    }
+
+    /// Closes an open belief without an outcome, e.g. because its event was cancelled
+    ///
+    /// Freezes submissions like resolution does. Settlement then skips
+    /// scoring and slashing and refunds the reward pool (see SubmissionModule).
+    pub fn void_belief(&mut self, belief_id: BeliefId, state: &mut impl TxState<S>) -> Result<()> {
+        let mut belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        if belief.resolved {
+            return Err(BeliefError::Resolved { belief_id }.into());
+        }
+        if belief.voided {
+            return Err(BeliefError::Voided { belief_id }.into());
+        }
+
+        // Voiding by mistake can be undone by rolling back to this snapshot
+        self.record_snapshot(&belief, state)?;
+        belief.voided = true;
+        self.beliefs.set(&belief_id, &belief, state)?;
+        self.set_open(belief_id, false, state)?;
+
+        Ok(())
+    }
+
+    /// Rebuilds `dest_id` from `records` and deletes `source_id`
+    ///
+    /// `records` are both beliefs' submissions in submission order (see
+    /// SubmissionModule's merge.rs); they are replayed through the same math as
+    /// live updates. Both beliefs must be open. Returns `dest_id` as it was before
+    /// and after the merge.
+    pub fn merge_beliefs(
+        &mut self,
+        source_id: BeliefId,
+        dest_id: BeliefId,
+        records: &[Contribution],
+        state: &mut impl TxState<S>,
+    ) -> Result<(Belief, Belief)> {
+        if source_id == dest_id {
+            bail!("Belief {} can't be merged into itself", source_id);
+        }
+        for belief_id in [source_id, dest_id] {
+            let belief = self.beliefs.get(&belief_id, state)?
+                .ok_or(BeliefError::NotFound { belief_id })?;
+            if belief.resolved {
+                return Err(BeliefError::Resolved { belief_id }.into());
+            }
+            if belief.voided {
+                return Err(BeliefError::Voided { belief_id }.into());
+            }
+        }
+
+        let (before, mut after) = self.replay_belief(dest_id, records, state)?;
+        after.last_delta = before.aggregate.abs_diff(after.aggregate);
+        self.beliefs.set(&dest_id, &after, state)?;
+
+        self.beliefs.delete(&source_id, state)?;
+        self.set_open(source_id, false, state)?;
+        self.submission_counts.delete(&source_id, state)?;
+        self.contributions.delete(&source_id, state)?;
+        self.recent_aggregates.delete(&source_id, state)?;
+        for height in self.snapshot_heights.get(&source_id, state)?.unwrap_or_default() {
+            self.snapshots.delete(&(source_id, height), state)?;
+        }
+        self.snapshot_heights.delete(&source_id, state)?;
+
+        self.emit_event(
+            state,
+            Event::AggregateUpdated {
+                belief_id: dest_id,
+                old_aggregate: before.aggregate,
+                new_aggregate: after.aggregate,
+                total_weight: after.total_weight,
+            },
+        );
+        self.record_recent_aggregate(dest_id, after.aggregate, state)?;
+
+        Ok((before, after))
+    }
+
+    /// Moves a closed belief out of hot state into the accessory archive
+    ///
+    /// Keeps provable state small once a market is settled. The final stats
+    /// stay queryable natively through `get_resolved_belief`.
+    pub fn archive_belief(
+        &mut self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+
+        if !belief.resolved && !belief.voided {
+            bail!("Only resolved or voided beliefs can be archived");
+        }
+
+        let submission_count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
+        let view = resolved_view(belief, submission_count, true);
+
+        self.archived_beliefs.set(&belief_id, &view, state)?;
+        self.beliefs.delete(&belief_id, state)?;
+        self.submission_counts.delete(&belief_id, state)?;
+        self.contributions.delete(&belief_id, state)?;
+        self.recent_aggregates.delete(&belief_id, state)?;
+
+        Ok(())
+    }
+
+    /// Returns the final outcome and stats of a closed belief (native-only)
+    ///
+    /// Reads live state first and falls back to the accessory archive.
+    ///
+    /// Returns:
+    /// - Ok(Some(view)) for a resolved/voided belief, live or archived
+    /// - Ok(None) for a belief that exists but is still open
+    /// - Err if the belief never existed
+    #[cfg(feature = "native")]
+    pub fn get_resolved_belief(
+        &self,
+        belief_id: BeliefId,
+        state: &mut ApiStateAccessor<S>,
+    ) -> Result<Option<ResolvedBeliefView>> {
+        if let Some(belief) = self.beliefs.get(&belief_id, state)? {
+            if !belief.resolved && !belief.voided {
+                return Ok(None);
+            }
+            let submission_count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
+            return Ok(Some(resolved_view(belief, submission_count, false)));
+        }
+
+        match self.archived_beliefs.get(&belief_id, state)? {
+            Some(view) => Ok(Some(view)),
+            None => bail!("Belief {} never existed", belief_id),
+        }
+    }
+
+    /// Lists every CallMessage variant with the permission `call` enforces for it
+    #[cfg(feature = "native")]
+    pub fn call_permissions(&self) -> Vec<CallPermissionEntry> {
+        vec![
+            CallPermissionEntry::new("no_op", CallPermission::Public),
+            CallPermissionEntry::new("create_belief", CallPermission::Admin),
+            CallPermissionEntry::new("create_categorical_belief", CallPermission::Admin),
+            CallPermissionEntry::new("resolve_belief", CallPermission::Admin),
+            CallPermissionEntry::new("set_resolution_quorum", CallPermission::Admin),
+            CallPermissionEntry::new("archive_belief", CallPermission::Admin),
+            CallPermissionEntry::new("amend_belief_question", CallPermission::Admin),
+            CallPermissionEntry::new("void_belief", CallPermission::Admin),
+        ]
+    }
+
+    /// Returns deadline countdown, quorum progress and resolvability in one call
+    pub fn get_belief_progress(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<BeliefProgress> {
+        let belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        let submission_count = self.submission_counts.get(&belief_id, state)?.unwrap_or(0);
+        let current_block = self.current_block(state)?;
+
+        let quorum = self.effective_resolution_quorum(&belief, state)?;
+        let quorum_met = submission_count >= quorum.min_submissions
+            && belief.total_weight >= quorum.min_total_weight as u128;
+        let no_quorum = quorum.min_submissions == 0 && quorum.min_total_weight == 0;
+
+        let open = !belief.resolved && !belief.voided;
+        let before_deadline = belief.deadline_block.map_or(true, |d| current_block <= d);
+
+        Ok(BeliefProgress {
+            id: belief.id,
+            current_block,
+            deadline_block: belief.deadline_block,
+            blocks_remaining: belief.deadline_block.map(|d| d.saturating_sub(current_block)),
+            submission_count,
+            total_weight: belief.total_weight,
+            quorum: if no_quorum { None } else { Some(quorum) },
+            quorum_met,
+            accepting_submissions: open && before_deadline,
+            resolution_permitted: open && quorum_met,
+        })
+    }
+
+    /// Returns the beliefs with IDs offset+1 ..= offset+limit
+    ///
+    /// `limit` is clamped to MAX_BELIEF_PAGE_SIZE. Pages are defined over the
+    /// ID range, so they stay disjoint even when some IDs were archived.
+    /// With a `status`, only beliefs in that status are kept; with a `category`
+    /// other than ANY_CATEGORY, only beliefs in that category.
+    pub fn list_beliefs(
+        &self,
+        offset: u64,
+        limit: u64,
+        status: Option<BeliefStatus>,
+        category: Option<&str>,
+        state: &mut impl TxState<S>,
+    ) -> Result<BeliefPage> {
+        let limit = limit.min(MAX_BELIEF_PAGE_SIZE);
+        let total = self.next_belief_id.get(state)?.unwrap_or(1).saturating_sub(1);
+
+        let first = offset.saturating_add(1);
+        let last = offset.saturating_add(limit).min(total);
+        let mut beliefs = Vec::new();
+        for belief_id in first..=last {
+            let Some(belief) = self.beliefs.get(&belief_id, state)? else {
+                continue;
+            };
+            let category_matches = category.map_or(true, |c| c == ANY_CATEGORY || c == belief.category);
+            if category_matches && status.map_or(true, |status| status.matches(&belief)) {
+                beliefs.push(self.get_belief_state(belief_id, state)?);
+            }
+        }
+
+        Ok(BeliefPage { total, offset, limit, beliefs })
+    }
+
+    /// Returns the `n` beliefs with the most total weight, heaviest first
+    ///
+    /// Equal weights are ordered by ascending ID, so every node returns the
+    /// same list. `n` is clamped to MAX_TOP_BELIEFS. Covers every live belief,
+    /// open or closed; archived ones are gone from state and left out.
+    /// Reads every belief ID assigned so far.
+    pub fn top_beliefs_by_weight(&self, n: u64, state: &mut impl TxState<S>) -> Result<Vec<BeliefState>> {
+        let n = n.min(MAX_TOP_BELIEFS) as usize;
+        let total = self.next_belief_id.get(state)?.unwrap_or(1).saturating_sub(1);
+
+        let mut ranked: Vec<(u128, BeliefId)> = Vec::new();
+        for belief_id in 1..=total {
+            if let Some(belief) = self.beliefs.get(&belief_id, state)? {
+                ranked.push((belief.total_weight, belief_id));
+            }
+        }
+        ranked.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        ranked.into_iter()
+            .take(n)
+            .map(|(_, belief_id)| self.get_belief_state(belief_id, state))
+            .collect()
+    }
+
+    /// A belief's aggregate, statistics and resolution in one read-only view
+    ///
+    /// Same as `get_belief_state_ref`, for callers holding a transaction state.
     pub fn get_belief_state(&self, belief_id: BeliefId, state: &mut impl TxState<S>) -> Result<BeliefState> {
+        self.get_belief_state_ref(belief_id, state)
+    }
+
+    /// `get_belief_state` over the SDK's read-only state reader
+    ///
+    /// The reader can't write, so queries and previews can use it without a
+    /// transaction state. It stays `&mut` because SDK reads are metered and cached.
+    pub fn get_belief_state_ref(&self, belief_id: BeliefId, state: &mut impl StateReader<User>) -> Result<BeliefState> {
         let belief = self.beliefs.get(&belief_id, state)?
-            .ok_or_else(|| anyhow::anyhow!("Belief not found"))?;
+            .ok_or(BeliefError::NotFound { belief_id })?;
         let submission_count = self.submission_counts.get(&belief_id, state)?
             .unwrap_or(0);
+
+        let (mut median, mut interval_low, mut interval_high) = (None, None, None);
+        if belief.report_interval {
+            let contributions = self.effective_contributions(&belief, state)?;
+            median = weighted_quantile(&contributions, SCALE / 2);
+            if contributions.len() >= MIN_INTERVAL_SUBMISSIONS {
+                interval_low = weighted_quantile(&contributions, INTERVAL_LOW_QUANTILE);
+                interval_high = weighted_quantile(&contributions, INTERVAL_HIGH_QUANTILE);
+            }
+        }
         
         Ok(BeliefState {
             id: belief.id,
             question: belief.question,
+            category: belief.category,
             aggregate: belief.aggregate,
             total_weight: belief.total_weight,
             submission_count,
+            variance: belief.variance,
+            median,
+            interval_low,
+            interval_high,
+            resolved: belief.resolved,
+            outcome: belief.outcome,
+            quorum_met: belief.total_weight >= belief.min_quorum_weight as u128,
+            last_delta: belief.last_delta,
         })
     }
 }
 
+/// Events emitted by BeliefModule
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    /// A belief was created by a transaction; carries the ID it was assigned
+    BeliefCreated { belief_id: BeliefId, question: String },
+    /// A submission (or an overwrite/retraction of one) moved a belief's aggregate
+    /// Also emitted when the aggregate stays put, e.g. for a matching value
+    AggregateUpdated { belief_id: BeliefId, old_aggregate: u64, new_aggregate: u64, total_weight: u128 },
+    /// A belief was restored to its snapshot at `height`
+    /// Emitted by this module even when SubmissionModule drives the rollback
+    BeliefRolledBack { belief_id: BeliefId, height: u64, aggregate: u64 },
+    /// Governance reworded a belief's question before any submission
+    BeliefQuestionAmended { belief_id: BeliefId, question: String },
+    /// A belief closed without an outcome, by governance or by resolution below quorum
+    BeliefVoided { belief_id: BeliefId },
+    /// A belief resolved; carries its final state so indexers need no further reads
+    /// (not emitted when resolution voids a belief below quorum)
+    BeliefResolved {
+        belief_id: BeliefId,
+        outcome: u64,
+        final_aggregate: u64,
+        total_weight: u128,
+        submission_count: u64,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, JsonSchema, UniversalWallet)]
 #[serialize(Borsh, Serde)]
 #[serde(rename_all = "snake_case")]
 pub enum CallMessage {
-    // Dummy variant to satisfy the compiler - this module doesn't expose public methods
+    // Dummy variant kept for wire compatibility
     // The update_aggregate method is called by SubmissionModule directly as an internal method
     NoOp,
+    /// Admin-only: open a new belief with its per-belief settings (see BeliefOptions)
+    /// Its ID is reported in a BeliefCreated event
+    CreateBelief { question: String, initial_value: u64, options: BeliefOptions },
+    /// Admin-only: open a multi-outcome belief with a uniform starting distribution
+    CreateCategoricalBelief { question: String, outcomes: Vec<String> },
+    /// Admin-only: settle a belief with its real-world outcome (true = SCALE, false = 0)
+    ResolveBelief { belief_id: BeliefId, outcome: bool },
+    /// Admin-only: override (or clear) the resolution quorum of a single belief
+    SetResolutionQuorum { belief_id: BeliefId, quorum: Option<ResolutionQuorum> },
+    /// Admin-only: move a resolved/voided belief into the accessory archive
+    ArchiveBelief { belief_id: BeliefId },
+    /// Admin-only: reword a belief's question while it has no submissions
+    AmendBeliefQuestion { belief_id: BeliefId, new_question: String },
+    /// Admin-only: close an open belief without an outcome (e.g. its event was cancelled)
+    VoidBelief { belief_id: BeliefId },
+}
+
+/// Checks genesis beliefs and returns the ID the first created belief will get
+///
+/// IDs must be non-zero and unique (a duplicate would silently overwrite an
+/// earlier belief); they may come in any order. The next ID is one past the
+/// largest, or 1 without genesis beliefs.
+pub fn genesis_next_belief_id(beliefs: &[Belief]) -> Result<BeliefId> {
+    let mut seen = std::collections::BTreeSet::new();
+    for belief in beliefs {
+        if belief.id == 0 {
+            bail!("Genesis belief IDs start at 1");
+        }
+        if !seen.insert(belief.id) {
+            bail!("Duplicate genesis belief ID {}", belief.id);
+        }
+    }
+    let Some(max) = seen.last() else {
+        return Ok(1);
+    };
+    match max.checked_add(1) {
+        Some(next) => Ok(next),
+        None => bail!("Genesis belief ID {} leaves no ID for the next belief", max),
+    }
+}
+
+fn resolved_view(belief: Belief, submission_count: u64, archived: bool) -> ResolvedBeliefView {
+    ResolvedBeliefView {
+        id: belief.id,
+        question: belief.question,
+        outcome: belief.outcome,
+        voided: belief.voided,
+        final_aggregate: belief.aggregate,
+        total_weight: belief.total_weight,
+        participant_count: submission_count,
+        archived,
+    }
 }
 
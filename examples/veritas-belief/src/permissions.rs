@@ -0,0 +1,41 @@
+//! Call permissions - which CallMessage variants each Veritas module gates
+//!
+//! FILE PURPOSE:
+//! Clients (permission-aware UIs, bots) need to know whether a message
+//! requires admin rights before sending it, instead of paying for a
+//! transaction that is bound to fail. Each Veritas module exposes a
+//! native-only `call_permissions()` listing its variants with the level
+//! that its `call` actually enforces.
+//!
+//! Lives in veritas-belief because every other Veritas crate depends on it.
+
+use schemars::JsonSchema;
+
+/// Who may send a given CallMessage variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CallPermission {
+    /// Any sender
+    Public,
+    /// Only the configured governance admin
+    Admin,
+    /// Only the creator of the targeted object (e.g. a belief)
+    Creator,
+}
+
+/// One CallMessage variant and the permission required to send it
+#[derive(Debug, Clone, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct CallPermissionEntry {
+    /// Variant name as it appears in the serialized CallMessage (snake_case)
+    pub message: String,
+    pub permission: CallPermission,
+}
+
+impl CallPermissionEntry {
+    pub fn new(message: &str, permission: CallPermission) -> Self {
+        Self {
+            message: message.to_string(),
+            permission,
+        }
+    }
+}
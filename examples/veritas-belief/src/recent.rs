@@ -0,0 +1,97 @@
+//! Recent aggregates - a fixed-size ring of each belief's latest aggregates
+//!
+//! FILE PURPOSE:
+//! Sparkline charts only need a belief's last few aggregates. history.rs keeps
+//! one point per block in accessory state, which grows without bound and is
+//! only readable natively; this keeps the last K aggregates of every belief in
+//! provable state instead, at a constant size per belief.
+//!
+//! HOW IT WORKS:
+//! - K comes from the genesis `recent_aggregates` setting, at most
+//!   MAX_RECENT_AGGREGATES; 0 (the default) disables the rings
+//! - Every aggregate update (submission, overwrite or retraction) stores the
+//!   new aggregate; once a ring holds K values the oldest slot is overwritten
+//! - Served oldest first as `GET /beliefs/{beliefId}/history` (see http.rs)
+//! - Archived and merged-away beliefs lose their ring; categorical beliefs have none
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+
+use crate::{BeliefError, BeliefId, BeliefModule};
+
+/// Most aggregates a belief's ring may hold
+pub const MAX_RECENT_AGGREGATES: u64 = 256;
+
+/// Fails for a ring size above MAX_RECENT_AGGREGATES
+pub fn validate_recent_aggregates(capacity: u64) -> Result<()> {
+    if capacity > MAX_RECENT_AGGREGATES {
+        bail!("Recent aggregates are capped at {} per belief", MAX_RECENT_AGGREGATES);
+    }
+    Ok(())
+}
+
+/// A belief's latest aggregates, overwritten in place once full
+#[derive(Clone, Debug, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct AggregateRing {
+    /// Stored aggregates in slot order; fewer than the capacity until the ring fills
+    pub values: Vec<u64>,
+
+    /// Slot the next aggregate goes to, i.e. the oldest one once the ring is full
+    pub next: u64,
+}
+
+impl AggregateRing {
+    /// Stores `aggregate`, overwriting the oldest value once `capacity` are held
+    pub fn push(&mut self, aggregate: u64, capacity: u64) {
+        if capacity == 0 {
+            return;
+        }
+        if (self.values.len() as u64) < capacity {
+            self.values.push(aggregate);
+        } else if let Some(slot) = self.values.get_mut(self.next as usize) {
+            *slot = aggregate;
+        }
+        self.next = (self.next + 1) % capacity;
+    }
+
+    /// The stored aggregates, oldest first
+    pub fn to_vec(&self) -> Vec<u64> {
+        let split = (self.next as usize).min(self.values.len());
+        let (newer, older) = self.values.split_at(split);
+        older.iter().chain(newer).copied().collect()
+    }
+}
+
+impl<S: Spec> BeliefModule<S> {
+    /// Adds `aggregate` to the belief's ring (no-op while rings are disabled)
+    pub(crate) fn record_recent_aggregate(
+        &mut self,
+        belief_id: BeliefId,
+        aggregate: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let capacity = self.recent_aggregates_capacity.get(state)?.unwrap_or(0);
+        if capacity == 0 {
+            return Ok(());
+        }
+        let mut ring = self.recent_aggregates.get(&belief_id, state)?.unwrap_or_default();
+        ring.push(aggregate, capacity);
+        self.recent_aggregates.set(&belief_id, &ring, state)?;
+        Ok(())
+    }
+
+    /// A belief's latest aggregates, oldest first
+    ///
+    /// Empty until its first update, and always while rings are disabled.
+    pub fn get_recent_aggregates(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<u64>> {
+        if self.beliefs.get(&belief_id, state)?.is_none() {
+            return Err(BeliefError::NotFound { belief_id }.into());
+        }
+        Ok(self.recent_aggregates.get(&belief_id, state)?.unwrap_or_default().to_vec())
+    }
+}
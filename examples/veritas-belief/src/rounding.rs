@@ -0,0 +1,48 @@
+//! Rounding mode - how the running weighted mean divides
+//!
+//! FILE PURPOSE:
+//! A Mean belief's aggregate is updated as
+//!   (old × old_weight + value × weight) / new_weight
+//! and integer division truncates, so every update can lose up to one unit,
+//! always downwards. Over many submissions the aggregate drifts slightly low.
+//! `RoundingMode::RoundHalfUp` rounds to the nearest unit instead (exact
+//! halves up), so the per-update error is at most half a unit either way.
+//!
+//! DETERMINISM:
+//! Both modes are pure u128 integer arithmetic: no floats, no platform
+//! dependent behaviour, so every node and the zkVM compute the same aggregate.
+//! The mode is still part of consensus: the same submissions give different
+//! aggregates under each. It is chosen at genesis and stamped on every belief
+//! when it is created, so replays and repairs always use the belief's own mode.
+//!
+//! Only the running mean (Raw weighting) is affected; capped means, medians
+//! and reported variances keep truncating.
+
+use schemars::JsonSchema;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+
+/// Rounding of the running weighted mean's division
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round towards zero (the original behaviour)
+    #[default]
+    Truncate,
+    /// Round to the nearest integer, exact halves up
+    RoundHalfUp,
+}
+
+impl RoundingMode {
+    /// `numerator / denominator` rounded per this mode (`denominator` must be non-zero)
+    ///
+    /// RoundHalfUp adds half the denominator before dividing:
+    /// floor((n + floor(d / 2)) / d). For an even `d` an exact half rounds up;
+    /// an odd `d` can't produce one.
+    pub fn divide(&self, numerator: u128, denominator: u128) -> u128 {
+        match self {
+            Self::Truncate => numerator / denominator,
+            Self::RoundHalfUp => numerator.saturating_add(denominator / 2) / denominator,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Belief snapshots - rolling a belief back for dispute handling
+//!
+//! FILE PURPOSE:
+//! A belief resolved with the wrong outcome, or moved by a submission later
+//! found to be fraudulent, has no way back: aggregates are running totals.
+//! With snapshots enabled, BeliefModule keeps a few past states of each
+//! belief, and governance can restore one with `rollback_belief`.
+//!
+//! HOW IT WORKS:
+//! - Off by default; the genesis `snapshots` config enables it
+//! - Right before a binary belief changes (submission, replay, resolution), its
+//!   current state is recorded under (belief, current block height), unless
+//!   its latest snapshot is less than `interval_blocks` old
+//! - A snapshot at height H therefore holds the belief as it was when block H
+//!   started changing it; rolling back to H undoes everything from there on
+//! - Only beliefs that change are snapshotted, so idle beliefs cost nothing
+//! - Rolling back drops the snapshots taken after the restored one
+//!
+//! STORAGE COST:
+//! A snapshot holds ~100 bytes of fixed fields, plus 16 bytes per submission
+//! for beliefs that keep their contributions (see `Belief::keeps_contributions`).
+//! Each belief keeps at most `max_snapshots` of them (itself capped at
+//! MAX_SNAPSHOTS_PER_BELIEF); the oldest is deleted when a new one exceeds it.
+//!
+//! WHO ROLLS BACK:
+//! Only BeliefModule's state moves here. Rolled back on its own, a belief would
+//! drift from SubmissionModule's log, and the next replay of that log would undo
+//! the rollback. Governance therefore goes through SubmissionModule's
+//! RollbackBelief, which rewinds the log with the belief and refuses beliefs
+//! that were already settled (see veritas_submission's rollback.rs).
+//! Categorical beliefs aren't snapshotted.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+use sov_modules_api::{EventEmitter, Spec, TxState};
+
+use crate::{Belief, BeliefError, BeliefId, BeliefModule, BeliefStatus, Contribution, Event};
+
+/// Most snapshots any deployment may keep per belief
+pub const MAX_SNAPSHOTS_PER_BELIEF: u64 = 32;
+
+/// Settings for recording belief snapshots
+#[derive(Debug, Clone, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub struct SnapshotConfig {
+    /// Whether beliefs are snapshotted at all
+    pub enabled: bool,
+
+    /// Fewest blocks between two snapshots of the same belief (0 behaves like 1)
+    pub interval_blocks: u64,
+
+    /// Snapshots kept per belief, between 1 and MAX_SNAPSHOTS_PER_BELIEF
+    pub max_snapshots: u64,
+}
+
+impl SnapshotConfig {
+    /// Fails for an enabled config with an out-of-range retention
+    pub fn validate(&self) -> Result<()> {
+        if self.enabled && (self.max_snapshots == 0 || self.max_snapshots > MAX_SNAPSHOTS_PER_BELIEF) {
+            bail!("Snapshot retention must be between 1 and {}", MAX_SNAPSHOTS_PER_BELIEF);
+        }
+        Ok(())
+    }
+}
+
+/// Everything `rollback_belief` restores
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct BeliefSnapshot {
+    pub aggregate: u64,
+    pub total_weight: u128,
+    pub submission_count: u64,
+    pub variance: u64,
+    pub weighted_sq_dev: u128,
+    pub decayed_weight: Option<u128>,
+    pub last_update_height: u64,
+    pub resolved: bool,
+    pub outcome: Option<u64>,
+    pub voided: bool,
+    /// The belief's stored contributions; empty if it doesn't keep them
+    pub contributions: Vec<Contribution>,
+}
+
+impl<S: Spec> BeliefModule<S> {
+    /// Records `belief`'s current state if its latest snapshot is old enough
+    ///
+    /// Called before every change to a binary belief. No-op while snapshots are disabled.
+    pub(crate) fn record_snapshot(&mut self, belief: &Belief, state: &mut impl TxState<S>) -> Result<()> {
+        let config = self.snapshot_config.get(state)?.unwrap_or_default();
+        if !config.enabled {
+            return Ok(());
+        }
+
+        let height = self.current_block(state)?;
+        let mut heights = self.snapshot_heights.get(&belief.id, state)?.unwrap_or_default();
+        if let Some(latest) = heights.last() {
+            if height < latest.saturating_add(config.interval_blocks.max(1)) {
+                return Ok(());
+            }
+        }
+
+        let contributions = if belief.keeps_contributions() {
+            self.contributions.get(&belief.id, state)?.unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let snapshot = BeliefSnapshot {
+            aggregate: belief.aggregate,
+            total_weight: belief.total_weight,
+            submission_count: self.submission_counts.get(&belief.id, state)?.unwrap_or(0),
+            variance: belief.variance,
+            weighted_sq_dev: belief.weighted_sq_dev,
+            decayed_weight: belief.decayed_weight,
+            last_update_height: belief.last_update_height,
+            resolved: belief.resolved,
+            outcome: belief.outcome,
+            voided: belief.voided,
+            contributions,
+        };
+        self.snapshots.set(&(belief.id, height), &snapshot, state)?;
+        heights.push(height);
+
+        // Retention cap: the oldest snapshots go first
+        let excess = heights.len().saturating_sub(config.max_snapshots as usize);
+        for dropped in heights.drain(..excess) {
+            self.snapshots.delete(&(belief.id, dropped), state)?;
+        }
+        self.snapshot_heights.set(&belief.id, &heights, state)?;
+
+        Ok(())
+    }
+
+    /// Restores a belief to its snapshot at block `height`
+    ///
+    /// Snapshots taken after `height` are deleted; the restored one is kept.
+    /// Doesn't touch the submission log: SubmissionModule's `rollback_belief`
+    /// calls this and rewinds the log itself. Returns the restored aggregate.
+    pub fn rollback_belief(
+        &mut self,
+        belief_id: BeliefId,
+        height: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let mut belief = self.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        let Some(snapshot) = self.snapshots.get(&(belief_id, height), state)? else {
+            bail!("No snapshot of belief {} at block {}", belief_id, height);
+        };
+
+        belief.aggregate = snapshot.aggregate;
+        belief.total_weight = snapshot.total_weight;
+        belief.variance = snapshot.variance;
+        belief.weighted_sq_dev = snapshot.weighted_sq_dev;
+        belief.decayed_weight = snapshot.decayed_weight;
+        belief.last_update_height = snapshot.last_update_height;
+        belief.resolved = snapshot.resolved;
+        belief.outcome = snapshot.outcome;
+        belief.voided = snapshot.voided;
+        self.beliefs.set(&belief_id, &belief, state)?;
+        // Undoing a resolution or void reopens the belief
+        self.set_open(belief_id, BeliefStatus::Open.matches(&belief), state)?;
+        self.submission_counts.set(&belief_id, &snapshot.submission_count, state)?;
+        if belief.keeps_contributions() {
+            self.contributions.set(&belief_id, &snapshot.contributions, state)?;
+        }
+
+        let mut heights = self.snapshot_heights.get(&belief_id, state)?.unwrap_or_default();
+        for later in heights.iter().filter(|h| **h > height) {
+            self.snapshots.delete(&(belief_id, *later), state)?;
+        }
+        heights.retain(|h| *h <= height);
+        self.snapshot_heights.set(&belief_id, &heights, state)?;
+
+        self.emit_event(state, Event::BeliefRolledBack { belief_id, height, aggregate: snapshot.aggregate });
+
+        Ok(snapshot.aggregate)
+    }
+
+    /// Block heights of a belief's retained snapshots, oldest first
+    pub fn get_snapshot_heights(&self, belief_id: BeliefId, state: &mut impl TxState<S>) -> Result<Vec<u64>> {
+        Ok(self.snapshot_heights.get(&belief_id, state)?.unwrap_or_default())
+    }
+}
@@ -0,0 +1,20 @@
+//! Agent tiers - reputation levels that can gate access to beliefs
+//!
+//! Defined here rather than in veritas-agent because beliefs carry a minimum
+//! tier and veritas-belief can't depend on the agent crate (the reverse
+//! dependency already exists). AgentModule assigns tiers; SubmissionModule
+//! enforces `Belief::min_tier`.
+
+use schemars::JsonSchema;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+
+/// Reputation tier of an agent, from least to most trusted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentTier {
+    #[default]
+    Novice,
+    Verified,
+    Expert,
+}
@@ -0,0 +1,252 @@
+//! Weighting modes - optional post-processing of participant weights per belief
+//!
+//! FILE PURPOSE:
+//! By default a belief's aggregate is a plain stake × score weighted average, so a
+//! single very large agent can dominate it. A flat per-agent max cap only helps
+//! against one whale; it says nothing about how unequal the whole weight vector is.
+//!
+//! `WeightingMode::MeanMultipleCap` bounds overall concentration instead:
+//! once the weights of all submissions are known, every weight is clipped to
+//! `max_multiple × mean(weights)` and the aggregate is recomputed from the
+//! clipped ("effective") weights.
+//!
+//! PROPERTIES OF THE TRANSFORM:
+//! - Deterministic integer math (u128 intermediates), identical on every node
+//! - Order-preserving: if raw a <= b then effective a <= b
+//! - Never increases a weight, so it can only compress the distribution
+//! - No participant's effective weight exceeds `max_multiple × mean`, which
+//!   bounds the largest share of influence at roughly max_multiple / n
+//!
+//! NOTE: the cap is recomputed from ALL submissions on every update, so the
+//! cost of a submission grows linearly with the belief's submission count.
+//! That's the price of a holistic (whole-vector) control.
+//!
+//! FIRST SUBMISSION:
+//! `FirstSubmissionAnchor` decides what the value a belief was created with
+//! means once submissions arrive, identically in every weighting mode:
+//! - `Replace`: it is a placeholder; the first submission with nonzero weight
+//!   becomes the aggregate (for a single contribution every mode's mean is its value)
+//! - `Prior { weight }`: it stays in the average as a pseudo-submission of
+//!   `weight`, so early submissions only pull the aggregate part of the way.
+//!   Under MeanMultipleCap the prior is capped like any other contribution.
+//!   The prior never counts toward `total_weight`, quorums or reward splits.
+//! In both cases zero-weight submissions never move the aggregate.
+//!
+//! AGGREGATION MODE:
+//! A weighted mean can still be dragged far by one heavily weighted outlier.
+//! The deployment-wide `AggregationMode::WeightedMedian` (set at genesis)
+//! makes the aggregate the value at which cumulative weight crosses half the
+//! total instead (an exact split is interpolated, see `weighted_median`).
+//! It needs every contribution, like MeanMultipleCap, and composes with it:
+//! the median is taken over the capped weights. Each belief records the mode
+//! it was created under, so replays always agree with it.
+//! Decay (see decay.rs) only applies to the running mean.
+//!
+//! Also home of the weighted quantiles behind a belief's reported interval.
+
+use schemars::JsonSchema;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+
+use crate::SCALE;
+
+/// Quantiles (SCALE units) bounding a belief's reported interval: 25th to 75th percentile
+pub const INTERVAL_LOW_QUANTILE: u64 = 2500;
+pub const INTERVAL_HIGH_QUANTILE: u64 = 7500;
+
+/// Fewer submissions than this and no interval is reported
+pub const MIN_INTERVAL_SUBMISSIONS: usize = 2;
+
+/// How submission weights are turned into influence on a belief's aggregate
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightingMode {
+    /// Weights are used as-is (running weighted average)
+    #[default]
+    Raw,
+    /// Each weight is clipped to `max_multiple × mean weight` before aggregating
+    /// `max_multiple` is in SCALE units: 20000 = 2× the mean. Must be >= SCALE.
+    MeanMultipleCap { max_multiple: u64 },
+}
+
+/// Which statistic of the weighted submissions becomes a belief's aggregate
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum AggregationMode {
+    /// Weighted average of all submissions
+    #[default]
+    Mean,
+    /// Smallest value at which cumulative weight reaches half of the total
+    WeightedMedian,
+}
+
+/// What the value a belief was created with means once submissions arrive
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum FirstSubmissionAnchor {
+    /// The first submission with nonzero weight replaces the initial value
+    #[default]
+    Replace,
+    /// The initial value keeps counting as a submission of `weight`
+    Prior { weight: u64 },
+}
+
+/// A single submission as seen by the aggregation (value and raw weight)
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct Contribution {
+    pub value: u64,
+    pub weight: u64,
+}
+
+/// Clips every weight to `max_multiple × mean(weights)` (max_multiple in SCALE units)
+///
+/// Returns the effective weights, in the same order as the input.
+/// A cap that works out below 1 is raised to 1 so nonzero weights keep counting.
+pub fn cap_weights_at_mean_multiple(weights: &[u64], max_multiple: u64) -> Vec<u64> {
+    if weights.is_empty() {
+        return Vec::new();
+    }
+
+    let sum: u128 = weights.iter().map(|w| *w as u128).sum();
+    let count = weights.len() as u128;
+
+    // cap = mean × max_multiple / SCALE, computed as one division to keep precision
+    let cap = (sum * max_multiple as u128) / (count * SCALE as u128);
+    let cap = cap.clamp(1, u64::MAX as u128) as u64;
+
+    weights.iter().map(|w| (*w).min(cap)).collect()
+}
+
+/// `contributions` with every weight clipped to `max_multiple × mean(weights)`
+pub fn cap_contributions(contributions: &[Contribution], max_multiple: u64) -> Vec<Contribution> {
+    let weights: Vec<u64> = contributions.iter().map(|c| c.weight).collect();
+    let effective = cap_weights_at_mean_multiple(&weights, max_multiple);
+    contributions.iter()
+        .zip(effective)
+        .map(|(c, weight)| Contribution { value: c.value, weight })
+        .collect()
+}
+
+/// Weighted average of `contributions` after applying the mean-multiple cap
+///
+/// Returns None if every effective weight is zero (nothing to average).
+pub fn capped_weighted_mean(contributions: &[Contribution], max_multiple: u64) -> Option<u64> {
+    let weights: Vec<u64> = contributions.iter().map(|c| c.weight).collect();
+    let effective = cap_weights_at_mean_multiple(&weights, max_multiple);
+
+    let mut weighted_sum: u128 = 0;
+    let mut total_weight: u128 = 0;
+    for (contribution, weight) in contributions.iter().zip(effective) {
+        weighted_sum += contribution.value as u128 * weight as u128;
+        total_weight += weight as u128;
+    }
+
+    if total_weight == 0 {
+        return None;
+    }
+
+    Some((weighted_sum / total_weight) as u64)
+}
+
+/// Σ effective_weight × (value - mean)² and Σ effective_weight, after the mean-multiple cap
+///
+/// Used for a capped belief's variance (the quotient of the two).
+/// The weight sum is at least 1 so callers can divide by it directly.
+pub fn capped_sq_deviation(contributions: &[Contribution], max_multiple: u64, mean: u64) -> (u128, u128) {
+    weighted_sq_deviation(&cap_contributions(contributions, max_multiple), mean)
+}
+
+/// Σ weight × (value - center)² and Σ weight, with weights used as-is
+///
+/// The weight sum is at least 1 so callers can divide by it directly.
+pub fn weighted_sq_deviation(contributions: &[Contribution], center: u64) -> (u128, u128) {
+    let mut sq_dev: u128 = 0;
+    let mut total_weight: u128 = 0;
+    for contribution in contributions {
+        let distance = contribution.value.abs_diff(center) as u128;
+        sq_dev = sq_dev.saturating_add(contribution.weight as u128 * distance * distance);
+        total_weight += contribution.weight as u128;
+    }
+
+    (sq_dev, total_weight.max(1))
+}
+
+/// Weighted quantile of the contributions' values (`quantile` in SCALE units)
+///
+/// Returns the smallest value whose cumulative weight reaches `quantile` of the
+/// total weight, or None if the total weight is zero.
+pub fn weighted_quantile(contributions: &[Contribution], quantile: u64) -> Option<u64> {
+    let mut sorted: Vec<&Contribution> = contributions.iter().collect();
+    sorted.sort_by_key(|c| c.value);
+
+    let total_weight: u128 = sorted.iter().map(|c| c.weight as u128).sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    // cumulative / total >= quantile / SCALE, cross-multiplied to stay in integers
+    let target = total_weight * quantile.min(SCALE) as u128;
+    let mut cumulative: u128 = 0;
+    for contribution in &sorted {
+        cumulative += contribution.weight as u128;
+        if cumulative * SCALE as u128 >= target && contribution.weight > 0 {
+            return Some(contribution.value);
+        }
+    }
+
+    sorted.last().map(|c| c.value)
+}
+
+/// Weighted median of the contributions' values, the `WeightedMedian` aggregate
+///
+/// Values are sorted ascending with equal values merged (their weights summed)
+/// and zero weights dropped. The median is the first value whose cumulative
+/// weight exceeds half the total. If the cumulative weight lands EXACTLY on
+/// half at value `a`, the next value `b` straddles the midpoint just as much,
+/// so neither is picked: the tie is broken by interpolating between them by
+/// their weights,
+///   median = (a × weight_a + b × weight_b) / (weight_a + weight_b)
+/// rounded down. Equal weights give the midpoint. Everything is u128 integer
+/// math, so every node (and the zkVM) computes the same value regardless of
+/// submission order. Returns None if the total weight is zero.
+///
+/// Unlike `weighted_quantile(.., SCALE / 2)`, which reports the lower value
+/// of an exact split, this never favours either side.
+pub fn weighted_median(contributions: &[Contribution]) -> Option<u64> {
+    let mut sorted: Vec<&Contribution> = contributions.iter().filter(|c| c.weight > 0).collect();
+    sorted.sort_by_key(|c| c.value);
+
+    // (value, total weight at that value), ascending
+    let mut levels: Vec<(u64, u128)> = Vec::with_capacity(sorted.len());
+    for contribution in sorted {
+        match levels.last_mut() {
+            Some((value, weight)) if *value == contribution.value => *weight += contribution.weight as u128,
+            _ => levels.push((contribution.value, contribution.weight as u128)),
+        }
+    }
+
+    let total_weight: u128 = levels.iter().map(|(_, weight)| weight).sum();
+    if total_weight == 0 {
+        return None;
+    }
+
+    // cumulative vs total / 2, doubled to stay in integers
+    let mut cumulative: u128 = 0;
+    for (i, (value, weight)) in levels.iter().enumerate() {
+        cumulative += weight;
+        if cumulative * 2 > total_weight {
+            return Some(*value);
+        }
+        if cumulative * 2 == total_weight {
+            // The other half is non-empty, so a next value exists
+            if let Some((next_value, next_weight)) = levels.get(i + 1) {
+                let numerator = *value as u128 * weight + *next_value as u128 * next_weight;
+                return Some((numerator / (weight + next_weight)) as u64);
+            }
+        }
+    }
+
+    levels.last().map(|(value, _)| *value)
+}
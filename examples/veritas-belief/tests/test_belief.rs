@@ -0,0 +1,1326 @@
+#[cfg(test)]
+mod tests {
+    use veritas_belief::{
+        cap_weights_at_mean_multiple, decay_factor, genesis_next_belief_id, AggregateDecay, AggregateDisplay, AggregationMode,
+        BelowQuorumPolicy, Belief, BeliefError, BeliefModule, BeliefOptions, BeliefState, BeliefStatus, CallMessage, CallPermission, Event,
+        FirstSubmissionAnchor, Precision, ResolutionQuorum, ResolutionResult, RoundingMode, SnapshotConfig, ValueStepMode, WeightingMode, ANY_CATEGORY,
+        weighted_median, Contribution, DEFAULT_CATEGORY, DEFAULT_MAX_QUESTION_LEN, SCALE,
+    };
+    use sov_modules_api::test_utils::generate_address;
+    use sov_modules_api::capabilities::RollupHeight;
+    use sov_modules_api::da::Time;
+    use sov_modules_api::{Context, Module, StateReader, WorkingSet};
+    use sov_state::User;
+    use sov_test_utils::TestSpec;
+
+    type S = TestSpec;
+
+    /// Moves ChainState, and so every Veritas height check, to rollup height `height`
+    fn set_height(module: &BeliefModule<S>, height: u64, working_set: &mut WorkingSet<S>) {
+        module.chain_state.rollup_height.set(&RollupHeight::new(height), working_set).unwrap();
+    }
+
+    /// Creates a module with an admin and a single open belief (ID 1)
+    fn setup(working_set: &mut WorkingSet<S>) -> (BeliefModule<S>, Context<S>) {
+        let mut module = BeliefModule::<S>::default();
+        let admin = generate_address::<S>("admin");
+        module.admin.set(&admin, working_set).unwrap();
+        module.create_belief("Will it rain?".to_string(), 5000, working_set).unwrap();
+
+        (module, Context::new(admin, Default::default(), 1))
+    }
+
+    #[test]
+    fn test_resolution_blocked_below_quorum() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+
+        module.default_resolution_quorum.set(
+            &ResolutionQuorum {
+                min_submissions: 2,
+                min_total_weight: 1000,
+                below_quorum: BelowQuorumPolicy::Block,
+            },
+            &mut working_set,
+        ).unwrap();
+
+        module.update_aggregate(1, 8000, 600, &mut working_set).unwrap();
+
+        let result = module.call(
+            CallMessage::ResolveBelief { belief_id: 1, outcome: true },
+            &admin,
+            &mut working_set,
+        );
+        assert!(result.unwrap_err().to_string().contains("quorum not met"));
+
+        // Belief stays open and keeps accepting submissions
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert!(!belief.resolved && !belief.voided);
+        module.update_aggregate(1, 9000, 600, &mut working_set).unwrap();
+
+        // Quorum is now met: 2 submissions, 1200 weight
+        module.call(
+            CallMessage::ResolveBelief { belief_id: 1, outcome: true },
+            &admin,
+            &mut working_set,
+        ).unwrap();
+
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert!(belief.resolved);
+        assert_eq!(belief.outcome, Some(SCALE));
+    }
+
+    #[test]
+    fn test_resolution_voids_below_quorum() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+
+        // Per-belief override takes precedence over the (empty) default
+        module.set_resolution_quorum(
+            1,
+            Some(ResolutionQuorum {
+                min_submissions: 3,
+                min_total_weight: 0,
+                below_quorum: BelowQuorumPolicy::Void,
+            }),
+            &mut working_set,
+        ).unwrap();
+
+        module.update_aggregate(1, 8000, 600, &mut working_set).unwrap();
+        working_set.take_events();
+
+        let result = module.resolve_belief(1, false, &mut working_set).unwrap();
+        assert_eq!(result, ResolutionResult::Voided);
+
+        // Indexers see the void like an admin VoidBelief
+        let events: Vec<_> = working_set.take_events()
+            .into_iter()
+            .filter_map(|event| event.downcast::<Event>())
+            .collect();
+        assert_eq!(events, vec![Event::BeliefVoided { belief_id: 1 }]);
+
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert!(belief.voided);
+        assert!(!belief.resolved);
+        assert_eq!(belief.outcome, None);
+
+        // Voided beliefs are frozen and can't be resolved afterwards
+        assert!(module.update_aggregate(1, 5000, 100, &mut working_set).is_err());
+        assert!(module.resolve_belief(1, true, &mut working_set).is_err());
+    }
+
+    #[test]
+    fn test_create_belief_by_admin() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+
+        module.call(
+            CallMessage::CreateBelief {
+                question: "Will it snow?".to_string(),
+                initial_value: 3000,
+                options: BeliefOptions { category: "weather".to_string(), ..Default::default() },
+            },
+            &admin,
+            &mut working_set,
+        ).unwrap();
+
+        // Takes the next ID after the setup belief
+        let belief = module.beliefs.get(&2, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.question, "Will it snow?");
+        assert_eq!(belief.aggregate, 3000);
+        assert_eq!(belief.category, "weather");
+        assert_eq!(module.next_belief_id.get(&mut working_set).unwrap(), Some(3));
+    }
+
+    #[test]
+    fn test_create_belief_rejections() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+        let intruder = Context::new(generate_address::<S>("intruder"), Default::default(), 1);
+
+        let cases = [
+            (&intruder, "Will it snow?", 3000, "Only the admin"),
+            (&admin, "", 3000, "Question cannot be empty"),
+            (&admin, "Will it snow?", SCALE + 1, "Initial value must be between"),
+        ];
+        for (sender, question, initial_value, error) in cases {
+            let result = module.call(
+                CallMessage::CreateBelief {
+                    question: question.to_string(),
+                    initial_value,
+                    options: BeliefOptions { category: "weather".to_string(), ..Default::default() },
+                },
+                sender,
+                &mut working_set,
+            );
+            assert!(result.unwrap_err().to_string().contains(error), "{}", error);
+        }
+
+        // Nothing was created
+        assert!(module.beliefs.get(&2, &mut working_set).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_create_belief_with_options_by_admin() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+        let create = |deadline_block| CallMessage::CreateBelief {
+            question: "Will it snow?".to_string(),
+            initial_value: 3000,
+            options: BeliefOptions { value_step: 100, deadline_block: Some(deadline_block), ..Default::default() },
+        };
+
+        set_height(&module, 5, &mut working_set);
+        let error = module.call(create(4), &admin, &mut working_set).unwrap_err();
+        assert!(error.to_string().contains("already passed"));
+
+        module.call(create(10), &admin, &mut working_set).unwrap();
+        let belief = module.beliefs.get(&2, &mut working_set).unwrap().unwrap();
+        assert_eq!((belief.value_step, belief.deadline_block), (100, Some(10)));
+
+        // Whole percents only
+        module.update_aggregate(2, 7500, 100, &mut working_set).unwrap();
+        let error = module.update_aggregate(2, 7525, 100, &mut working_set).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BeliefError>(),
+            Some(&BeliefError::ValueOffStep { belief_id: 2, value: 7525, value_step: 100 })
+        );
+
+        // Closed once the deadline has passed
+        set_height(&module, 11, &mut working_set);
+        let error = module.update_aggregate(2, 7500, 100, &mut working_set).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BeliefError>(),
+            Some(&BeliefError::PastDeadline { belief_id: 2, deadline_block: 10 })
+        );
+    }
+
+    #[test]
+    fn test_amend_belief_question() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+
+        let amend = |question: &str| CallMessage::AmendBeliefQuestion {
+            belief_id: 1,
+            new_question: question.to_string(),
+        };
+
+        let err = module.call(amend(""), &admin, &mut working_set).unwrap_err();
+        assert!(err.to_string().contains("empty"));
+
+        module.call(amend("Will it rain in Paris?"), &admin, &mut working_set).unwrap();
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.question, "Will it rain in Paris?");
+
+        // Frozen once someone has submitted
+        module.update_aggregate(1, 7000, 100, &mut working_set).unwrap();
+        let err = module.call(amend("Will it rain in Lyon?"), &admin, &mut working_set).unwrap_err();
+        assert!(err.to_string().contains("no longer change"));
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.question, "Will it rain in Paris?");
+    }
+
+    #[test]
+    fn test_last_delta_settles_and_spikes() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+
+        // Identical submissions pull the aggregate ever closer to 8000
+        let mut deltas = Vec::new();
+        for _ in 0..6 {
+            module.update_aggregate(1, 8000, 100, &mut working_set).unwrap();
+            deltas.push(module.get_belief_state(1, &mut working_set).unwrap().last_delta);
+        }
+        assert!(deltas.windows(2).all(|pair| pair[1] <= pair[0]), "{:?}", deltas);
+        assert_eq!(*deltas.last().unwrap(), 0);
+
+        // An outlier moves it again
+        module.update_aggregate(1, 0, 600, &mut working_set).unwrap();
+        let view = module.get_belief_state(1, &mut working_set).unwrap();
+        assert_eq!(view.last_delta, 4000);
+    }
+
+    #[test]
+    fn test_belief_state_through_read_only_reader() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        module.update_aggregate(1, 7000, 100, &mut working_set).unwrap();
+
+        // Only a shared module and a reader that can't write, like a query handler
+        fn read(module: &BeliefModule<S>, reader: &mut impl StateReader<User>) -> BeliefState {
+            module.get_belief_state_ref(1, reader).unwrap()
+        }
+        let view = read(&module, &mut working_set);
+        assert_eq!((view.aggregate, view.submission_count), (7000, 1));
+        let via_tx_state = module.get_belief_state(1, &mut working_set).unwrap();
+        assert_eq!((view.total_weight, view.variance), (via_tx_state.total_weight, via_tx_state.variance));
+
+        let missing = module.get_belief_state_ref(2, &mut working_set).unwrap_err();
+        assert_eq!(missing.downcast_ref::<BeliefError>(), Some(&BeliefError::NotFound { belief_id: 2 }));
+    }
+
+    #[test]
+    fn test_max_question_len_counts_bytes() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+        let max = DEFAULT_MAX_QUESTION_LEN as usize;
+        let create = |question: String| CallMessage::CreateBelief {
+            question,
+            initial_value: 5000,
+            options: BeliefOptions { category: "misc".to_string(), ..Default::default() },
+        };
+
+        module.call(create("a".repeat(max)), &admin, &mut working_set).unwrap();
+        let error = module.call(create("a".repeat(max + 1)), &admin, &mut working_set).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<BeliefError>(),
+            Some(&BeliefError::QuestionTooLong { len: max as u64 + 1, max: max as u64 })
+        );
+
+        // "é" is 2 bytes: 256 of them fill the limit, one more ASCII byte passes it
+        let wide = "é".repeat(max / 2);
+        module.call(create(wide.clone()), &admin, &mut working_set).unwrap();
+        let error = module.call(create(format!("a{}", wide)), &admin, &mut working_set).unwrap_err();
+        assert!(error.downcast_ref::<BeliefError>().is_some());
+
+        // Amendments are held to the same limit
+        let amend = |new_question: String| CallMessage::AmendBeliefQuestion { belief_id: 1, new_question };
+        assert!(module.call(amend(format!("a{}", wide)), &admin, &mut working_set).is_err());
+        module.call(amend(wide), &admin, &mut working_set).unwrap();
+
+        // A lower genesis limit applies as configured
+        module.max_question_len.set(&8, &mut working_set).unwrap();
+        module.call(create("12345678".to_string()), &admin, &mut working_set).unwrap();
+        assert!(module.call(create("123456789".to_string()), &admin, &mut working_set).is_err());
+    }
+
+    #[test]
+    fn test_void_belief_freezes_submissions() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+
+        module.update_aggregate(1, 8000, 600, &mut working_set).unwrap();
+        module.call(CallMessage::VoidBelief { belief_id: 1 }, &admin, &mut working_set).unwrap();
+
+        let result = module.update_aggregate(1, 1000, 600, &mut working_set);
+        assert!(result.unwrap_err().to_string().contains("voided"));
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert!(belief.voided);
+        assert_eq!(belief.outcome, None);
+        assert_eq!(belief.aggregate, 8000);
+
+        // Neither resolvable nor voidable a second time
+        let resolve = CallMessage::ResolveBelief { belief_id: 1, outcome: true };
+        assert!(module.call(resolve, &admin, &mut working_set).is_err());
+        assert!(module.call(CallMessage::VoidBelief { belief_id: 1 }, &admin, &mut working_set).is_err());
+    }
+
+    #[test]
+    fn test_resolution_freezes_aggregate() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+
+        module.update_aggregate(1, 8000, 600, &mut working_set).unwrap();
+        module.call(
+            CallMessage::ResolveBelief { belief_id: 1, outcome: false },
+            &admin,
+            &mut working_set,
+        ).unwrap();
+
+        // Late submissions are refused and leave the consensus untouched
+        let result = module.update_aggregate(1, 1000, 600, &mut working_set);
+        assert!(result.unwrap_err().to_string().contains("already resolved"));
+
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.aggregate, 8000);
+        assert_eq!(belief.total_weight, 600);
+        assert_eq!(belief.outcome, Some(0));
+
+        // The outcome is final
+        let result = module.call(
+            CallMessage::ResolveBelief { belief_id: 1, outcome: true },
+            &admin,
+            &mut working_set,
+        );
+        assert!(result.unwrap_err().to_string().contains("already resolved"));
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.outcome, Some(0));
+    }
+
+    #[test]
+    fn test_resolution_requires_admin() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+
+        let intruder = Context::new(generate_address::<S>("intruder"), Default::default(), 1);
+        let result = module.call(
+            CallMessage::ResolveBelief { belief_id: 1, outcome: false },
+            &intruder,
+            &mut working_set,
+        );
+
+        assert!(result.unwrap_err().to_string().contains("Only the admin"));
+    }
+
+    #[test]
+    fn test_misaligned_value_rejected() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        // 5% buckets
+        let id = module.create_belief_with_options(
+            "Bucketed".to_string(),
+            5000,
+            BeliefOptions {
+                value_step: 500,
+                value_step_mode: ValueStepMode::Reject,
+                ..Default::default()
+            },
+            &mut working_set,
+        ).unwrap();
+
+        let err = module.update_aggregate(id, 7250, 100, &mut working_set).unwrap_err();
+        assert!(err.to_string().contains("not a multiple"));
+        assert_eq!(
+            err.downcast_ref::<BeliefError>(),
+            Some(&BeliefError::ValueOffStep { belief_id: id, value: 7250, value_step: 500 })
+        );
+
+        // Nothing was folded in
+        let belief = module.beliefs.get(&id, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.total_weight, 0);
+
+        assert_eq!(module.update_aggregate(id, 7500, 100, &mut working_set).unwrap(), 7500);
+    }
+
+    #[test]
+    fn test_misaligned_value_rounded() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        let id = module.create_belief_with_options(
+            "Bucketed".to_string(),
+            5000,
+            BeliefOptions {
+                value_step: 500,
+                value_step_mode: ValueStepMode::Round,
+                ..Default::default()
+            },
+            &mut working_set,
+        ).unwrap();
+
+        let normalize = |value, ws: &mut WorkingSet<S>| {
+            module.normalize_submission_value(id, value, ws).unwrap()
+        };
+        assert_eq!(normalize(7240, &mut working_set), 7000);
+        assert_eq!(normalize(7250, &mut working_set), 7500); // halfway rounds up
+        assert_eq!(normalize(7260, &mut working_set), 7500);
+        assert_eq!(normalize(SCALE, &mut working_set), SCALE);
+
+        assert_eq!(module.update_aggregate(id, 7260, 100, &mut working_set).unwrap(), 7500);
+    }
+
+    #[test]
+    fn test_rounding_never_exceeds_scale() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        // Buckets 0, 3000, 6000, 9000: SCALE isn't one of them
+        let id = module.create_belief_with_options(
+            "Thirds".to_string(),
+            6000,
+            BeliefOptions {
+                value_step: 3000,
+                value_step_mode: ValueStepMode::Round,
+                ..Default::default()
+            },
+            &mut working_set,
+        ).unwrap();
+
+        assert_eq!(module.normalize_submission_value(id, 9900, &mut working_set).unwrap(), 9000);
+    }
+
+    #[test]
+    fn test_mean_multiple_cap_compresses_weights() {
+        // One whale and four small agents: mean = 2800, cap at 2× mean = 5600
+        let raw = vec![10000, 1000, 1000, 1000, 1000];
+        let effective = cap_weights_at_mean_multiple(&raw, 2 * SCALE);
+        assert_eq!(effective, vec![5600, 1000, 1000, 1000, 1000]);
+
+        // Ordering is preserved
+        for i in 0..raw.len() {
+            for j in 0..raw.len() {
+                if raw[i] <= raw[j] {
+                    assert!(effective[i] <= effective[j]);
+                }
+            }
+        }
+
+        // The largest share of influence shrinks
+        let raw_total: u64 = raw.iter().sum();
+        let effective_total: u64 = effective.iter().sum();
+        assert!(effective[0] * raw_total < raw[0] * effective_total);
+
+        // A cap at exactly the mean flattens everything above it
+        assert_eq!(cap_weights_at_mean_multiple(&[300, 100, 200], SCALE), vec![200, 100, 200]);
+    }
+
+    #[test]
+    fn test_mean_multiple_cap_aggregate() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        let capped = module.create_belief_with_options(
+            "Capped".to_string(),
+            5000,
+            BeliefOptions {
+                weighting_mode: WeightingMode::MeanMultipleCap { max_multiple: 2 * SCALE },
+                ..Default::default()
+            },
+            &mut working_set,
+        ).unwrap();
+        let raw = module.create_belief("Raw".to_string(), 5000, &mut working_set).unwrap();
+
+        let submissions = [(SCALE, 10000), (0, 1000), (0, 1000), (0, 1000), (0, 1000)];
+        for (value, weight) in submissions {
+            module.update_aggregate(capped, value, weight, &mut working_set).unwrap();
+            module.update_aggregate(raw, value, weight, &mut working_set).unwrap();
+        }
+
+        // Raw: the whale dominates (~10000 / 14000, running average truncates)
+        let raw_state = module.get_belief_state(raw, &mut working_set).unwrap();
+        assert_eq!(raw_state.aggregate, 7141);
+
+        // Capped: whale weight clipped to 5600 (5600 / 9600)
+        let capped_state = module.get_belief_state(capped, &mut working_set).unwrap();
+        assert_eq!(capped_state.aggregate, 5833);
+
+        // total_weight keeps the raw sum
+        assert_eq!(capped_state.total_weight, raw_state.total_weight);
+        assert_eq!(
+            module.get_effective_weights(capped, &mut working_set).unwrap(),
+            vec![5600, 1000, 1000, 1000, 1000]
+        );
+    }
+
+    #[test]
+    fn test_min_quorum_weight() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let mut module = BeliefModule::<S>::default();
+
+        let id = module.create_belief_with_options(
+            "Needs quorum".to_string(),
+            5000,
+            BeliefOptions { min_quorum_weight: 3000, ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+
+        // Below quorum: the aggregate is reported but flagged as premature
+        module.update_aggregate(id, 8000, 2000, &mut working_set).unwrap();
+        let state = module.get_belief_state(id, &mut working_set).unwrap();
+        assert_eq!(state.aggregate, 8000);
+        assert!(!state.quorum_met);
+
+        // Reaching it exactly counts
+        module.update_aggregate(id, 2000, 1000, &mut working_set).unwrap();
+        let state = module.get_belief_state(id, &mut working_set).unwrap();
+        assert_eq!(state.total_weight, 3000);
+        assert!(state.quorum_met);
+
+        // Without a minimum, any aggregate is valid
+        let plain = module.create_belief("Plain".to_string(), 5000, &mut working_set).unwrap();
+        assert!(module.get_belief_state(plain, &mut working_set).unwrap().quorum_met);
+    }
+
+    #[test]
+    fn test_aggregate_bounds_clamp_extremes() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let mut module = BeliefModule::<S>::default();
+        let bounds = BeliefOptions { min_aggregate: 500, max_aggregate: 9500, ..Default::default() };
+        let id = module.create_belief_with_options("Bounded".to_string(), 5000, bounds.clone(), &mut working_set).unwrap();
+
+        // A heavy submission of certainty stops at the ceiling
+        assert_eq!(module.update_aggregate(id, SCALE, 100_000, &mut working_set).unwrap(), 9500);
+
+        // The next fold starts from the clamped value
+        assert_eq!(module.update_aggregate(id, 0, 1, &mut working_set).unwrap(), 9499);
+
+        // And the floor holds the other way
+        assert_eq!(module.update_aggregate(id, 0, 10_000_000, &mut working_set).unwrap(), 500);
+
+        // Default bounds leave certainty reachable
+        let plain = module.create_belief("Plain".to_string(), 5000, &mut working_set).unwrap();
+        assert_eq!(module.update_aggregate(plain, SCALE, 100_000, &mut working_set).unwrap(), SCALE);
+
+        // Inverted, out-of-range or excluding the initial value: rejected
+        for options in [
+            BeliefOptions { min_aggregate: 6000, max_aggregate: 4000, ..Default::default() },
+            BeliefOptions { max_aggregate: SCALE + 1, ..Default::default() },
+            BeliefOptions { min_aggregate: 6000, ..Default::default() },
+        ] {
+            assert!(module.create_belief_with_options("Bad".to_string(), 5000, options, &mut working_set).is_err());
+        }
+    }
+
+    #[test]
+    fn test_mean_multiple_cap_below_mean_rejected() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        let result = module.create_belief_with_options(
+            "Capped".to_string(),
+            5000,
+            BeliefOptions {
+                weighting_mode: WeightingMode::MeanMultipleCap { max_multiple: SCALE - 1 },
+                ..Default::default()
+            },
+            &mut working_set,
+        );
+        assert!(result.unwrap_err().to_string().contains("at least"));
+    }
+
+    #[test]
+    fn test_call_permissions_match_enforcement() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        let intruder = Context::new(generate_address::<S>("intruder"), Default::default(), 1);
+
+        let messages = [
+            ("no_op", CallMessage::NoOp),
+            (
+                "create_belief",
+                CallMessage::CreateBelief { question: "Q".to_string(), initial_value: 5000, options: BeliefOptions::default() },
+            ),
+            (
+                "create_categorical_belief",
+                CallMessage::CreateCategoricalBelief { question: "Q".to_string(), outcomes: vec!["A".into(), "B".into()] },
+            ),
+            ("resolve_belief", CallMessage::ResolveBelief { belief_id: 1, outcome: true }),
+            ("set_resolution_quorum", CallMessage::SetResolutionQuorum { belief_id: 1, quorum: None }),
+            ("archive_belief", CallMessage::ArchiveBelief { belief_id: 1 }),
+            (
+                "amend_belief_question",
+                CallMessage::AmendBeliefQuestion { belief_id: 1, new_question: "Q".to_string() },
+            ),
+            ("void_belief", CallMessage::VoidBelief { belief_id: 1 }),
+        ];
+
+        let permissions = module.call_permissions();
+        assert_eq!(permissions.len(), messages.len());
+
+        for (name, msg) in messages {
+            let entry = permissions.iter().find(|e| e.message == name)
+                .unwrap_or_else(|| panic!("{} missing from call_permissions", name));
+
+            let rejected_as_non_admin = module.call(msg, &intruder, &mut working_set)
+                .is_err_and(|e| e.to_string().contains("Only the admin"));
+            assert_eq!(rejected_as_non_admin, entry.permission == CallPermission::Admin, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_belief_progress_partway() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+
+        set_height(&module, 40, &mut working_set);
+        let id = module.create_belief_with_options(
+            "Deadline".to_string(),
+            5000,
+            BeliefOptions { deadline_block: Some(100), ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+        let quorum = ResolutionQuorum {
+            min_submissions: 3,
+            min_total_weight: 1000,
+            below_quorum: BelowQuorumPolicy::Block,
+        };
+        module.set_resolution_quorum(id, Some(quorum.clone()), &mut working_set).unwrap();
+
+        module.update_aggregate(id, 7000, 400, &mut working_set).unwrap();
+        module.update_aggregate(id, 6000, 300, &mut working_set).unwrap();
+        set_height(&module, 60, &mut working_set);
+
+        let progress = module.get_belief_progress(id, &mut working_set).unwrap();
+        assert_eq!(progress.current_block, 60);
+        assert_eq!(progress.blocks_remaining, Some(40));
+        assert_eq!((progress.submission_count, progress.total_weight), (2, 700));
+        assert_eq!(progress.quorum, Some(quorum));
+        assert!(!progress.quorum_met);
+        assert!(progress.accepting_submissions);
+        assert!(!progress.resolution_permitted);
+
+        // Past the deadline: closed to submissions
+        set_height(&module, 101, &mut working_set);
+        let progress = module.get_belief_progress(id, &mut working_set).unwrap();
+        assert_eq!(progress.blocks_remaining, Some(0));
+        assert!(!progress.accepting_submissions);
+        assert!(module.update_aggregate(id, 6000, 300, &mut working_set).is_err());
+
+        // Belief 1 has neither deadline nor quorum: open-ended and resolvable
+        let progress = module.get_belief_progress(1, &mut working_set).unwrap();
+        assert_eq!((progress.deadline_block, progress.blocks_remaining, progress.quorum), (None, None, None));
+        assert!(progress.accepting_submissions && progress.resolution_permitted);
+    }
+
+    #[test]
+    fn test_block_timestamp_reads_chain_state_time() {
+        // Two independent runs with the same chain time agree, however often they ask
+        let timestamps: Vec<u64> = (0..2)
+            .map(|_| {
+                let module = BeliefModule::<S>::default();
+                let mut working_set = WorkingSet::<S>::new(Default::default());
+                module.chain_state.time.set(&Time::from_secs(1_700_001_500), &mut working_set).unwrap();
+                set_height(&module, 250, &mut working_set);
+
+                let first = module.block_timestamp(&mut working_set).unwrap();
+                assert_eq!(module.block_timestamp(&mut working_set).unwrap(), first);
+                assert_eq!(module.current_block(&mut working_set).unwrap(), 250);
+                first
+            })
+            .collect();
+        assert_eq!(timestamps, vec![1_700_001_500, 1_700_001_500]);
+
+        // A time before the epoch doesn't wrap around
+        let module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.chain_state.time.set(&Time::from_secs(-5), &mut working_set).unwrap();
+        assert_eq!(module.block_timestamp(&mut working_set).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_interval_reflects_disagreement() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        let options = BeliefOptions { report_interval: true, ..Default::default() };
+        let split = module.create_belief_with_options("Split".to_string(), 5000, options.clone(), &mut working_set).unwrap();
+        let agreed = module.create_belief_with_options("Agreed".to_string(), 5000, options, &mut working_set).unwrap();
+
+        // Not enough submissions yet: no interval
+        module.update_aggregate(split, 2000, 100, &mut working_set).unwrap();
+        let state = module.get_belief_state(split, &mut working_set).unwrap();
+        assert_eq!((state.median, state.interval_low, state.interval_high), (Some(2000), None, None));
+
+        for value in [8000, 3000, 7000] {
+            module.update_aggregate(split, value, 100, &mut working_set).unwrap();
+        }
+        for value in [4900, 5000, 5100, 5000] {
+            module.update_aggregate(agreed, value, 100, &mut working_set).unwrap();
+        }
+
+        let split_state = module.get_belief_state(split, &mut working_set).unwrap();
+        assert_eq!((split_state.interval_low, split_state.interval_high), (Some(2000), Some(7000)));
+        assert_eq!(split_state.median, Some(3000));
+
+        let agreed_state = module.get_belief_state(agreed, &mut working_set).unwrap();
+        assert_eq!((agreed_state.interval_low, agreed_state.interval_high), (Some(4900), Some(5000)));
+        assert_eq!(agreed_state.median, Some(5000));
+
+        // Plain beliefs don't report an interval at all
+        let plain = module.create_belief("Plain".to_string(), 5000, &mut working_set).unwrap();
+        module.update_aggregate(plain, 2000, 100, &mut working_set).unwrap();
+        module.update_aggregate(plain, 8000, 100, &mut working_set).unwrap();
+        let plain_state = module.get_belief_state(plain, &mut working_set).unwrap();
+        assert_eq!((plain_state.median, plain_state.interval_low), (None, None));
+    }
+
+    #[test]
+    fn test_aggregate_display_formats() {
+        let full = AggregateDisplay::new(1, SCALE);
+        assert_eq!((full.raw, full.decimal.as_str(), full.percent.as_str()), (10000, "1.0000", "100%"));
+
+        let zero = AggregateDisplay::new(1, 0);
+        assert_eq!((zero.raw, zero.decimal.as_str(), zero.percent.as_str()), (0, "0.0000", "0%"));
+
+        let partial = AggregateDisplay::new(1, 7520);
+        assert_eq!((partial.decimal.as_str(), partial.percent.as_str()), ("0.7520", "75.2%"));
+        assert_eq!(AggregateDisplay::new(1, 5).percent, "0.05%");
+    }
+
+    #[test]
+    fn test_precision_matches_scale() {
+        assert_eq!(Precision::current(), Precision { scale: 10000, decimals: 4 });
+        assert_eq!(veritas_belief::scale(), SCALE);
+    }
+
+    #[test]
+    fn test_first_submission_under_each_mode() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let capped = WeightingMode::MeanMultipleCap { max_multiple: 12000 };
+        let prior = FirstSubmissionAnchor::Prior { weight: 100 };
+
+        // (weighting mode, anchor, expected aggregate after
+        // 9000 @ weight 300 on a belief created at 5000)
+        let cases = [
+            // The placeholder is replaced outright in every mode
+            (WeightingMode::Raw, FirstSubmissionAnchor::Replace, 9000),
+            (capped, FirstSubmissionAnchor::Replace, 9000),
+            // (5000 × 100 + 9000 × 300) / 400
+            (WeightingMode::Raw, prior, 8000),
+            // Weights [100, 300], cap = 1.2 × 400 / 2 = 240: (5000 × 100 + 9000 × 240) / 340
+            (capped, prior, 7823),
+        ];
+
+        for (weighting_mode, first_submission, expected) in cases {
+            let id = module.create_belief_with_options(
+                "First".to_string(),
+                5000,
+                BeliefOptions { weighting_mode, first_submission, ..Default::default() },
+                &mut working_set,
+            ).unwrap();
+
+            let aggregate = module.update_aggregate(id, 9000, 300, &mut working_set).unwrap();
+            assert_eq!(aggregate, expected, "{:?} / {:?}", weighting_mode, first_submission);
+
+            // The prior never counts as submitted weight
+            let belief = module.beliefs.get(&id, &mut working_set).unwrap().unwrap();
+            assert_eq!(belief.total_weight, 300);
+        }
+    }
+
+    #[test]
+    fn test_variance_tracks_spread() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        // Identical values never spread, whatever their weights
+        let agreed = module.create_belief("Agreed".to_string(), 5000, &mut working_set).unwrap();
+        for weight in [100, 200, 300] {
+            module.update_aggregate(agreed, 7000, weight, &mut working_set).unwrap();
+        }
+        assert_eq!(module.get_belief_state(agreed, &mut working_set).unwrap().variance, 0);
+
+        // Mean (2000 + 6000 + 2 × 10000) / 4 = 7000;
+        // variance (5000² + 1000² + 2 × 3000²) / 4 = 11_000_000
+        let spread = module.create_belief("Spread".to_string(), 5000, &mut working_set).unwrap();
+        for (value, weight) in [(2000, 1), (6000, 1), (10000, 2)] {
+            module.update_aggregate(spread, value, weight, &mut working_set).unwrap();
+        }
+        let state = module.get_belief_state(spread, &mut working_set).unwrap();
+        assert_eq!((state.aggregate, state.variance), (7000, 11_000_000));
+
+        // An even split between the extremes: standard deviation SCALE / 2
+        let split = module.create_belief("Split".to_string(), 5000, &mut working_set).unwrap();
+        module.update_aggregate(split, 0, 1000, &mut working_set).unwrap();
+        module.update_aggregate(split, SCALE, 1000, &mut working_set).unwrap();
+        assert_eq!(module.get_belief_state(split, &mut working_set).unwrap().variance, (SCALE / 2).pow(2));
+    }
+
+    #[test]
+    fn test_categorical_weighted_average() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let outcomes = vec!["Home".to_string(), "Draw".to_string(), "Away".to_string()];
+        let id = module.create_categorical_belief("Match result?".to_string(), outcomes, &mut working_set).unwrap();
+
+        // Uniform start, still summing to SCALE
+        let belief = module.categorical_beliefs.get(&id, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.aggregates, vec![3334, 3333, 3333]);
+
+        // The first weighted submission replaces the starting distribution
+        let aggregates = module.update_categorical_aggregate(id, &[6000, 3000, 1000], 100, &mut working_set).unwrap();
+        assert_eq!(aggregates, vec![6000, 3000, 1000]);
+
+        // (6000 × 100 + 2000 × 300) / 400 = 3000, (3000 × 100 + 2000 × 300) / 400 = 2250,
+        // (1000 × 100 + 6000 × 300) / 400 = 4750
+        let aggregates = module.update_categorical_aggregate(id, &[2000, 2000, 6000], 300, &mut working_set).unwrap();
+        assert_eq!(aggregates, vec![3000, 2250, 4750]);
+
+        let belief = module.categorical_beliefs.get(&id, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.total_weight, 400);
+        assert_eq!(module.submission_counts.get(&id, &mut working_set).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn test_categorical_distribution_validation() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let outcomes = vec!["A".to_string(), "B".to_string(), "C".to_string()];
+        let id = module.create_categorical_belief("Which?".to_string(), outcomes, &mut working_set).unwrap();
+
+        // Wrong length, entry above SCALE, sum off by more than one unit per outcome
+        for distribution in [vec![5000, 5000], vec![SCALE + 1, 0, 0], vec![5000, 3000, 1996]] {
+            assert!(module.update_categorical_aggregate(id, &distribution, 100, &mut working_set).is_err());
+        }
+
+        // Rounded client values are accepted and renormalized to SCALE
+        let aggregates = module.update_categorical_aggregate(id, &[3333, 3333, 3333], 100, &mut working_set).unwrap();
+        assert_eq!(aggregates, vec![3334, 3333, 3333]);
+
+        // Categorical beliefs need 2 to 32 outcomes and share IDs with binary beliefs
+        assert!(module.create_categorical_belief("One?".to_string(), vec!["A".to_string()], &mut working_set).is_err());
+        assert_eq!(module.create_belief("Binary?".to_string(), 5000, &mut working_set).unwrap(), id + 1);
+    }
+
+    #[test]
+    fn test_categorical_zero_weight_rejected() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let outcomes = vec!["A".to_string(), "B".to_string()];
+        let id = module.create_categorical_belief("Which?".to_string(), outcomes, &mut working_set).unwrap();
+        module.update_categorical_aggregate(id, &[7000, 3000], 100, &mut working_set).unwrap();
+
+        let err = module.update_categorical_aggregate(id, &[1000, 9000], 0, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<BeliefError>(), Some(&BeliefError::ZeroWeight));
+
+        // Neither counted nor folded in
+        assert_eq!(module.submission_counts.get(&id, &mut working_set).unwrap(), Some(1));
+        let belief = module.categorical_beliefs.get(&id, &mut working_set).unwrap().unwrap();
+        assert_eq!((belief.aggregates, belief.total_weight), (vec![7000, 3000], 100));
+    }
+
+    #[test]
+    fn test_total_weight_beyond_u64() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+
+        // Each weight alone fills a u64; a saturating total would pin the
+        // denominator and overweight every later submission
+        module.update_aggregate(1, 8000, u64::MAX, &mut working_set).unwrap();
+        module.update_aggregate(1, 2000, u64::MAX, &mut working_set).unwrap();
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.aggregate, 5000);
+        assert_eq!(belief.total_weight, 2 * u64::MAX as u128);
+
+        // (5000 × 2M + 2000 × M) / 3M = 4000
+        module.update_aggregate(1, 2000, u64::MAX, &mut working_set).unwrap();
+        let state = module.get_belief_state(1, &mut working_set).unwrap();
+        assert_eq!(state.aggregate, 4000);
+        assert_eq!(state.total_weight, 3 * u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_list_beliefs_by_status() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+        for question in ["Q2", "Q3"] {
+            module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        module.call(CallMessage::ResolveBelief { belief_id: 2, outcome: true }, &admin, &mut working_set).unwrap();
+
+        let mut list = |status| module.list_beliefs(0, 10, status, None, &mut working_set).unwrap().beliefs;
+        let resolved = list(Some(BeliefStatus::Resolved));
+        assert_eq!(resolved.iter().map(|b| b.id).collect::<Vec<_>>(), vec![2]);
+        assert!(resolved[0].resolved);
+        assert_eq!(resolved[0].outcome, Some(SCALE));
+
+        let open = list(Some(BeliefStatus::Open));
+        assert_eq!(open.iter().map(|b| b.id).collect::<Vec<_>>(), vec![1, 3]);
+        assert!(open.iter().all(|b| !b.resolved && b.outcome.is_none()));
+
+        assert_eq!(list(None).len(), 3);
+    }
+
+    #[test]
+    fn test_top_beliefs_by_weight() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        for question in ["Q2", "Q3", "Q4"] {
+            module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+
+        // Beliefs 2 and 4 tie; belief 1 stays at zero
+        for (belief_id, weight) in [(2, 300), (3, 700), (4, 300)] {
+            module.update_aggregate(belief_id, 6000, weight, &mut working_set).unwrap();
+        }
+
+        let top = |n, working_set: &mut WorkingSet<S>| -> Vec<(u64, u128)> {
+            module.top_beliefs_by_weight(n, working_set).unwrap()
+                .iter()
+                .map(|b| (b.id, b.total_weight))
+                .collect()
+        };
+        assert_eq!(top(10, &mut working_set), vec![(3, 700), (2, 300), (4, 300), (1, 0)]);
+        assert_eq!(top(2, &mut working_set), vec![(3, 700), (2, 300)]);
+        assert!(top(0, &mut working_set).is_empty());
+    }
+
+    #[test]
+    fn test_list_beliefs_by_category() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = setup(&mut working_set);
+        for (question, category) in [("BTC?", "crypto"), ("Cup?", "sports"), ("ETH?", "crypto")] {
+            module.call(
+                CallMessage::CreateBelief {
+                    question: question.to_string(),
+                    initial_value: 5000,
+                    options: BeliefOptions { category: category.to_string(), ..Default::default() },
+                },
+                &admin,
+                &mut working_set,
+            ).unwrap();
+        }
+
+        let mut ids = |category| {
+            module.list_beliefs(0, 10, None, category, &mut working_set).unwrap()
+                .beliefs.iter().map(|b| b.id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(Some("crypto")), vec![2, 4]);
+        assert_eq!(ids(Some("sports")), vec![3]);
+        assert!(ids(Some("politics")).is_empty());
+        // The setup belief was created without a category
+        assert_eq!(ids(Some(DEFAULT_CATEGORY)), vec![1]);
+        assert_eq!(ids(Some(ANY_CATEGORY)), vec![1, 2, 3, 4]);
+        assert_eq!(ids(None), vec![1, 2, 3, 4]);
+
+        let state = module.get_belief_state(2, &mut working_set).unwrap();
+        assert_eq!(state.category, "crypto");
+
+        for (category, error) in [("", "Category cannot be empty"), (ANY_CATEGORY, "reserved")] {
+            let result = module.call(
+                CallMessage::CreateBelief {
+                    question: "Q".to_string(),
+                    initial_value: 5000,
+                    options: BeliefOptions { category: category.to_string(), ..Default::default() },
+                },
+                &admin,
+                &mut working_set,
+            );
+            assert!(result.unwrap_err().to_string().contains(error), "{}", error);
+        }
+        assert!(module.beliefs.get(&5, &mut working_set).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decay_factor() {
+        assert_eq!(decay_factor(9000, 0), SCALE as u128);
+        assert_eq!(decay_factor(9000, 1), 9000);
+        // 0.9^10 = 0.34867..., rounded down at every squaring
+        assert_eq!(decay_factor(9000, 10), 3486);
+        assert_eq!(decay_factor(9000, 100), 0);
+        assert_eq!(decay_factor(SCALE, u64::MAX), SCALE as u128);
+        assert_eq!(decay_factor(0, 1), 0);
+    }
+
+    #[test]
+    fn test_decayed_aggregate() {
+        // Two submissions `gap` blocks apart; returns (aggregate, total_weight)
+        let run = |decay: AggregateDecay, gap: u64| {
+            let mut working_set = WorkingSet::<S>::new(Default::default());
+            let (mut module, _admin) = setup(&mut working_set);
+            module.aggregate_decay.set(&decay, &mut working_set).unwrap();
+
+            module.update_aggregate(1, 8000, 10_000, &mut working_set).unwrap();
+            set_height(&module, gap, &mut working_set);
+            module.update_aggregate(1, 2000, 10_000, &mut working_set).unwrap();
+
+            let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+            (belief.aggregate, belief.total_weight)
+        };
+        let decay = AggregateDecay::PerBlock { retain: 9000 };
+
+        // Without decay equal weights meet halfway, however far apart
+        assert_eq!(run(AggregateDecay::None, 10), (5000, 20_000));
+
+        // After 10 blocks only 3486 of the first 10_000 weight is left:
+        // (8000 × 3486 + 2000 × 10_000) / 13_486 = 3550
+        assert_eq!(run(decay, 10), (3550, 20_000));
+
+        // Same-block submissions don't decay at all
+        assert_eq!(run(decay, 0).0, 5000);
+
+        // A long idle stretch forgets the first submission entirely
+        assert_eq!(run(decay, 100).0, 2000);
+    }
+
+    #[test]
+    fn test_open_beliefs_index() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        let config = SnapshotConfig { enabled: true, interval_blocks: 1, max_snapshots: 3 };
+        module.snapshot_config.set(&config, &mut working_set).unwrap();
+        let open = |module: &BeliefModule<S>, working_set: &mut WorkingSet<S>| {
+            module.open_beliefs.get(working_set).unwrap().unwrap_or_default()
+        };
+
+        let resolved = module.create_belief("Resolved".to_string(), 5000, &mut working_set).unwrap();
+        let voided = module.create_belief("Voided".to_string(), 5000, &mut working_set).unwrap();
+        // Categorical beliefs have no history, so they aren't indexed
+        let outcomes = vec!["A".to_string(), "B".to_string()];
+        module.create_categorical_belief("Which?".to_string(), outcomes, &mut working_set).unwrap();
+        assert_eq!(open(&module, &mut working_set), vec![1, resolved, voided]);
+
+        set_height(&module, 3, &mut working_set);
+        module.resolve_belief(resolved, true, &mut working_set).unwrap();
+        module.void_belief(voided, &mut working_set).unwrap();
+        assert_eq!(open(&module, &mut working_set), vec![1]);
+
+        // Archiving a closed belief leaves the index alone; rolling back a void reopens it
+        module.archive_belief(resolved, &mut working_set).unwrap();
+        module.rollback_belief(voided, 3, &mut working_set).unwrap();
+        assert_eq!(open(&module, &mut working_set), vec![1, voided]);
+    }
+
+    #[test]
+    fn test_rollback_to_snapshot() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        let config = SnapshotConfig { enabled: true, interval_blocks: 1, max_snapshots: 3 };
+        module.snapshot_config.set(&config, &mut working_set).unwrap();
+
+        // Block 0 snapshots the fresh belief, block 1 the state after the first submission
+        module.update_aggregate(1, 8000, 100, &mut working_set).unwrap();
+        set_height(&module, 1, &mut working_set);
+        module.update_aggregate(1, 2000, 100, &mut working_set).unwrap();
+        // Same block as the latest snapshot: nothing new is recorded
+        assert_eq!(module.update_aggregate(1, 2000, 200, &mut working_set).unwrap(), 3500);
+        assert_eq!(module.get_snapshot_heights(1, &mut working_set).unwrap(), vec![0, 1]);
+
+        let missing = module.rollback_belief(1, 5, &mut working_set);
+        assert!(missing.unwrap_err().to_string().contains("No snapshot"));
+
+        module.rollback_belief(1, 1, &mut working_set).unwrap();
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.aggregate, 8000);
+        assert_eq!(belief.total_weight, 100);
+        assert_eq!(module.submission_counts.get(&1, &mut working_set).unwrap(), Some(1));
+
+        // A wrong resolution is undone too, reopening the belief
+        set_height(&module, 2, &mut working_set);
+        module.resolve_belief(1, false, &mut working_set).unwrap();
+        module.rollback_belief(1, 2, &mut working_set).unwrap();
+        let belief = module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert!(!belief.resolved);
+        assert_eq!(belief.outcome, None);
+        module.update_aggregate(1, 8000, 100, &mut working_set).unwrap();
+
+        // Retention keeps the newest three
+        for height in 3..=4 {
+            set_height(&module, height, &mut working_set);
+            module.update_aggregate(1, 5000, 100, &mut working_set).unwrap();
+        }
+        assert_eq!(module.get_snapshot_heights(1, &mut working_set).unwrap(), vec![2, 3, 4]);
+        assert!(module.snapshots.get(&(1, 0), &mut working_set).unwrap().is_none());
+
+        // Rolling back drops the snapshots taken after the restored one
+        module.rollback_belief(1, 3, &mut working_set).unwrap();
+        assert_eq!(module.get_snapshot_heights(1, &mut working_set).unwrap(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_weighted_median_resists_outlier() {
+        // Three agreeing agents and one heavy outlier short of half the weight
+        let submissions = [(3000, 400), (3200, 400), (3400, 400), (9500, 1000)];
+        let run = |mode: AggregationMode| {
+            let mut working_set = WorkingSet::<S>::new(Default::default());
+            let (mut module, _admin) = setup(&mut working_set);
+            module.aggregation_mode.set(&mode, &mut working_set).unwrap();
+            let id = module.create_belief("Q2".to_string(), 5000, &mut working_set).unwrap();
+            for (value, weight) in submissions {
+                module.update_aggregate(id, value, weight, &mut working_set).unwrap();
+            }
+            let belief = module.beliefs.get(&id, &mut working_set).unwrap().unwrap();
+            let kept = module.contributions.get(&id, &mut working_set).unwrap().unwrap_or_default();
+            (belief.aggregate, belief.total_weight, kept.len())
+        };
+
+        // (3000×400 + 3200×400 + 3400×400 + 9500×1000) / 2200 = 6063
+        assert_eq!(run(AggregationMode::Mean), (6063, 2200, 0));
+
+        // Cumulative weight 400, 800, 1200: 3400 is the first to reach 1100
+        assert_eq!(run(AggregationMode::WeightedMedian), (3400, 2200, 4));
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!((RoundingMode::Truncate.divide(5, 2), RoundingMode::RoundHalfUp.divide(5, 2)), (2, 3));
+        assert_eq!((RoundingMode::Truncate.divide(4, 3), RoundingMode::RoundHalfUp.divide(4, 3)), (1, 1));
+        assert_eq!((RoundingMode::Truncate.divide(5, 3), RoundingMode::RoundHalfUp.divide(5, 3)), (1, 2));
+
+        // Slowly rising values: every truncated update loses just under a unit
+        let run = |mode: RoundingMode| {
+            let mut working_set = WorkingSet::<S>::new(Default::default());
+            let mut module = BeliefModule::<S>::default();
+            module.rounding_mode.set(&mode, &mut working_set).unwrap();
+            let id = module.create_belief("Rising".to_string(), 5000, &mut working_set).unwrap();
+            let mut aggregate = 0;
+            for value in 5001..=5010 {
+                aggregate = module.update_aggregate(id, value, 1, &mut working_set).unwrap();
+            }
+            let belief = module.beliefs.get(&id, &mut working_set).unwrap().unwrap();
+            assert_eq!(belief.rounding, mode);
+            aggregate
+        };
+
+        // The exact mean is 5005.5: truncation never leaves the first value
+        assert_eq!(run(RoundingMode::Truncate), 5001);
+        assert_eq!(run(RoundingMode::RoundHalfUp), 5006);
+    }
+
+    #[test]
+    fn test_weighted_median_even_split() {
+        let median = |pairs: &[(u64, u64)]| {
+            let contributions: Vec<Contribution> = pairs.iter()
+                .map(|(value, weight)| Contribution { value: *value, weight: *weight })
+                .collect();
+            weighted_median(&contributions)
+        };
+
+        // Two values, equal weight: the midpoint, whichever order they come in
+        assert_eq!(median(&[(2000, 100), (8000, 100)]), Some(5000));
+        assert_eq!(median(&[(8000, 100), (2000, 100)]), Some(5000));
+        // Rounded down
+        assert_eq!(median(&[(1000, 7), (2001, 7)]), Some(1500));
+        // Symmetric around 5000: cumulative 200 of 400 after 3000, interpolated with 7000
+        assert_eq!(median(&[(2000, 100), (3000, 100), (7000, 100), (8000, 100)]), Some(5000));
+        // Straddling values weighted by their own (merged) weights: (3000×100 + 8000×200) / 300
+        assert_eq!(median(&[(2000, 100), (3000, 100), (8000, 150), (8000, 50)]), Some(6333));
+        // No exact split: plain crossing value; zero weights ignored
+        assert_eq!(median(&[(2000, 100), (3000, 101), (9000, 0)]), Some(3000));
+        assert_eq!(median(&[(2000, 0)]), None);
+
+        // End to end in WeightedMedian mode
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        module.aggregation_mode.set(&AggregationMode::WeightedMedian, &mut working_set).unwrap();
+        let id = module.create_belief("Split".to_string(), 5000, &mut working_set).unwrap();
+        module.update_aggregate(id, 1000, 500, &mut working_set).unwrap();
+        assert_eq!(module.update_aggregate(id, 6000, 500, &mut working_set).unwrap(), 3500);
+    }
+
+    #[test]
+    fn test_genesis_belief_ids() {
+        let beliefs = |ids: &[u64]| -> Vec<Belief> {
+            ids.iter().map(|id| Belief::new(*id, format!("Q{}", id), 5000)).collect()
+        };
+
+        // Out of order: the next ID still goes past the largest
+        assert_eq!(genesis_next_belief_id(&beliefs(&[7, 2, 4])).unwrap(), 8);
+        assert_eq!(genesis_next_belief_id(&beliefs(&[])).unwrap(), 1);
+
+        let duplicate = genesis_next_belief_id(&beliefs(&[3, 1, 3])).unwrap_err();
+        assert!(duplicate.to_string().contains("Duplicate genesis belief ID 3"));
+        assert!(genesis_next_belief_id(&beliefs(&[0, 1])).is_err());
+
+        // The last possible ID would leave nothing to allocate
+        assert!(genesis_next_belief_id(&beliefs(&[1, u64::MAX])).is_err());
+    }
+
+    #[test]
+    fn test_belief_ids_exhausted_after_largest_genesis_id() {
+        let mut module = BeliefModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        // A genesis ID of u64::MAX - 1 is allowed but leaves the counter at u64::MAX
+        let genesis = vec![Belief::new(u64::MAX - 1, "Last".to_string(), 5000)];
+        let next = genesis_next_belief_id(&genesis).unwrap();
+        assert_eq!(next, u64::MAX);
+        module.next_belief_id.set(&next, &mut working_set).unwrap();
+
+        for result in [
+            module.create_belief("Plain".to_string(), 5000, &mut working_set),
+            module.create_categorical_belief(
+                "Which?".to_string(),
+                vec!["A".to_string(), "B".to_string()],
+                &mut working_set,
+            ),
+        ] {
+            let err = result.unwrap_err();
+            assert_eq!(err.downcast_ref::<BeliefError>(), Some(&BeliefError::IdsExhausted));
+        }
+
+        // Nothing was half-created under the last ID
+        assert_eq!(module.next_belief_id.get(&mut working_set).unwrap(), Some(u64::MAX));
+        assert!(module.beliefs.get(&u64::MAX, &mut working_set).unwrap().is_none());
+        assert!(module.categorical_beliefs.get(&u64::MAX, &mut working_set).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_genesis_accepts_legacy_float_probabilities() {
+        let genesis = |aggregate: &str| {
+            let json = format!(
+                r#"{{"initial_beliefs": [{{"id": 1, "question": "Q1", "aggregate": {0}, "initial_value": {0}, "total_weight": 0}}]}}"#,
+                aggregate
+            );
+            serde_json::from_str::<veritas_belief::GenesisConfig<S>>(&json)
+        };
+
+        // f64-era files and fixed-point files describe the same belief
+        for aggregate in ["0.75", "7500"] {
+            let config = genesis(aggregate).unwrap();
+            let belief = &config.initial_beliefs[0];
+            assert_eq!((belief.aggregate, belief.initial_value), (7500, Some(7500)), "{}", aggregate);
+        }
+
+        // Floats round to the nearest unit; certainty is 1.0, not 1
+        assert_eq!(genesis("0.12346").unwrap().initial_beliefs[0].aggregate, 1235);
+        assert_eq!(genesis("1.0").unwrap().initial_beliefs[0].aggregate, SCALE);
+        assert_eq!(genesis("1").unwrap().initial_beliefs[0].aggregate, 1);
+
+        // Outside 0.0..=1.0 isn't a probability
+        assert!(genesis("1.5").is_err());
+        assert!(genesis("-0.1").is_err());
+    }
+
+    #[test]
+    fn test_update_aggregate_typed_errors() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        let deadline = module.create_belief_with_options(
+            "Deadline".to_string(),
+            5000,
+            BeliefOptions { deadline_block: Some(10), ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+
+        fn error_for(module: &mut BeliefModule<S>, belief_id: u64, working_set: &mut WorkingSet<S>) -> Option<BeliefError> {
+            module.update_aggregate(belief_id, 7000, 100, working_set).unwrap_err()
+                .downcast_ref::<BeliefError>().copied()
+        }
+
+        assert_eq!(error_for(&mut module, 99, &mut working_set), Some(BeliefError::NotFound { belief_id: 99 }));
+
+        // Past the deadline, whoever calls
+        set_height(&module, 11, &mut working_set);
+        assert_eq!(
+            error_for(&mut module, deadline, &mut working_set),
+            Some(BeliefError::PastDeadline { belief_id: deadline, deadline_block: 10 })
+        );
+
+        module.resolve_belief(1, true, &mut working_set).unwrap();
+        assert_eq!(error_for(&mut module, 1, &mut working_set), Some(BeliefError::Resolved { belief_id: 1 }));
+    }
+
+    #[test]
+    fn test_zero_weight_rejected() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+
+        let err = module.update_aggregate(1, 7000, 0, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<BeliefError>(), Some(&BeliefError::ZeroWeight));
+
+        // The preview agrees with the real update
+        let err = module.preview_update(1, 7000, 0, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<BeliefError>(), Some(&BeliefError::ZeroWeight));
+
+        // Nothing was counted or moved
+        let state = module.get_belief_state(1, &mut working_set).unwrap();
+        assert_eq!((state.submission_count, state.aggregate, state.total_weight), (0, 5000, 0));
+    }
+
+    #[test]
+    fn test_recent_aggregates_keep_last_k_in_order() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, _admin) = setup(&mut working_set);
+        module.recent_aggregates_capacity.set(&3, &mut working_set).unwrap();
+
+        let mut aggregates = Vec::new();
+        for value in [9000, 1000, 8000, 2000, 7000] {
+            aggregates.push(module.update_aggregate(1, value, 1000, &mut working_set).unwrap());
+        }
+
+        // Five updates into three slots: the last three, oldest first
+        assert_eq!(module.get_recent_aggregates(1, &mut working_set).unwrap(), aggregates[2..].to_vec());
+
+        // Capacity 0 keeps nothing
+        module.recent_aggregates_capacity.set(&0, &mut working_set).unwrap();
+        let other = module.create_belief("Other".to_string(), 5000, &mut working_set).unwrap();
+        module.update_aggregate(other, 9000, 1000, &mut working_set).unwrap();
+        assert!(module.get_recent_aggregates(other, &mut working_set).unwrap().is_empty());
+
+        let err = module.get_recent_aggregates(99, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<BeliefError>(), Some(&BeliefError::NotFound { belief_id: 99 }));
+    }
+}
@@ -0,0 +1,102 @@
+//! Aggregate audit - checking a running mean against its submissions
+//!
+//! FILE PURPOSE:
+//! BeliefModule folds each submission into a running weighted mean, rounding
+//! on every update (truncating by default), so the stored aggregate can drift
+//! a little from the exact mean of everything submitted. The integrity sweep (integrity.rs) checks
+//! totals but not the aggregate itself. `recompute_aggregate` recomputes the
+//! mean from scratch, in u128, over the belief's current (non-retracted)
+//! submissions and returns it next to the stored one. It never writes.
+//!
+//! ROUNDING TOLERANCE:
+//! Each fold rounds once, losing less than one unit, and the loss of earlier
+//! folds only shrinks as weight accumulates. So for a healthy belief
+//!   |stored - recomputed| <= submission count
+//! (`AggregateAudit::tolerance`). With the default Truncate rounding the stored
+//! value is never above the recomputed one; with RoundHalfUp it can sit on
+//! either side (see veritas_belief's rounding.rs). Anything outside that range
+//! is real drift; `repair_belief` rebuilds the belief from its log.
+//!
+//! SCOPE:
+//! Only beliefs whose aggregate IS a running weighted mean can be audited this
+//! way: Raw weighting, Mean aggregation, no decay applied and no aggregate bounds.
+//! Capped, median, decayed and clamped aggregates are different quantities and
+//! are rejected.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+use veritas_belief::{AggregationMode, BeliefId, WeightingMode};
+
+use crate::{SubmissionError, SubmissionModule};
+
+/// A belief's stored aggregate next to the one recomputed from its submissions
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct AggregateAudit {
+    pub belief_id: BeliefId,
+
+    /// `aggregate` as stored on the belief
+    pub stored_aggregate: u64,
+
+    /// Exact weighted mean of the current submissions (and prior), rounded down
+    pub recomputed_aggregate: u64,
+
+    /// Largest gap rounding alone can explain: one unit per submission
+    pub tolerance: u64,
+}
+
+impl AggregateAudit {
+    /// Whether the stored aggregate is within rounding of the recomputed one
+    pub fn is_consistent(&self) -> bool {
+        self.stored_aggregate.abs_diff(self.recomputed_aggregate) <= self.tolerance
+    }
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Recomputes a belief's weighted mean from its recorded submissions
+    ///
+    /// Read-only; unlike `BeliefModule::recompute_aggregate`, nothing is written.
+    pub fn recompute_aggregate(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<AggregateAudit> {
+        let Some(belief) = self.belief_module.beliefs.get(&belief_id, state)? else {
+            return Err(SubmissionError::BeliefNotFound { belief_id }.into());
+        };
+        if belief.weighting_mode != WeightingMode::Raw
+            || belief.aggregation != AggregationMode::Mean
+            || belief.decayed_weight.is_some()
+            || belief.has_aggregate_bounds()
+        {
+            bail!("Belief {} doesn't aggregate by a plain running mean", belief_id);
+        }
+
+        // The prior, if any, is weight already behind the aggregate
+        let submissions = self.get_submissions(belief_id, state)?;
+        let mut weighted_sum: u128 = 0;
+        let mut total_weight: u128 = 0;
+        for contribution in belief.prior.iter() {
+            weighted_sum += contribution.value as u128 * contribution.weight as u128;
+            total_weight += contribution.weight as u128;
+        }
+        for submission in &submissions {
+            weighted_sum += submission.value as u128 * submission.weight as u128;
+            total_weight += submission.weight as u128;
+        }
+
+        // No weight at all: the belief still shows its starting value
+        let recomputed_aggregate = if total_weight == 0 {
+            belief.initial_value.unwrap_or(belief.aggregate)
+        } else {
+            (weighted_sum / total_weight) as u64
+        };
+
+        Ok(AggregateAudit {
+            belief_id,
+            stored_aggregate: belief.aggregate,
+            recomputed_aggregate,
+            tolerance: submissions.len() as u64,
+        })
+    }
+}
@@ -0,0 +1,97 @@
+//! Categorical submissions - distributions over a multi-outcome belief
+//!
+//! FILE PURPOSE:
+//! A categorical belief (see veritas_belief's categorical.rs) takes a whole
+//! probability distribution per submission instead of a single value.
+//! `SubmitCategorical` weighs it like a binary submission (stake × score,
+//! bounded by the weight cap) and hands it to `update_categorical_aggregate`.
+//!
+//! DIFFERENCES FROM BINARY SUBMISSIONS (for now):
+//! - One submission per agent and belief; a second one is rejected rather than overwriting
+//! - No accuracy bonus, submission fee or stake lock, since categorical
+//!   beliefs can't be resolved yet; the withdrawal cooldown still applies
+//! - Only the total-stake weight basis is supported
+//! - Recorded in their own log, `categorical_submissions`
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{Context, Spec, TxState};
+use veritas_belief::BeliefId;
+
+use crate::{SubmissionError, SubmissionModule, WeightBasis};
+
+/// Records a single distribution submitted to a categorical belief
+#[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, JsonSchema)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+pub struct CategoricalSubmission<S: Spec> {
+    pub agent: S::Address,
+
+    pub belief_id: BeliefId,
+
+    /// Probability per outcome (SCALE units, summing to SCALE within rounding)
+    pub distribution: Vec<u64>,
+
+    /// The agent's weight at time of submission
+    pub weight: u64,
+
+    /// Block timestamp from BeliefModule::block_timestamp
+    pub timestamp: u64,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Submits a distribution to a categorical belief
+    ///
+    /// Returns the belief's new aggregates.
+    pub fn submit_categorical(
+        &mut self,
+        belief_id: BeliefId,
+        distribution: Vec<u64>,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<u64>> {
+        if self.paused.get(state)?.unwrap_or(false) {
+            return Err(SubmissionError::Paused.into());
+        }
+
+        // Same error precedence as submit_belief: belief first, then agent
+        let sender = context.sender();
+        if self.belief_module.categorical_beliefs.get(&belief_id, state)?.is_none() {
+            return Err(SubmissionError::BeliefNotFound { belief_id }.into());
+        }
+        if self.agent_module.agents.get(sender, state)?.is_none() {
+            return Err(SubmissionError::AgentNotRegistered.into());
+        }
+
+        let key = (sender.clone(), belief_id);
+        if self.categorical_agent_submissions.get(&key, state)?.is_some() {
+            return Err(SubmissionError::AlreadySubmitted { belief_id }.into());
+        }
+
+        if self.weight_basis.get(state)?.unwrap_or_default() != WeightBasis::TotalStake {
+            bail!("Categorical beliefs only support the total stake weight basis");
+        }
+        let weight = self.agent_module.get_weight(sender, state)
+            .map_err(SubmissionError::from_agent)?;
+        let weight = self.apply_weight_cap(weight, state)?;
+
+        // Validates the distribution before anything is written
+        let aggregates = self.belief_module.update_categorical_aggregate(belief_id, &distribution, weight, state)?;
+        self.agent_module.start_withdrawal_cooldown(sender, state)?;
+
+        let submission = CategoricalSubmission {
+            agent: sender.clone(),
+            belief_id,
+            distribution,
+            weight,
+            timestamp: self.belief_module.block_timestamp(state)?,
+        };
+        let position = self.categorical_submissions.len(state)?;
+        self.categorical_submissions.push(&submission, state)?;
+        self.categorical_agent_submissions.set(&key, &position, state)?;
+        self.count_submissions(1, state)?;
+
+        Ok(aggregates)
+    }
+}
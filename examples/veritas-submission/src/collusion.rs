@@ -0,0 +1,125 @@
+//! Collusion detection - flags agents that keep submitting in lockstep
+//!
+//! FILE PURPOSE:
+//! A ring of agents can push an aggregate by submitting nearly identical
+//! values to the same beliefs in the same blocks. This heuristic spots such
+//! clusters and reports the agents for admin review. It never punishes
+//! anyone or rejects a submission.
+//!
+//! HOW IT WORKS:
+//! - Opt-in via `CollusionDetectionConfig` (disabled by default)
+//! - Each belief keeps a window of the current block's submissions only;
+//!   the first submission in a new block replaces the window
+//! - A cluster is every agent in the window within `value_tolerance` of the
+//!   new value; once it has `min_cluster_size` agents, each member earns
+//!   one strike per (belief, block)
+//! - When a member reaches `min_repeats` strikes, `Event::CollusionSuspected`
+//!   is emitted with the whole cluster
+//!
+//! Runs after the submission is recorded and its failures are only logged,
+//! so it stays off the critical path.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{EventEmitter, Spec, TxState};
+use veritas_belief::BeliefId;
+
+use crate::{Event, SubmissionModule};
+
+/// Settings for the collusion heuristic
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct CollusionDetectionConfig {
+    /// Whether submissions are clustered at all
+    pub enabled: bool,
+
+    /// Largest value distance (SCALE units) still counted as "nearly identical"
+    pub value_tolerance: u64,
+
+    /// Agents needed in one (belief, block) for a cluster to count
+    pub min_cluster_size: u64,
+
+    /// Strikes after which an agent is reported for review
+    pub min_repeats: u64,
+}
+
+impl Default for CollusionDetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            value_tolerance: 50,
+            min_cluster_size: 3,
+            min_repeats: 3,
+        }
+    }
+}
+
+/// Submissions a belief received in a single block
+#[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+pub struct BlockSubmissions<S: Spec> {
+    pub block_height: u64,
+
+    /// (agent, value) in submission order
+    pub entries: Vec<(S::Address, u64)>,
+
+    /// Agents that already earned their strike for this (belief, block)
+    pub struck: Vec<S::Address>,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Adds a submission to its belief's block window and strikes any cluster it completes
+    ///
+    /// No-op while detection is disabled.
+    pub fn detect_collusion(
+        &mut self,
+        agent: &S::Address,
+        belief_id: BeliefId,
+        value: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let config = self.collusion_detection.get(state)?.unwrap_or_default();
+        if !config.enabled {
+            return Ok(());
+        }
+
+        let block_height = self.belief_module.current_block(state)?;
+        let mut window = match self.block_submissions.get(&belief_id, state)? {
+            Some(window) if window.block_height == block_height => window,
+            _ => BlockSubmissions { block_height, entries: Vec::new(), struck: Vec::new() },
+        };
+        window.entries.push((agent.clone(), value));
+
+        let mut cluster: Vec<S::Address> = Vec::new();
+        for (member, member_value) in &window.entries {
+            if member_value.abs_diff(value) <= config.value_tolerance && !cluster.contains(member) {
+                cluster.push(member.clone());
+            }
+        }
+
+        if cluster.len() as u64 >= config.min_cluster_size {
+            let mut newly_suspected = false;
+            for member in &cluster {
+                if window.struck.contains(member) {
+                    continue;
+                }
+                window.struck.push(member.clone());
+
+                let strikes = self.collusion_strikes.get(member, state)?.unwrap_or(0) + 1;
+                self.collusion_strikes.set(member, &strikes, state)?;
+                newly_suspected |= strikes == config.min_repeats;
+            }
+
+            if newly_suspected {
+                self.emit_event(
+                    state,
+                    Event::CollusionSuspected { belief_id, block_height, value, agents: cluster },
+                );
+            }
+        }
+
+        self.block_submissions.set(&belief_id, &window, state)?;
+
+        Ok(())
+    }
+}
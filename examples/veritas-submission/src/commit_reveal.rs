@@ -0,0 +1,178 @@
+//! Commit-reveal submissions - hiding values until everyone has committed
+//!
+//! FILE PURPOSE:
+//! Submissions are public as soon as they land, so a late agent can simply
+//! copy the current aggregate and collect an accuracy bonus it didn't earn.
+//! For beliefs where that matters, governance can switch on a commit-reveal
+//! flow with `enable_commit_reveal`.
+//!
+//! HOW IT WORKS:
+//! - Until `commit_deadline`, agents send `CommitSubmission` with
+//!   `submission_commitment(value, nonce)`; committing again replaces the commitment
+//! - After `commit_deadline` and until `reveal_deadline` (both inclusive), agents
+//!   send `RevealSubmission` with the value and nonce. A matching reveal goes
+//!   through `submit_belief` like any submission: same checks, weight, fee and
+//!   scoring. Reveals before the commit phase ends would leak values, so they
+//!   are rejected too
+//! - Plain `SubmitBelief` / `SubmitBeliefAtRisk` are rejected for such beliefs
+//! - Commit-reveal has to be enabled before the belief's first submission
+//!
+//! COMMITMENT FORMAT:
+//! The rollup hasher (`S::CryptoSpec::Hasher`) over the value as 8 big-endian
+//! bytes followed by the 32-byte nonce. The nonce must be random and secret:
+//! with only SCALE + 1 possible values, a guessable nonce reveals the value.
+//!
+//! LIMITATIONS:
+//! Reveals carry no stake at risk, so they fail under the StakeAtRisk weight
+//! basis. An unrevealed commitment simply never counts.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::digest::Digest;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+use sov_modules_api::{Context, CryptoSpec, Spec, TxState};
+use veritas_belief::{BeliefError, BeliefId, BeliefStatus};
+
+use crate::{SubmissionError, SubmissionModule};
+
+/// Block heights bounding a commit-reveal belief's two phases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub struct CommitRevealWindow {
+    /// Last block accepting commitments
+    pub commit_deadline: u64,
+
+    /// Last block accepting reveals
+    pub reveal_deadline: u64,
+}
+
+/// The commitment an agent publishes for `value`, to be revealed with the same `nonce`
+pub fn submission_commitment<S: Spec>(value: u64, nonce: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = <S::CryptoSpec as CryptoSpec>::Hasher::new();
+    hasher.update(value.to_be_bytes());
+    hasher.update(nonce);
+    hasher.finalize().into()
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Makes a belief take its submissions through commit-reveal (admin only)
+    ///
+    /// The belief must be open and without submissions, and the commit phase
+    /// must not be over already.
+    pub fn enable_commit_reveal(
+        &mut self,
+        belief_id: BeliefId,
+        window: CommitRevealWindow,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+
+        let belief = self.belief_module.beliefs.get(&belief_id, state)?
+            .ok_or(BeliefError::NotFound { belief_id })?;
+        if !BeliefStatus::Open.matches(&belief) {
+            bail!("Belief {} is closed", belief_id);
+        }
+        if self.belief_module.submission_counts.get(&belief_id, state)?.unwrap_or(0) > 0 {
+            bail!("Belief {} already has submissions", belief_id);
+        }
+        if window.reveal_deadline <= window.commit_deadline {
+            bail!("The reveal deadline must come after the commit deadline");
+        }
+        if self.belief_module.current_block(state)? > window.commit_deadline {
+            bail!("The commit deadline has already passed");
+        }
+
+        self.commit_reveal_windows.set(&belief_id, &window, state)?;
+        Ok(())
+    }
+
+    /// Fails for beliefs that only take values through RevealSubmission
+    pub(crate) fn ensure_direct_submissions(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if self.commit_reveal_windows.get(&belief_id, state)?.is_some() {
+            return Err(SubmissionError::CommitRevealRequired { belief_id }.into());
+        }
+        Ok(())
+    }
+
+    /// Records the sender's commitment to a value for `belief_id`
+    pub fn commit_submission(
+        &mut self,
+        belief_id: BeliefId,
+        commitment: [u8; 32],
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if self.paused.get(state)?.unwrap_or(false) {
+            return Err(SubmissionError::Paused.into());
+        }
+
+        let sender = context.sender();
+        self.ensure_can_submit(belief_id, sender, state)?;
+        let window = self.commit_reveal_window(belief_id, state)?;
+        if self.belief_module.current_block(state)? > window.commit_deadline {
+            return Err(SubmissionError::CommitPhaseOver {
+                belief_id,
+                commit_deadline: window.commit_deadline,
+            }.into());
+        }
+
+        self.commitments.set(&(sender.clone(), belief_id), &commitment, state)?;
+        Ok(())
+    }
+
+    /// Submits `value` if it matches the sender's commitment
+    pub fn reveal_submission(
+        &mut self,
+        belief_id: BeliefId,
+        value: u64,
+        nonce: [u8; 32],
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let window = self.commit_reveal_window(belief_id, state)?;
+        let height = self.belief_module.current_block(state)?;
+        if height <= window.commit_deadline {
+            return Err(SubmissionError::RevealNotOpen {
+                belief_id,
+                commit_deadline: window.commit_deadline,
+            }.into());
+        }
+        if height > window.reveal_deadline {
+            return Err(SubmissionError::RevealClosed {
+                belief_id,
+                reveal_deadline: window.reveal_deadline,
+            }.into());
+        }
+
+        let key = (context.sender().clone(), belief_id);
+        let Some(commitment) = self.commitments.get(&key, state)? else {
+            return Err(SubmissionError::NoCommitment { belief_id }.into());
+        };
+        if submission_commitment::<S>(value, &nonce) != commitment {
+            return Err(SubmissionError::CommitmentMismatch { belief_id }.into());
+        }
+
+        self.submit_belief(belief_id, value, None, context, state)?;
+        self.commitments.delete(&key, state)?;
+        Ok(())
+    }
+
+    /// The belief's commit-reveal window, failing for beliefs without one
+    fn commit_reveal_window(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<CommitRevealWindow> {
+        if self.belief_module.beliefs.get(&belief_id, state)?.is_none() {
+            return Err(SubmissionError::BeliefNotFound { belief_id }.into());
+        }
+        self.commit_reveal_windows.get(&belief_id, state)?
+            .ok_or_else(|| SubmissionError::CommitRevealNotEnabled { belief_id }.into())
+    }
+}
@@ -0,0 +1,69 @@
+//! Deployment config - the genesis settings clients need to mirror
+//!
+//! FILE PURPOSE:
+//! Wallets and agents previewing weights or score bonuses have to compute
+//! them exactly like the chain does, which depends on settings chosen at
+//! genesis and spread over all three modules. `get_config` gathers them from
+//! state into one read-only struct. Also served as `GET /config` (see http.rs).
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+use veritas_agent::{InitialScorePolicy, StakeWeighting, DEFAULT_MAX_SCORE};
+use veritas_belief::AggregationMode;
+
+use crate::{ScoringParams, SubmissionModule, WeightBasis, WeightCapConfig};
+
+/// Weighting and scoring settings of a Veritas deployment
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct VeritasConfig {
+    /// How stake counts towards weight (AgentModule)
+    pub stake_weighting: StakeWeighting,
+
+    /// Whether weight is stake alone, ignoring scores (AgentModule)
+    pub ignore_score: bool,
+
+    /// Whether weights come from total stake or stake at risk
+    pub weight_basis: WeightBasis,
+
+    /// Cap on a single submission's share of total registered weight
+    pub weight_cap: WeightCapConfig,
+
+    /// Weighted mean or weighted median aggregates for new beliefs (BeliefModule)
+    pub aggregation_mode: AggregationMode,
+
+    /// Score given to newly registering agents (AgentModule)
+    pub initial_score: InitialScorePolicy,
+
+    /// Score ceiling (AgentModule)
+    pub max_score: u64,
+
+    /// Smallest stake accepted at registration (AgentModule)
+    pub min_stake: u64,
+
+    /// Accuracy bonus size and curve applied on each submission
+    pub scoring: ScoringParams,
+
+    /// Blocks between two accuracy bonuses to the same agent (0 = no cooldown)
+    pub score_cooldown_blocks: u64,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Reads the active weighting and scoring settings of all three modules
+    ///
+    /// Unset values are reported as the defaults the modules fall back to.
+    pub fn get_config(&self, state: &mut impl TxState<S>) -> Result<VeritasConfig> {
+        Ok(VeritasConfig {
+            stake_weighting: self.agent_module.stake_weighting.get(state)?.unwrap_or_default(),
+            ignore_score: self.agent_module.ignore_score.get(state)?.unwrap_or(false),
+            weight_basis: self.weight_basis.get(state)?.unwrap_or_default(),
+            weight_cap: self.weight_cap.get(state)?.unwrap_or_default(),
+            aggregation_mode: self.belief_module.aggregation_mode.get(state)?.unwrap_or_default(),
+            initial_score: self.agent_module.initial_score.get(state)?.unwrap_or_default(),
+            max_score: self.agent_module.max_score.get(state)?.unwrap_or(DEFAULT_MAX_SCORE),
+            min_stake: self.agent_module.min_stake.get(state)?.unwrap_or(0),
+            scoring: self.scoring.get(state)?.unwrap_or_default(),
+            score_cooldown_blocks: self.score_cooldown_blocks.get(state)?.unwrap_or(0),
+        })
+    }
+}
@@ -0,0 +1,232 @@
+//! Typed errors returned by SubmissionModule
+//!
+//! Errors are still surfaced as `anyhow::Error` (the Module trait requires it),
+//! but clients and tests can recover the exact kind with
+//! `err.downcast_ref::<SubmissionError>()` instead of matching on strings.
+//!
+//! VeritasError:
+//! Each module has its own error type (BeliefError, AgentError, SubmissionError),
+//! and a submission can fail with any of them. `VeritasError::from_anyhow` finds
+//! whichever one an error carries, so a client can branch on every Veritas
+//! failure with a single match.
+
+use std::fmt;
+
+use veritas_agent::AgentError;
+use veritas_belief::{AgentTier, BeliefError, BeliefId, SCALE};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionError {
+    /// The target belief doesn't exist
+    BeliefNotFound { belief_id: BeliefId },
+
+    /// The sender never registered with AgentModule
+    AgentNotRegistered,
+
+    /// The sender is registered but has no stake, so its weight is 0
+    AgentZeroStake,
+
+    /// The sender is registered but has a score of 0, so its weight is 0
+    AgentZeroScore,
+
+    /// The submission's weight exceeds the configured share of total registered weight
+    WeightAboveCap { weight: u64, cap: u64 },
+
+    /// The belief requires a higher agent tier than the sender's
+    TierTooLow { required: AgentTier, actual: AgentTier },
+
+    /// Submissions are paused until the admin unpauses them
+    Paused,
+
+    /// The current block is past the belief's deadline
+    BeliefClosed { belief_id: BeliefId, deadline_block: u64 },
+
+    /// The sender has no current submission to the belief to retract
+    NothingToRetract { belief_id: BeliefId },
+
+    /// The agent already has a submission to this belief and can't add another
+    /// (categorical beliefs, imported records)
+    AlreadySubmitted { belief_id: BeliefId },
+
+    /// The sender already joined as many beliefs as one agent may
+    BeliefLimitReached { limit: u64 },
+
+    /// A submitted value is above SCALE
+    ValueOutOfRange { value: u64 },
+
+    /// The sender already made as many submissions in this block as one agent may
+    BlockLimitReached { limit: u64 },
+
+    /// The belief takes submissions through CommitSubmission / RevealSubmission only
+    CommitRevealRequired { belief_id: BeliefId },
+
+    /// The belief doesn't take commit-reveal submissions
+    CommitRevealNotEnabled { belief_id: BeliefId },
+
+    /// The belief's commit phase ended at `commit_deadline`
+    CommitPhaseOver { belief_id: BeliefId, commit_deadline: u64 },
+
+    /// Reveals open once the commit phase ending at `commit_deadline` is over
+    RevealNotOpen { belief_id: BeliefId, commit_deadline: u64 },
+
+    /// The belief's reveal phase ended at `reveal_deadline`
+    RevealClosed { belief_id: BeliefId, reveal_deadline: u64 },
+
+    /// The sender has no commitment to reveal for the belief
+    NoCommitment { belief_id: BeliefId },
+
+    /// The revealed value and nonce don't hash to the sender's commitment
+    CommitmentMismatch { belief_id: BeliefId },
+
+    /// The belief's fees, rewards or scores were already settled, which a rollback can't undo
+    AlreadySettled { belief_id: BeliefId },
+
+    /// A submission the snapshot at `height` counts was overwritten or retracted since
+    ChangedSinceSnapshot { belief_id: BeliefId, height: u64 },
+}
+
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionError::BeliefNotFound { belief_id } => {
+                write!(f, "Belief not found: {}", belief_id)
+            }
+            SubmissionError::AgentNotRegistered => write!(f, "Agent not registered"),
+            SubmissionError::AgentZeroStake => write!(f, "Agent has no stake, so its weight is 0"),
+            SubmissionError::AgentZeroScore => write!(f, "Agent has a score of 0, so its weight is 0"),
+            SubmissionError::WeightAboveCap { weight, cap } => {
+                write!(f, "Submission weight {} exceeds the cap of {}", weight, cap)
+            }
+            SubmissionError::TierTooLow { required, actual } => {
+                write!(f, "Belief requires tier {:?}, agent is {:?}", required, actual)
+            }
+            SubmissionError::Paused => write!(f, "Submissions are paused"),
+            SubmissionError::BeliefClosed { belief_id, deadline_block } => {
+                write!(f, "Belief {} closed for submissions after block {}", belief_id, deadline_block)
+            }
+            SubmissionError::NothingToRetract { belief_id } => {
+                write!(f, "No submission to retract for belief {}", belief_id)
+            }
+            SubmissionError::AlreadySubmitted { belief_id } => {
+                write!(f, "Already submitted to belief {}", belief_id)
+            }
+            SubmissionError::BeliefLimitReached { limit } => {
+                write!(f, "Agent already submitted to the maximum of {} beliefs", limit)
+            }
+            SubmissionError::ValueOutOfRange { .. } => write!(f, "Value must be between 0 and {}", SCALE),
+            SubmissionError::BlockLimitReached { limit } => {
+                write!(f, "Agent already made the maximum of {} submissions in this block", limit)
+            }
+            SubmissionError::CommitRevealRequired { belief_id } => {
+                write!(f, "Belief {} only takes commit-reveal submissions", belief_id)
+            }
+            SubmissionError::CommitRevealNotEnabled { belief_id } => {
+                write!(f, "Belief {} doesn't take commit-reveal submissions", belief_id)
+            }
+            SubmissionError::CommitPhaseOver { belief_id, commit_deadline } => {
+                write!(f, "Belief {} stopped taking commitments after block {}", belief_id, commit_deadline)
+            }
+            SubmissionError::RevealNotOpen { belief_id, commit_deadline } => {
+                write!(f, "Reveals for belief {} open after block {}", belief_id, commit_deadline)
+            }
+            SubmissionError::RevealClosed { belief_id, reveal_deadline } => {
+                write!(f, "Belief {} stopped taking reveals after block {}", belief_id, reveal_deadline)
+            }
+            SubmissionError::NoCommitment { belief_id } => {
+                write!(f, "No commitment to reveal for belief {}", belief_id)
+            }
+            SubmissionError::CommitmentMismatch { belief_id } => {
+                write!(f, "Revealed value doesn't match the commitment for belief {}", belief_id)
+            }
+            SubmissionError::AlreadySettled { belief_id } => {
+                write!(f, "Belief {} was already settled and can't be rolled back", belief_id)
+            }
+            SubmissionError::ChangedSinceSnapshot { belief_id, height } => {
+                write!(f, "Belief {}'s earlier submissions changed since its snapshot at block {}", belief_id, height)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmissionError {}
+
+/// Only the weight-related AgentErrors have a submission counterpart
+impl TryFrom<AgentError> for SubmissionError {
+    type Error = AgentError;
+
+    fn try_from(error: AgentError) -> Result<Self, Self::Error> {
+        match error {
+            AgentError::NotRegistered => Ok(SubmissionError::AgentNotRegistered),
+            AgentError::ZeroStake => Ok(SubmissionError::AgentZeroStake),
+            AgentError::ZeroScore => Ok(SubmissionError::AgentZeroScore),
+            AgentError::StakeOverflow | AgentError::InsufficientStake { .. } => Err(error),
+        }
+    }
+}
+
+impl SubmissionError {
+    /// Re-types an `AgentError` inside `error` as a SubmissionError, passing others through
+    pub fn from_agent(error: anyhow::Error) -> anyhow::Error {
+        match error.downcast::<AgentError>() {
+            Ok(agent_error) => match SubmissionError::try_from(agent_error) {
+                Ok(submission_error) => submission_error.into(),
+                Err(agent_error) => agent_error.into(),
+            },
+            Err(error) => error,
+        }
+    }
+}
+
+/// A typed error from any Veritas module
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VeritasError {
+    Belief(BeliefError),
+    Agent(AgentError),
+    Submission(SubmissionError),
+}
+
+impl VeritasError {
+    /// The typed Veritas error `error` carries, or None for untyped (string) errors
+    pub fn from_anyhow(error: &anyhow::Error) -> Option<Self> {
+        if let Some(error) = error.downcast_ref::<VeritasError>() {
+            return Some(error.clone());
+        }
+        if let Some(error) = error.downcast_ref::<SubmissionError>() {
+            return Some(VeritasError::Submission(error.clone()));
+        }
+        if let Some(error) = error.downcast_ref::<AgentError>() {
+            return Some(VeritasError::Agent(*error));
+        }
+        error.downcast_ref::<BeliefError>().map(|error| VeritasError::Belief(*error))
+    }
+}
+
+impl fmt::Display for VeritasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VeritasError::Belief(error) => error.fmt(f),
+            VeritasError::Agent(error) => error.fmt(f),
+            VeritasError::Submission(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for VeritasError {}
+
+impl From<BeliefError> for VeritasError {
+    fn from(error: BeliefError) -> Self {
+        VeritasError::Belief(error)
+    }
+}
+
+impl From<AgentError> for VeritasError {
+    fn from(error: AgentError) -> Self {
+        VeritasError::Agent(error)
+    }
+}
+
+impl From<SubmissionError> for VeritasError {
+    fn from(error: SubmissionError) -> Self {
+        VeritasError::Submission(error)
+    }
+}
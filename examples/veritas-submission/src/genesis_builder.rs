@@ -0,0 +1,386 @@
+//! VeritasGenesisBuilder - fluent construction of the three Veritas genesis configs
+//!
+//! FILE PURPOSE:
+//! Building `GenesisConfig` for AgentModule, BeliefModule and SubmissionModule by hand
+//! in tests is verbose and easy to get subtly wrong (mismatched belief IDs,
+//! forgotten admin, serde bounds on the address type). This builder keeps the
+//! three configs consistent with each other.
+//!
+//! Mirrors the ergonomics of sov-test-utils' `HighLevelOptimisticGenesisConfig`:
+//!
+//! ```ignore
+//! let veritas = VeritasGenesisBuilder::<S>::new()
+//!     .with_admin(admin.address())
+//!     .with_agent(alice.address(), 1000, 100)
+//!     .with_belief("Will ETH exceed $5000?", 5000)
+//!     .build();
+//! let genesis = GenesisConfig::from_minimal_config(
+//!     minimal.into(), veritas.agent, veritas.belief, veritas.submission,
+//! );
+//! ```
+//!
+//! Native-only: it is a testing/tooling helper, never part of the zk guest.
+
+use sov_modules_api::Spec;
+use veritas_agent::{Agent, InitialScorePolicy, StakeLockConfig, StakeWeighting, TierThresholds};
+use veritas_belief::{
+    AggregateDecay, AggregationMode, Belief, BeliefId, BeliefOptions, ResolutionQuorum, RoundingMode,
+    SnapshotConfig,
+};
+
+use crate::{
+    CollusionDetectionConfig, FeeConfig, IntegritySweepConfig, ScoringParams, SlashingConfig, Submission, WeightBasis,
+    WeightCapConfig,
+};
+
+/// The three module configs produced by `VeritasGenesisBuilder::build`
+pub struct VeritasGenesisConfig<S>
+where
+    S: Spec,
+    S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
+    pub agent: veritas_agent::GenesisConfig<S>,
+    pub belief: veritas_belief::GenesisConfig<S>,
+    pub submission: crate::GenesisConfig<S>,
+}
+
+/// Fluent builder for consistent Veritas genesis state
+pub struct VeritasGenesisBuilder<S>
+where
+    S: Spec,
+    S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
+    admin: Option<S::Address>,
+    agents: Vec<(S::Address, Agent)>,
+    beliefs: Vec<Belief>,
+    submissions: Vec<Submission<S>>,
+    default_resolution_quorum: ResolutionQuorum,
+    integrity_sweep: IntegritySweepConfig,
+    stake_lock: StakeLockConfig,
+    initial_score: InitialScorePolicy,
+    weight_cap: WeightCapConfig,
+    weight_basis: WeightBasis,
+    aggregate_decay: AggregateDecay,
+    snapshots: SnapshotConfig,
+    aggregation_mode: AggregationMode,
+    rounding_mode: RoundingMode,
+    aggregate_history: bool,
+    recent_aggregates: u64,
+    max_question_len: u64,
+    fee: FeeConfig,
+    collusion_detection: CollusionDetectionConfig,
+    slashing: SlashingConfig,
+    scoring: ScoringParams,
+    max_beliefs_per_agent: u64,
+    max_submissions_per_block: u64,
+    score_cooldown_blocks: u64,
+    tier_thresholds: TierThresholds,
+    stake_weighting: StakeWeighting,
+    ignore_score: bool,
+    withdrawal_cooldown: u64,
+    max_score: u64,
+    min_stake: u64,
+}
+
+impl<S> Default for VeritasGenesisBuilder<S>
+where
+    S: Spec,
+    S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
+    fn default() -> Self {
+        Self {
+            admin: None,
+            agents: Vec::new(),
+            beliefs: Vec::new(),
+            submissions: Vec::new(),
+            default_resolution_quorum: ResolutionQuorum::default(),
+            integrity_sweep: IntegritySweepConfig::default(),
+            stake_lock: StakeLockConfig::default(),
+            initial_score: InitialScorePolicy::default(),
+            weight_cap: WeightCapConfig::default(),
+            weight_basis: WeightBasis::default(),
+            aggregate_decay: AggregateDecay::default(),
+            snapshots: SnapshotConfig::default(),
+            aggregation_mode: AggregationMode::default(),
+            rounding_mode: RoundingMode::default(),
+            aggregate_history: false,
+            recent_aggregates: 0,
+            max_question_len: veritas_belief::DEFAULT_MAX_QUESTION_LEN,
+            fee: FeeConfig::default(),
+            collusion_detection: CollusionDetectionConfig::default(),
+            slashing: SlashingConfig::default(),
+            scoring: ScoringParams::default(),
+            max_beliefs_per_agent: 0,
+            max_submissions_per_block: 0,
+            score_cooldown_blocks: 0,
+            tier_thresholds: TierThresholds::default(),
+            stake_weighting: StakeWeighting::default(),
+            ignore_score: false,
+            withdrawal_cooldown: 0,
+            max_score: veritas_agent::DEFAULT_MAX_SCORE,
+            min_stake: 0,
+        }
+    }
+}
+
+impl<S> VeritasGenesisBuilder<S>
+where
+    S: Spec,
+    S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the governance admin (BeliefModule admin actions)
+    pub fn with_admin(mut self, admin: S::Address) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+
+    /// Adds a pre-registered agent with the given stake and score
+    pub fn with_agent(mut self, address: S::Address, stake: u64, score: u64) -> Self {
+        self.agents.push((address, Agent::new(stake, score)));
+        self
+    }
+
+    /// Adds an open belief; IDs are assigned sequentially starting at 1
+    pub fn with_belief(mut self, question: impl Into<String>, initial_value: u64) -> Self {
+        let id = self.next_belief_id();
+        self.beliefs.push(Belief::new(id, question.into(), initial_value));
+        self
+    }
+
+    /// Adds an open belief with per-belief settings (deadline, tier, weighting, ...)
+    pub fn with_belief_options(
+        mut self,
+        question: impl Into<String>,
+        initial_value: u64,
+        options: BeliefOptions,
+    ) -> Self {
+        let id = self.next_belief_id();
+        self.beliefs.push(Belief::with_options(id, question.into(), initial_value, options));
+        self
+    }
+
+    /// Adds a submission to the genesis log
+    ///
+    /// Genesis counts it and its weight into the belief; the belief's aggregate
+    /// stays as configured.
+    pub fn with_submission(mut self, agent: S::Address, belief_id: BeliefId, value: u64, weight: u64) -> Self {
+        self.submissions.push(Submission {
+            agent,
+            belief_id,
+            value,
+            weight,
+            timestamp: 0,
+            block_height: 0,
+            fee: 0,
+            retracted: false,
+        });
+        self
+    }
+
+    /// Sets the quorum every belief must reach before resolution
+    pub fn with_default_resolution_quorum(mut self, quorum: ResolutionQuorum) -> Self {
+        self.default_resolution_quorum = quorum;
+        self
+    }
+
+    /// Enables/configures the end-of-block integrity sweep
+    pub fn with_integrity_sweep(mut self, config: IntegritySweepConfig) -> Self {
+        self.integrity_sweep = config;
+        self
+    }
+
+    /// Enables/configures stake locks on participation
+    pub fn with_stake_lock(mut self, config: StakeLockConfig) -> Self {
+        self.stake_lock = config;
+        self
+    }
+
+    /// Sets how newly registering agents' initial score is derived from their stake
+    pub fn with_initial_score(mut self, policy: InitialScorePolicy) -> Self {
+        self.initial_score = policy;
+        self
+    }
+
+    /// Enables/configures the per-submission weight cap
+    pub fn with_weight_cap(mut self, config: WeightCapConfig) -> Self {
+        self.weight_cap = config;
+        self
+    }
+
+    /// Sets whether submission weights come from total stake or stake at risk
+    pub fn with_weight_basis(mut self, basis: WeightBasis) -> Self {
+        self.weight_basis = basis;
+        self
+    }
+
+    /// Sets how the weight behind aggregates decays between submissions
+    pub fn with_aggregate_decay(mut self, decay: AggregateDecay) -> Self {
+        self.aggregate_decay = decay;
+        self
+    }
+
+    /// Enables/configures belief snapshots for admin rollbacks
+    pub fn with_belief_snapshots(mut self, config: SnapshotConfig) -> Self {
+        self.snapshots = config;
+        self
+    }
+
+    /// Chooses weighted mean or weighted median aggregates for every belief
+    pub fn with_aggregation_mode(mut self, mode: AggregationMode) -> Self {
+        self.aggregation_mode = mode;
+        self
+    }
+
+    /// Chooses truncating or round-half-up running means for every belief
+    pub fn with_rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding_mode = mode;
+        self
+    }
+
+    /// Records open beliefs' aggregates after every block, for charts
+    pub fn with_aggregate_history(mut self, enabled: bool) -> Self {
+        self.aggregate_history = enabled;
+        self
+    }
+
+    /// Keeps each belief's last `capacity` aggregates for sparklines (0 keeps none)
+    pub fn with_recent_aggregates(mut self, capacity: u64) -> Self {
+        self.recent_aggregates = capacity;
+        self
+    }
+
+    /// Caps belief questions at `max_question_len` bytes
+    pub fn with_max_question_len(mut self, max_question_len: u64) -> Self {
+        self.max_question_len = max_question_len;
+        self
+    }
+
+    /// Sets the submission fee, its accuracy rebate and where fees go
+    pub fn with_fee(mut self, config: FeeConfig) -> Self {
+        self.fee = config;
+        self
+    }
+
+    /// Enables/configures the collusion review heuristic
+    pub fn with_collusion_detection(mut self, config: CollusionDetectionConfig) -> Self {
+        self.collusion_detection = config;
+        self
+    }
+
+    /// Enables/configures stake slashing at settlement
+    pub fn with_slashing(mut self, config: SlashingConfig) -> Self {
+        self.slashing = config;
+        self
+    }
+
+    /// Sets the accuracy bonus size and curve
+    pub fn with_scoring(mut self, params: ScoringParams) -> Self {
+        self.scoring = params;
+        self
+    }
+
+    /// Caps how many beliefs a single agent may submit to (0 = unlimited)
+    pub fn with_max_beliefs_per_agent(mut self, limit: u64) -> Self {
+        self.max_beliefs_per_agent = limit;
+        self
+    }
+
+    /// Caps how many submissions a single agent may make per block (0 = unlimited)
+    pub fn with_max_submissions_per_block(mut self, limit: u64) -> Self {
+        self.max_submissions_per_block = limit;
+        self
+    }
+
+    /// Sets how many blocks must pass between two accuracy bonuses to the same agent (0 = none)
+    pub fn with_score_cooldown_blocks(mut self, blocks: u64) -> Self {
+        self.score_cooldown_blocks = blocks;
+        self
+    }
+
+    /// Sets the score thresholds for automatic agent tiers
+    pub fn with_tier_thresholds(mut self, thresholds: TierThresholds) -> Self {
+        self.tier_thresholds = thresholds;
+        self
+    }
+
+    /// Sets how stake scales into agent weight (linear or square root)
+    pub fn with_stake_weighting(mut self, weighting: StakeWeighting) -> Self {
+        self.stake_weighting = weighting;
+        self
+    }
+
+    /// Weighs agents by stake alone, treating every score as 1
+    pub fn with_ignore_score(mut self, ignore_score: bool) -> Self {
+        self.ignore_score = ignore_score;
+        self
+    }
+
+    /// Sets how many blocks after a submission the agent can't withdraw stake
+    pub fn with_withdrawal_cooldown(mut self, blocks: u64) -> Self {
+        self.withdrawal_cooldown = blocks;
+        self
+    }
+
+    /// Sets the highest score an agent can reach
+    pub fn with_max_score(mut self, max_score: u64) -> Self {
+        self.max_score = max_score;
+        self
+    }
+
+    /// Sets the smallest stake a registered agent may hold
+    pub fn with_min_stake(mut self, min_stake: u64) -> Self {
+        self.min_stake = min_stake;
+        self
+    }
+
+    /// ID the next `with_belief` call will receive
+    pub fn next_belief_id(&self) -> BeliefId {
+        self.beliefs.len() as BeliefId + 1
+    }
+
+    /// Produces the three module configs
+    pub fn build(self) -> VeritasGenesisConfig<S> {
+        VeritasGenesisConfig {
+            agent: veritas_agent::GenesisConfig {
+                initial_agents: self.agents,
+                stake_lock: self.stake_lock,
+                initial_score: self.initial_score,
+                tier_thresholds: self.tier_thresholds,
+                stake_weighting: self.stake_weighting,
+                ignore_score: self.ignore_score,
+                withdrawal_cooldown: self.withdrawal_cooldown,
+                max_score: self.max_score,
+                min_stake: self.min_stake,
+            },
+            belief: veritas_belief::GenesisConfig {
+                initial_beliefs: self.beliefs,
+                admin: self.admin,
+                default_resolution_quorum: self.default_resolution_quorum,
+                aggregate_decay: self.aggregate_decay,
+                snapshots: self.snapshots,
+                aggregation_mode: self.aggregation_mode,
+                rounding_mode: self.rounding_mode,
+                aggregate_history: self.aggregate_history,
+                recent_aggregates: self.recent_aggregates,
+                max_question_len: self.max_question_len,
+            },
+            submission: crate::GenesisConfig {
+                initial_submissions: self.submissions,
+                integrity_sweep: self.integrity_sweep,
+                weight_cap: self.weight_cap,
+                weight_basis: self.weight_basis,
+                fee: self.fee,
+                collusion_detection: self.collusion_detection,
+                slashing: self.slashing,
+                scoring: self.scoring,
+                max_beliefs_per_agent: self.max_beliefs_per_agent,
+                max_submissions_per_block: self.max_submissions_per_block,
+                score_cooldown_blocks: self.score_cooldown_blocks,
+            },
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Custom REST endpoints for SubmissionModule
+//!
+//! Served next to the auto-generated state API (`ModuleRestApi`).
+//!
+//! - `GET /stats`: participation totals across the Veritas modules
+//!   (see `SubmissionModule::get_system_stats`)
+//! - `GET /config`: weighting and scoring settings chosen at genesis (see config.rs)
+//! - `GET /config/precision`: the fixed-point scale and its decimal places
+//! - `GET /preview?belief_id=&value=&agent=`: the aggregate before and after a
+//!   hypothetical submission (see preview.rs); 404 for unknown beliefs and
+//!   agents, 500 for anything the real submission would also reject
+//!
+//! Native-only: the zk guest never serves HTTP.
+
+use axum::extract::Query;
+use axum::routing::get;
+use sov_modules_api::prelude::utoipa::openapi::OpenApi;
+use sov_modules_api::rest::utils::{errors, ApiResult};
+use sov_modules_api::rest::{ApiState, HasCustomRestApi};
+use sov_modules_api::{ApiStateAccessor, Spec};
+use veritas_belief::{BeliefId, Precision};
+
+use crate::{AggregatePreview, SubmissionError, SubmissionModule, SystemStats, VeritasConfig};
+
+#[derive(serde::Deserialize)]
+#[serde(bound = "A: serde::de::DeserializeOwned")]
+struct PreviewParams<A> {
+    belief_id: BeliefId,
+    value: u64,
+    agent: A,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    async fn route_preview(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+        Query(params): Query<PreviewParams<S::Address>>,
+    ) -> ApiResult<AggregatePreview> {
+        let belief_id = params.belief_id;
+        let new_aggregate = state
+            .preview_aggregate(belief_id, params.value, &params.agent, &mut accessor)
+            .map_err(|e| match e.downcast_ref::<SubmissionError>() {
+                Some(SubmissionError::BeliefNotFound { .. }) => errors::not_found_404("Belief", belief_id),
+                Some(SubmissionError::AgentNotRegistered) => errors::not_found_404("Agent", &params.agent),
+                _ => errors::internal_server_error_response_500(e.to_string()),
+            })?;
+        // preview_aggregate found the belief, so it is still there
+        let current_aggregate = state
+            .belief_module
+            .beliefs
+            .get(&belief_id, &mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?
+            .map_or(0, |belief| belief.aggregate);
+
+        Ok(AggregatePreview { belief_id, current_aggregate, new_aggregate }.into())
+    }
+
+    async fn route_stats(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+    ) -> ApiResult<SystemStats> {
+        let stats = state
+            .get_system_stats(&mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?;
+
+        Ok(stats.into())
+    }
+
+    async fn route_config(
+        state: ApiState<S, Self>,
+        mut accessor: ApiStateAccessor<S>,
+    ) -> ApiResult<VeritasConfig> {
+        let config = state
+            .get_config(&mut accessor)
+            .map_err(|e| errors::internal_server_error_response_500(e.to_string()))?;
+
+        Ok(config.into())
+    }
+
+    async fn route_precision() -> ApiResult<Precision> {
+        Ok(Precision::current().into())
+    }
+}
+
+impl<S: Spec> HasCustomRestApi for SubmissionModule<S> {
+    type Spec = S;
+
+    fn custom_rest_api(&self, state: ApiState<S>) -> axum::Router<()> {
+        axum::Router::new()
+            .route("/stats", get(Self::route_stats))
+            .route("/config", get(Self::route_config))
+            .route("/config/precision", get(Self::route_precision))
+            .route("/preview", get(Self::route_preview))
+            .with_state(state.with(self.clone()))
+    }
+
+    fn custom_openapi_spec(&self) -> Option<OpenApi> {
+        None
+    }
+}
@@ -0,0 +1,274 @@
+//! Integrity sweep - end-of-block consistency checks across Veritas modules
+//!
+//! FILE PURPOSE:
+//! Catches accounting drift between BeliefModule and SubmissionModule in production.
+//! BeliefModule folds submissions into `total_weight` and `submission_counts` incrementally,
+//! while SubmissionModule keeps the raw submission log. If the two ever disagree
+//! (bug, bad migration, manual state surgery) we want to know about it.
+//!
+//! HOW IT WORKS:
+//! - Runs from `end_rollup_block_hook` when enabled in genesis
+//! - Each block inspects at most `beliefs_per_block` beliefs, rotating a cursor
+//!   through all belief IDs so every belief is eventually checked
+//! - For each inspected belief it verifies:
+//!   1. `total_weight` == sum of the weights of its recorded submissions
+//!   2. `submission_count` == number of entries in its submission index
+//!   3. `aggregate` <= SCALE
+//! - Mismatches are logged, recorded in `integrity_violations` and reported
+//!   on-chain with one `Event::IntegrityViolationDetected` each
+//!
+//! This is a safety net, NOT consensus-critical: it never mutates beliefs
+//! or submissions and never rejects anything itself.
+//!
+//! AUTO-PAUSE:
+//! With `auto_pause` set, a sweep that finds any violation also sets the
+//! module's `paused` flag and emits `Event::SubmissionsAutoPaused`, so no new
+//! submission can compound the corruption. Only the admin can unpause
+//! (`SetPaused`), ideally after repairing the belief.
+//!
+//! REPAIR:
+//! Fixing a drifted belief is a separate, explicit admin action: `repair_belief`
+//! (sent as `RepairBelief`) replays the belief's recorded submissions through
+//! BeliefModule's aggregation math, overwrites its totals, clears its recorded
+//! violation and emits `Event::BeliefRepaired`.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{Context, EventEmitter, Spec, TxState};
+use veritas_belief::{BeliefId, Contribution, SCALE};
+
+use crate::{Event, SubmissionModule};
+
+/// Settings for the end-of-block integrity sweep
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct IntegritySweepConfig {
+    /// Whether the sweep runs at all
+    pub enabled: bool,
+
+    /// Maximum number of beliefs inspected per block
+    /// Bounds the per-block cost regardless of how many beliefs exist
+    pub beliefs_per_block: u64,
+
+    /// Whether a detected violation pauses submissions
+    #[serde(default)]
+    pub auto_pause: bool,
+}
+
+impl Default for IntegritySweepConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            beliefs_per_block: 4,
+            auto_pause: false,
+        }
+    }
+}
+
+/// Describes a belief whose stored totals disagree with its recorded submissions
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct IntegrityViolation {
+    pub belief_id: BeliefId,
+
+    /// `total_weight` as stored on the belief
+    pub stored_total_weight: u128,
+
+    /// Sum of the weights of the belief's recorded submissions
+    pub recorded_total_weight: u128,
+
+    /// `submission_count` as stored in BeliefModule
+    pub stored_submission_count: u64,
+
+    /// Number of entries in the belief's submission index
+    pub indexed_submission_count: u64,
+
+    /// `aggregate` as stored on the belief (a violation if above SCALE)
+    #[serde(default)]
+    pub aggregate: u64,
+}
+
+/// A belief's weight and submission count, as compared by the sweep
+#[derive(Clone, Copy, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct IntegrityTotals {
+    pub total_weight: u128,
+    pub submission_count: u64,
+}
+
+impl IntegrityViolation {
+    /// The totals the belief's recorded submissions call for
+    pub fn expected(&self) -> IntegrityTotals {
+        IntegrityTotals {
+            total_weight: self.recorded_total_weight,
+            submission_count: self.indexed_submission_count,
+        }
+    }
+
+    /// The totals stored in BeliefModule
+    pub fn actual(&self) -> IntegrityTotals {
+        IntegrityTotals {
+            total_weight: self.stored_total_weight,
+            submission_count: self.stored_submission_count,
+        }
+    }
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Checks a single belief for accounting drift
+    ///
+    /// Returns:
+    /// - Ok(None) if the belief is consistent (or doesn't exist)
+    /// - Ok(Some(violation)) describing the mismatch otherwise
+    pub fn check_belief_integrity(
+        &self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<Option<IntegrityViolation>> {
+        // Belief IDs may have gaps, nothing to verify for a missing one
+        let Some(belief) = self.belief_module.beliefs.get(&belief_id, state)? else {
+            return Ok(None);
+        };
+
+        let stored_submission_count = self.belief_module.submission_counts.get(&belief_id, state)?
+            .unwrap_or(0);
+        let index = self.belief_submission_index.get(&belief_id, state)?
+            .unwrap_or_default();
+
+        let mut recorded_total_weight: u128 = 0;
+        for position in &index {
+            if let Some(submission) = self.submissions.get(*position, state)? {
+                recorded_total_weight += submission.weight as u128;
+            }
+        }
+
+        let indexed_submission_count = index.len() as u64;
+
+        if belief.total_weight == recorded_total_weight
+            && stored_submission_count == indexed_submission_count
+            && belief.aggregate <= SCALE
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(IntegrityViolation {
+            belief_id,
+            stored_total_weight: belief.total_weight,
+            recorded_total_weight,
+            stored_submission_count,
+            indexed_submission_count,
+            aggregate: belief.aggregate,
+        }))
+    }
+
+    /// Inspects the next `beliefs_per_block` beliefs and records any violations
+    ///
+    /// Called from `end_rollup_block_hook`; exposed publicly so operators and
+    /// tests can drive the sweep without producing blocks.
+    ///
+    /// Returns the violations found during this run.
+    pub fn run_integrity_sweep(
+        &mut self,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<IntegrityViolation>> {
+        let config = self.integrity_sweep.get(state)?.unwrap_or_default();
+        if !config.enabled || config.beliefs_per_block == 0 {
+            return Ok(Vec::new());
+        }
+
+        // Belief IDs are allocated sequentially starting at 1
+        let next_belief_id = self.belief_module.next_belief_id.get(state)?.unwrap_or(1);
+        let belief_count = next_belief_id.saturating_sub(1);
+        if belief_count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut cursor = self.integrity_cursor.get(state)?.unwrap_or(1);
+        let mut violations = Vec::new();
+
+        // Never inspect the same belief twice in one block
+        for _ in 0..config.beliefs_per_block.min(belief_count) {
+            if cursor == 0 || cursor >= next_belief_id {
+                cursor = 1;
+            }
+
+            if let Some(violation) = self.check_belief_integrity(cursor, state)? {
+                tracing::warn!(
+                    belief_id = violation.belief_id,
+                    stored_total_weight = violation.stored_total_weight,
+                    recorded_total_weight = violation.recorded_total_weight,
+                    stored_submission_count = violation.stored_submission_count,
+                    indexed_submission_count = violation.indexed_submission_count,
+                    aggregate = violation.aggregate,
+                    "Veritas integrity violation detected"
+                );
+                self.integrity_violations.set(&cursor, &violation, state)?;
+                self.emit_event(
+                    state,
+                    Event::IntegrityViolationDetected {
+                        belief_id: violation.belief_id,
+                        expected: violation.expected(),
+                        actual: violation.actual(),
+                        aggregate: violation.aggregate,
+                    },
+                );
+                violations.push(violation);
+            }
+
+            cursor += 1;
+        }
+
+        self.integrity_cursor.set(&cursor, state)?;
+
+        if config.auto_pause && !violations.is_empty() && !self.paused.get(state)?.unwrap_or(false) {
+            tracing::error!(
+                violations = violations.len(),
+                "Veritas submissions auto-paused after an integrity violation"
+            );
+            self.paused.set(&true, state)?;
+            self.emit_event(
+                state,
+                Event::SubmissionsAutoPaused { violations: violations.clone() },
+            );
+        }
+
+        Ok(violations)
+    }
+
+    /// Rebuilds a belief's aggregate, total weight and submission count from its
+    /// recorded submissions (admin only)
+    ///
+    /// The submissions are replayed in index order, so the result is exactly what
+    /// live submission would have produced. Works on closed beliefs as well.
+    /// Emits `Event::BeliefRepaired` with the before and after values.
+    pub fn repair_belief(
+        &mut self,
+        belief_id: BeliefId,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+
+        let old_submission_count = self.belief_module.submission_counts.get(&belief_id, state)?
+            .unwrap_or(0);
+        let records: Vec<Contribution> = self.get_submissions(belief_id, state)?
+            .iter()
+            .map(|s| Contribution { value: s.value, weight: s.weight })
+            .collect();
+
+        let (before, after) = self.belief_module.replay_belief(belief_id, &records, state)?;
+        self.integrity_violations.delete(&belief_id, state)?;
+
+        self.emit_event(
+            state,
+            Event::BeliefRepaired {
+                belief_id,
+                old_aggregate: before.aggregate,
+                new_aggregate: after.aggregate,
+                old_total_weight: before.total_weight,
+                new_total_weight: after.total_weight,
+                old_submission_count,
+                new_submission_count: records.len() as u64,
+            },
+        );
+
+        Ok(())
+    }
+}
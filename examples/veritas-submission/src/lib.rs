@@ -16,6 +16,47 @@
 //! - Implements cross-module calls via internal methods (not CallMessage)
 //! - Records all submissions for historical analysis
 //! - REFACTORED: Using u64 fixed-point math (scale 10000) instead of f64 for determinism
+//! - Added per-belief reward pools and a claimable rewards ledger (see rewards.rs)
+//! - Added an optional submission fee, rebated to accurate forecasters at settlement
+//! - Added admin `repair_belief` to rebuild a drifted belief from its submissions
+//! - Added an optional stake-at-risk weight basis (see weight_basis.rs)
+//! - Added opt-in detection of agents submitting in lockstep (see collusion.rs)
+//! - Added a native (agent, belief) contribution matrix query (see matrix.rs)
+//! - Added a paused flag, optionally set by the integrity sweep (see integrity.rs)
+//! - Accuracy bonuses are now applied to agent scores through AgentModule::update_score
+//! - Added outcome-based score settlement for resolved beliefs (see outcome_scoring.rs)
+//! - An agent's resubmission to a belief now overwrites its earlier one instead of adding weight
+//! - Submissions past a belief's deadline fail with a typed BeliefClosed error
+//! - Agents can retract their current submission to a belief (see retraction.rs)
+//! - Added a per-agent submission index and `get_submissions_by_agent`
+//! - Optional stake slashing for badly wrong predictions at settlement (see slashing.rs)
+//! - The accuracy bonus curve and size are configurable at genesis (see scoring.rs)
+//! - Added distribution submissions to categorical beliefs (see categorical.rs)
+//! - Added counter-based system stats, also served as `GET /stats` (see stats.rs, http.rs)
+//! - Zero-weight agents are rejected with typed AgentZeroStake / AgentZeroScore errors
+//! - Added a read-only aggregate preview for wallets, also served as `GET /preview` (see preview.rs)
+//! - Added `get_agent_accuracy`, an agent's all-time accuracy on resolved beliefs (see outcome_scoring.rs)
+//! - Submission fees are charged before the aggregate moves and can go straight to the reward pool
+//! - Optional cap on how many beliefs one agent can submit to, bounding per-agent indices
+//! - Added `get_config`, the deployment's weighting and scoring settings, also served as `GET /config` (see config.rs)
+//! - Added a read-only `recompute_aggregate` audit of a belief's running mean (see audit.rs)
+//! - Settling a voided belief skips scoring and slashing and refunds its reward pool to its contributors
+//! - Added `VeritasError`, one type covering the Belief, Agent and Submission errors (see error.rs)
+//! - Added `GET /config/precision`, so clients read the fixed-point scale instead of hardcoding it
+//! - Optional per-agent limit on submissions within a single block
+//! - Added admin-only MergeBeliefs, folding a duplicate belief into another (see merge.rs)
+//! - Optional per-belief commit-reveal submissions, so late agents can't copy earlier values (see commit_reveal.rs)
+//! - `import_submissions` reads each belief and agent once per batch instead of once per record
+//! - Optional per-agent cooldown between accuracy bonuses, against score farming (see scoring.rs)
+//! - Added `get_unvoted_beliefs`, a page of open beliefs an agent hasn't submitted to
+//! - Added `get_recent_submissions`, the latest log entries newest first
+//! - Pausing now also stops `import_submissions`, so SetPaused halts every write to the log
+//! - `import_submissions` is reachable as ImportSubmissions and rejects duplicate live submissions
+//! - `repair_belief` is reachable as the admin-only RepairBelief
+//! - The integrity sweep emits IntegrityViolationDetected for every mismatch it finds
+//! - Belief rollbacks moved here as RollbackBelief, rewinding the submission index with the belief (see rollback.rs)
+//! - Resubmissions no longer earn the accuracy bonus; only an agent's first submission to a belief does
+//! - Genesis submissions set their beliefs' total weight and submission count, so the integrity sweep agrees
 //!
 //! KEY PATTERN:
 //! This module shows how Sovereign SDK modules can work together:
@@ -40,14 +81,87 @@ use schemars::JsonSchema;
 use sov_modules_api::macros::{serialize, UniversalWallet};
 use sov_modules_api::{
     Context, Module, ModuleId, ModuleInfo, ModuleRestApi, Spec,
-    StateVec, TxState,
+    StateCheckpoint, StateMap, StateValue, StateVec, TxState,
 };
+use std::collections::{BTreeMap, HashSet};
 use std::marker::PhantomData;
-use veritas_belief::{BeliefId, SCALE};
+use veritas_belief::{
+    BeliefId, BeliefStatus, CallPermission, CallPermissionEntry, Contribution, MAX_BELIEF_PAGE_SIZE, SCALE,
+};
+
+mod audit;
+pub use audit::*;
+
+mod categorical;
+pub use categorical::*;
+
+mod collusion;
+pub use collusion::*;
+
+mod commit_reveal;
+pub use commit_reveal::*;
+
+mod config;
+pub use config::*;
+
+mod error;
+pub use error::*;
+
+mod integrity;
+pub use integrity::*;
+
+mod merge;
+pub use merge::*;
+
+mod outcome_scoring;
+pub use outcome_scoring::*;
+
+mod preview;
+pub use preview::*;
+
+mod retraction;
+pub use retraction::*;
+
+mod rollback;
+pub use rollback::*;
+
+mod rewards;
+pub use rewards::*;
+
+mod scoring;
+pub use scoring::*;
+
+mod slashing;
+pub use slashing::*;
+
+mod stats;
+pub use stats::*;
+
+mod weight_basis;
+pub use weight_basis::*;
+
+mod weight_cap;
+pub use weight_cap::*;
+
+#[cfg(feature = "native")]
+mod genesis_builder;
+#[cfg(feature = "native")]
+pub use genesis_builder::*;
+
+#[cfg(feature = "native")]
+mod http;
+
+#[cfg(feature = "native")]
+mod matrix;
+#[cfg(feature = "native")]
+pub use matrix::*;
+
+/// Most entries `get_recent_submissions` returns; bigger requests are clamped to it
+pub const MAX_RECENT_SUBMISSIONS: u64 = 100;
 
 /// Records a single prediction submission
 /// Stored for historical analysis and audit purposes
-#[derive(Clone, Debug, borsh::BorshSerialize, borsh::BorshDeserialize, JsonSchema)]
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, JsonSchema, UniversalWallet)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(bound(serialize = "S::Address: serde::Serialize", 
               deserialize = "S::Address: serde::de::DeserializeOwned"))]
@@ -65,8 +179,37 @@ pub struct Submission<S: Spec> {
     pub weight: u64,
     
     /// Block timestamp when submission was made
-    /// Currently set to 0 (TODO: get from context)
+    /// From BeliefModule::block_timestamp, never the wall clock
     pub timestamp: u64,
+
+    /// Rollup height the submission was made (or last overwritten) at
+    /// Lets a rollback tell which submissions came after a snapshot (see rollback.rs)
+    #[serde(default)]
+    pub block_height: u64,
+
+    /// Submission fee held for settlement, in gas tokens (see rewards.rs for rebates)
+    /// Includes the fees of any submissions this one overwrote
+    /// Fees paid straight into the reward pool aren't counted here
+    #[serde(default)]
+    pub fee: u64,
+
+    /// Withdrawn by the agent (see retraction.rs)
+    /// Kept in the log for audit but no longer part of its belief
+    #[serde(default)]
+    pub retracted: bool,
+}
+
+/// One page of belief IDs, in ID order
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct BeliefIdPage {
+    /// Number of belief IDs assigned so far; IDs run from 1 to `total`
+    pub total: u64,
+    /// IDs skipped before this page
+    pub offset: u64,
+    /// Page size actually applied (after clamping)
+    pub limit: u64,
+    /// Matching IDs among offset+1 ..= offset+limit
+    pub belief_ids: Vec<BeliefId>,
 }
 
 /// SubmissionModule orchestrates the belief submission process
@@ -86,6 +229,148 @@ pub struct SubmissionModule<S: Spec> {
     #[state]
     pub submissions: StateVec<Submission<S>>,
 
+    /// Secondary index: belief ID -> positions of its submissions in `submissions`
+    /// Lets per-belief queries and the integrity sweep avoid scanning the whole log
+    /// NOTE: the Vec grows with every submission to the belief
+    #[state]
+    pub belief_submission_index: StateMap<BeliefId, Vec<u64>>,
+
+    /// (agent, belief) -> position of the agent's current submission in `submissions`
+    /// A resubmission overwrites that entry instead of appending a new one
+    #[state]
+    pub agent_submissions: StateMap<(S::Address, BeliefId), u64>,
+
+    /// Secondary index: agent -> positions of all its submissions in `submissions`,
+    /// retracted ones included, in the order they were first made
+    /// NOTE: the Vec grows with every belief the agent submits to (resubmissions
+    /// reuse their position), so a long-lived agent's entry is read and rewritten
+    /// in full on each new belief it joins
+    #[state]
+    pub agent_submission_index: StateMap<S::Address, Vec<u64>>,
+
+    /// Number of entries in each agent's `agent_submission_index`, i.e. beliefs joined
+    /// (a belief rejoined after a retraction counts again)
+    #[state]
+    pub agent_belief_counts: StateMap<S::Address, u64>,
+
+    /// Most beliefs one agent may join (0 = unlimited), bounding its index
+    #[state]
+    pub max_beliefs_per_agent: StateValue<u64>,
+
+    /// Submissions each agent made in each Veritas block, keyed by (agent, height)
+    /// Only written while a per-block limit is set; old heights are never read again
+    #[state]
+    pub agent_block_submissions: StateMap<(S::Address, u64), u64>,
+
+    /// Most submissions one agent may make in a single block (0 = unlimited)
+    #[state]
+    pub max_submissions_per_block: StateValue<u64>,
+
+    /// Blocks that must pass between two accuracy bonuses to the same agent (0 = no cooldown)
+    #[state]
+    pub score_cooldown_blocks: StateValue<u64>,
+
+    /// Veritas block height at which each agent last earned an accuracy bonus
+    #[state]
+    pub last_score_height: StateMap<S::Address, u64>,
+
+    /// (agent, belief) pairs that ever had a submission, retracted ones included
+    /// Only the first submission of a pair is considered for the accuracy bonus
+    #[state]
+    pub submitted_before: StateMap<(S::Address, BeliefId), bool>,
+
+    /// Commit and reveal deadlines of the beliefs taking commit-reveal submissions
+    #[state]
+    pub commit_reveal_windows: StateMap<BeliefId, CommitRevealWindow>,
+
+    /// (agent, belief) -> the agent's unrevealed commitment (see commit_reveal.rs)
+    #[state]
+    pub commitments: StateMap<(S::Address, BeliefId), [u8; 32]>,
+
+    /// Number of submissions accepted so far (see stats.rs)
+    #[state]
+    pub total_submissions: StateValue<u64>,
+
+    /// Distributions submitted to categorical beliefs (see categorical.rs)
+    #[state]
+    pub categorical_submissions: StateVec<CategoricalSubmission<S>>,
+
+    /// (agent, categorical belief) -> position of its submission in `categorical_submissions`
+    #[state]
+    pub categorical_agent_submissions: StateMap<(S::Address, BeliefId), u64>,
+
+    /// Configuration of the end-of-block integrity sweep (see integrity.rs)
+    #[state]
+    pub integrity_sweep: StateValue<IntegritySweepConfig>,
+
+    /// Next belief ID the integrity sweep will inspect
+    /// Rotates through all beliefs so each block only checks a small subset
+    #[state]
+    pub integrity_cursor: StateValue<BeliefId>,
+
+    /// Latest inconsistency detected by the sweep for each belief
+    /// Entries are only written on mismatch, so an empty map means "all good"
+    #[state]
+    pub integrity_violations: StateMap<BeliefId, IntegrityViolation>,
+
+    /// While true, new submissions are rejected
+    /// Set by the admin or by the integrity sweep's auto-pause
+    #[state]
+    pub paused: StateValue<bool>,
+
+    /// Cap on a single submission's share of total registered weight (see weight_cap.rs)
+    #[state]
+    pub weight_cap: StateValue<WeightCapConfig>,
+
+    /// Whether weights come from total stake or per-submission stake at risk (see weight_basis.rs)
+    #[state]
+    pub weight_basis: StateValue<WeightBasis>,
+
+    /// Undistributed gas tokens funding each belief's rewards (see rewards.rs)
+    /// The tokens themselves sit in this module's bank account
+    #[state]
+    pub reward_pools: StateMap<BeliefId, u64>,
+
+    /// Who paid into each belief's pool and how much, refunded if the belief is voided
+    #[state]
+    pub pool_contributions: StateMap<BeliefId, Vec<(S::Address, u64)>>,
+
+    /// Rewards credited to each agent and not yet claimed
+    #[state]
+    pub rewards_ledger: StateMap<S::Address, u64>,
+
+    /// Submission fee and rebate settings (see rewards.rs)
+    #[state]
+    pub fee_config: StateValue<FeeConfig>,
+
+    /// Beliefs whose submission fees were already rebated or forfeited
+    #[state]
+    pub fees_settled: StateMap<BeliefId, bool>,
+
+    /// Beliefs whose participants were already scored against the outcome
+    #[state]
+    pub scores_settled: StateMap<BeliefId, bool>,
+
+    /// Accuracy bonus applied on each submission (see scoring.rs)
+    #[state]
+    pub scoring: StateValue<ScoringParams>,
+
+    /// Stake slashing settings applied by `settle_rewards` (see slashing.rs)
+    #[state]
+    pub slashing: StateValue<SlashingConfig>,
+
+    /// Collusion heuristic settings (see collusion.rs)
+    #[state]
+    pub collusion_detection: StateValue<CollusionDetectionConfig>,
+
+    /// Each belief's submissions in the block it last received one
+    #[state]
+    pub block_submissions: StateMap<BeliefId, BlockSubmissions<S>>,
+
+    /// Number of (belief, block) clusters each agent was part of
+    #[state]
+    pub collusion_strikes: StateMap<S::Address, u64>,
+
     /// Reference to AgentModule for weight calculations.
     /// CRITICAL: The #[module] attribute tells Sovereign SDK this is a module reference.
     /// This is HOW cross-module communication works - we store references to other modules
@@ -99,6 +384,10 @@ pub struct SubmissionModule<S: Spec> {
     #[module]
     pub belief_module: veritas_belief::BeliefModule<S>,
 
+    /// Reference to the bank for reward pool deposits and payouts
+    #[module]
+    pub bank: sov_bank::Bank<S>,
+
     #[phantom]
     pub phantom: PhantomData<S>,
 }
@@ -106,8 +395,8 @@ pub struct SubmissionModule<S: Spec> {
 impl<S: Spec> Module for SubmissionModule<S> {
     type Spec = S;
     type Config = GenesisConfig<S>;
-    type CallMessage = CallMessage;
-    type Event = ();
+    type CallMessage = CallMessage<S>;
+    type Event = Event<S>;
 
     /// Initialize the module's state from genesis configuration
     fn genesis(
@@ -117,12 +406,71 @@ impl<S: Spec> Module for SubmissionModule<S> {
         state: &mut impl sov_modules_api::GenesisState<S>,
     ) -> Result<()> {
         // Initialize submissions from genesis config (usually empty)
+        let mut belief_totals: BTreeMap<BeliefId, (u128, u64)> = BTreeMap::new();
         for submission in &config.initial_submissions {
+            let position = self.submissions.len(state)?;
             self.submissions.push(submission, state)?;
+
+            let mut history = self.agent_submission_index.get(&submission.agent, state)?
+                .unwrap_or_default();
+            history.push(position);
+            self.agent_submission_index.set(&submission.agent, &history, state)?;
+            self.agent_belief_counts.set(&submission.agent, &(history.len() as u64), state)?;
+            self.submitted_before.set(&(submission.agent.clone(), submission.belief_id), &true, state)?;
+            if submission.retracted {
+                continue;
+            }
+
+            let mut index = self.belief_submission_index.get(&submission.belief_id, state)?
+                .unwrap_or_default();
+            index.push(position);
+            self.belief_submission_index.set(&submission.belief_id, &index, state)?;
+            self.agent_submissions.set(&(submission.agent.clone(), submission.belief_id), &position, state)?;
+
+            let totals = belief_totals.entry(submission.belief_id).or_default();
+            totals.0 += submission.weight as u128;
+            totals.1 += 1;
         }
+        self.total_submissions.set(&(config.initial_submissions.len() as u64), state)?;
+
+        // BeliefModule's genesis ran first and knows nothing of these submissions.
+        // The log is authoritative for weights and counts (the aggregate stays as
+        // configured); otherwise the integrity sweep flags every such belief
+        for (belief_id, (total_weight, count)) in belief_totals {
+            let Some(mut belief) = self.belief_module.beliefs.get(&belief_id, state)? else {
+                bail!("Genesis submission to unknown belief {}", belief_id);
+            };
+            belief.total_weight = total_weight;
+            self.belief_module.beliefs.set(&belief_id, &belief, state)?;
+            self.belief_module.submission_counts.set(&belief_id, &count, state)?;
+        }
+
+        self.integrity_sweep.set(&config.integrity_sweep, state)?;
+        self.weight_cap.set(&config.weight_cap, state)?;
+        self.weight_basis.set(&config.weight_basis, state)?;
+        self.fee_config.set(&config.fee, state)?;
+        self.collusion_detection.set(&config.collusion_detection, state)?;
+        self.slashing.set(&config.slashing, state)?;
+        self.scoring.set(&config.scoring, state)?;
+        self.max_beliefs_per_agent.set(&config.max_beliefs_per_agent, state)?;
+        self.max_submissions_per_block.set(&config.max_submissions_per_block, state)?;
+        self.score_cooldown_blocks.set(&config.score_cooldown_blocks, state)?;
+
+        // Only this module may raise agent scores (see AgentModule::update_score)
+        self.agent_module.score_updater.set(&self.id, state)?;
         Ok(())
     }
 
+    /// Runs the optional integrity sweep after every rollup block
+    ///
+    /// This is a safety net, NOT consensus-critical logic: a failing sweep
+    /// is logged and must never halt block production
+    fn end_rollup_block_hook(&mut self, state: &mut StateCheckpoint<S>) {
+        if let Err(e) = self.run_integrity_sweep(state) {
+            tracing::warn!(error = %e, "Veritas integrity sweep failed");
+        }
+    }
+
     /// Entry point for transaction processing
     /// 
     /// NOTE: Value is already in fixed-point format (0-10000)
@@ -136,7 +484,65 @@ impl<S: Spec> Module for SubmissionModule<S> {
         match msg {
             CallMessage::SubmitBelief { belief_id, value } => {
                 // Value is already in fixed-point format (0-10000)
-                self.submit_belief(belief_id, value, context, state)
+                self.ensure_direct_submissions(belief_id, state)?;
+                self.submit_belief(belief_id, value, None, context, state)
+            }
+            CallMessage::SubmitBeliefAtRisk { belief_id, value, stake_at_risk } => {
+                self.ensure_direct_submissions(belief_id, state)?;
+                self.submit_belief(belief_id, value, Some(stake_at_risk), context, state)
+            }
+            CallMessage::SubmitCategorical { belief_id, distribution } => {
+                self.submit_categorical(belief_id, distribution, context, state)?;
+                Ok(())
+            }
+            CallMessage::FundRewardPool { belief_id, amount } => {
+                self.fund_reward_pool(belief_id, amount, context, state)
+            }
+            CallMessage::SettleFees { belief_id } => {
+                self.settle_fees(belief_id, state)
+            }
+            CallMessage::DistributeRewards { belief_id } => {
+                self.distribute_rewards(belief_id, state)?;
+                Ok(())
+            }
+            CallMessage::ClaimAllRewards => {
+                self.claim_all_rewards(context, state)?;
+                Ok(())
+            }
+            CallMessage::SettleRewards { belief_id } => {
+                self.settle_rewards(belief_id, state)?;
+                Ok(())
+            }
+            CallMessage::RetractSubmission { belief_id } => {
+                self.retract_submission(belief_id, context, state)?;
+                Ok(())
+            }
+            CallMessage::SetPaused { paused } => {
+                self.set_paused(paused, context, state)
+            }
+            CallMessage::MergeBeliefs { source_id, dest_id } => {
+                self.merge_beliefs(source_id, dest_id, context, state)?;
+                Ok(())
+            }
+            CallMessage::EnableCommitReveal { belief_id, window } => {
+                self.enable_commit_reveal(belief_id, window, context, state)
+            }
+            CallMessage::CommitSubmission { belief_id, commitment } => {
+                self.commit_submission(belief_id, commitment, context, state)
+            }
+            CallMessage::RevealSubmission { belief_id, value, nonce } => {
+                self.reveal_submission(belief_id, value, nonce, context, state)
+            }
+            CallMessage::ImportSubmissions { records, recompute } => {
+                self.import_submissions(records, recompute, context, state)?;
+                Ok(())
+            }
+            CallMessage::RepairBelief { belief_id } => {
+                self.repair_belief(belief_id, context, state)
+            }
+            CallMessage::RollbackBelief { belief_id, height } => {
+                self.rollback_belief(belief_id, height, context, state)?;
+                Ok(())
             }
         }
     }
@@ -152,6 +558,46 @@ where
     S::Address: serde::Serialize + for<'a> serde::Deserialize<'a>,
 {
     pub initial_submissions: Vec<Submission<S>>,
+
+    /// End-of-block consistency sweep settings (disabled by default)
+    #[serde(default)]
+    pub integrity_sweep: IntegritySweepConfig,
+
+    /// Per-submission weight cap (disabled by default)
+    #[serde(default)]
+    pub weight_cap: WeightCapConfig,
+
+    /// Weight basis for submissions (total stake by default)
+    #[serde(default)]
+    pub weight_basis: WeightBasis,
+
+    /// Submission fee, its accuracy rebate and destination (no fee by default)
+    #[serde(default)]
+    pub fee: FeeConfig,
+
+    /// Collusion heuristic (disabled by default)
+    #[serde(default)]
+    pub collusion_detection: CollusionDetectionConfig,
+
+    /// Stake slashing at settlement (disabled by default)
+    #[serde(default)]
+    pub slashing: SlashingConfig,
+
+    /// Accuracy bonus per submission (up to 100 points on the inverse curve by default)
+    #[serde(default)]
+    pub scoring: ScoringParams,
+
+    /// Most beliefs a single agent may submit to (0, the default, means unlimited)
+    #[serde(default)]
+    pub max_beliefs_per_agent: u64,
+
+    /// Most submissions a single agent may make per block (0, the default, means unlimited)
+    #[serde(default)]
+    pub max_submissions_per_block: u64,
+
+    /// Blocks between two accuracy bonuses to the same agent (0, the default, means no cooldown)
+    #[serde(default)]
+    pub score_cooldown_blocks: u64,
 }
 
 impl<S: Spec> SubmissionModule<S> {
@@ -164,59 +610,144 @@ impl<S: Spec> SubmissionModule<S> {
     /// 4. Records submission history
     /// 
     /// FLOW:
-    /// 1. Validate input
+    /// 1. Validate input (belief exists, then agent is registered)
     /// 2. Get agent's weight (stake × score) via cross-module call
     /// 3. Update belief aggregate via cross-module call
-    /// 4. Calculate score bonus based on accuracy
-    /// 5. Store submission record
+    ///    (recomputed instead when the agent already submitted to this belief)
+    /// 4. Calculate score bonus based on accuracy and apply it via AgentModule
+    ///    (the agent's first submission to the belief only)
+    /// 5. Store submission record, overwriting the agent's earlier one if any
+    ///
+    /// `stake_at_risk` must be given exactly when the weight basis is
+    /// `StakeAtRisk`; it then replaces the agent's total stake in the weight
+    /// and is locked until the belief closes.
     pub fn submit_belief(
         &mut self,
         belief_id: BeliefId,
         value: u64,
+        stake_at_risk: Option<u64>,
         context: &Context<S>,
         state: &mut impl TxState<S>,
     ) -> Result<()> {
+        if self.paused.get(state)?.unwrap_or(false) {
+            return Err(SubmissionError::Paused.into());
+        }
+
         if value > SCALE {
-            bail!("Value must be between 0 and {}", SCALE);
+            return Err(SubmissionError::ValueOutOfRange { value }.into());
         }
 
         let sender = context.sender();
+        let agent = self.ensure_can_submit(belief_id, sender, state)?;
+
+        // Joining a new belief grows the agent's index; resubmissions don't
+        let limit = self.max_beliefs_per_agent.get(state)?.unwrap_or(0);
+        if limit > 0 && self.agent_submissions.get(&(sender.clone(), belief_id), state)?.is_none() {
+            let joined = self.agent_belief_counts.get(sender, state)?.unwrap_or(0);
+            if joined >= limit {
+                return Err(SubmissionError::BeliefLimitReached { limit }.into());
+            }
+        }
+
+        // RATE LIMIT:
+        // Repeated folds in one block could drag an aggregate around, so each
+        // agent gets a budget per block; the height in the key resets it
+        let per_block = self.max_submissions_per_block.get(state)?.unwrap_or(0);
+        let block_key = (sender.clone(), self.belief_module.current_block(state)?);
+        let in_block = self.agent_block_submissions.get(&block_key, state)?.unwrap_or(0);
+        if per_block > 0 && in_block >= per_block {
+            return Err(SubmissionError::BlockLimitReached { limit: per_block }.into());
+        }
         
         // CROSS-MODULE CALL #1: Get agent's weight from AgentModule
         // This demonstrates how modules can call each other's public methods
-        let weight = self.agent_module.get_weight(sender, state)?;
+        let basis = self.weight_basis.get(state)?.unwrap_or_default();
+        let weight = match (basis, stake_at_risk) {
+            (WeightBasis::TotalStake, None) => self.agent_module.get_weight(sender, state)
+                .map_err(SubmissionError::from_agent)?,
+            (WeightBasis::StakeAtRisk, Some(amount)) => {
+                // Fails unless the amount fits in the agent's free stake
+                self.agent_module.commit_stake_at_risk(sender, belief_id, amount, state)?;
+                let rule = self.agent_module.weight_rule(state)?;
+                agent.weight_for_stake(amount, rule)
+            }
+            (WeightBasis::TotalStake, Some(_)) => {
+                bail!("Stake at risk is not enabled; use SubmitBelief");
+            }
+            (WeightBasis::StakeAtRisk, None) => {
+                bail!("Submissions must specify a stake at risk; use SubmitBeliefAtRisk");
+            }
+        };
+        // Only a zero score gets here: get_weight never returns 0 and stake at risk is non-zero
         if weight == 0 {
-            bail!("Agent has no weight (stake × score = 0)");
+            return Err(SubmissionError::AgentZeroScore.into());
+        }
+
+        // Bound this submission's share of total registered weight (reject or scale down)
+        let weight = self.apply_weight_cap(weight, state)?;
+
+        // Apply the belief's value granularity (may reject or snap to a bucket)
+        // so the recorded submission matches what gets aggregated
+        let value = self.belief_module.normalize_submission_value(belief_id, value, state)?;
+
+        // ONE SUBMISSION PER AGENT AND BELIEF:
+        // Resubmitting replaces the earlier submission, so an agent's weight
+        // is never counted twice towards the same belief
+        let previous = self.agent_submissions.get(&(sender.clone(), belief_id), state)?;
+
+        // Charged before the aggregate moves, so a sender who can't pay changes nothing
+        // Held by this module until the belief settles, unless it goes straight to the pool
+        // An overwritten submission's held fee carries over, so settlement still accounts for it
+        let mut fee = self.collect_submission_fee(belief_id, context, state)?;
+        if let Some(position) = previous {
+            if let Some(old) = self.submissions.get(position, state)? {
+                fee = fee.saturating_add(old.fee);
+            }
         }
 
         // CROSS-MODULE CALL #2: Update belief aggregate in BeliefModule
         // The new aggregate is returned so we can calculate score rewards
-        let new_aggregate = self.belief_module.update_aggregate(belief_id, value, weight, state)?;
-        
+        let new_aggregate = match previous {
+            None => self.belief_module.update_aggregate(belief_id, value, weight, state)?,
+            Some(position) => self.update_submission(belief_id, position, value, weight, state)?,
+        };
+
+        // Lock the stake backing this submission (no-op unless stake locks are enabled)
+        // Stake at risk was already locked above
+        if stake_at_risk.is_none() {
+            self.agent_module.commit_stake(sender, belief_id, state)?;
+        }
+        // No withdrawals at all for a while (no-op unless a cooldown is configured)
+        self.agent_module.start_withdrawal_cooldown(sender, state)?;
+
         // SCORING MECHANISM:
         // Agents are rewarded based on how close their prediction is to consensus
-        // Using integer math: distance of 0 = perfect match = 100 point bonus
-        // Distance of 5000 (50%) = ~2 point bonus
-        let distance = if value > new_aggregate {
-            value - new_aggregate
-        } else {
-            new_aggregate - value
-        };
-        
-        // Score bonus calculation using fixed-point math
-        // Max bonus is 100 points for perfect match
-        let _score_delta = if distance == 0 {
-            100
-        } else {
-            // Scale down the bonus based on distance
-            100u64.saturating_mul(SCALE).saturating_div(SCALE + distance)
-        };
+        // By default: distance of 0 = perfect match = 100 point bonus,
+        // distance of 5000 (50%) = 66 point bonus (see scoring.rs for the curves)
+        let scoring = self.scoring.get(state)?.unwrap_or_default();
+        let score_delta = compute_score_delta(value, new_aggregate, &scoring);
         
-        // TODO: Currently we can't update scores because update_score is not exposed
-        // in AgentModule's CallMessage. In a real implementation, we'd either:
-        // 1. Add UpdateScore to CallMessage (but restrict who can call it)
-        // 2. Make SubmissionModule a friend module with special access
-        // 3. Use a different scoring mechanism
+        // CROSS-MODULE CALL #3: Apply the bonus in AgentModule
+        // update_score only accepts this module's ID, registered at genesis;
+        // until then (e.g. a module built without genesis) scores stay put
+        // Within the score cooldown the value still counts, only the bonus is skipped
+        // Only an agent's first submission to a belief earns it: the aggregate
+        // already holds the agent's own value, so overwriting (or retracting and
+        // resubmitting) would farm the bonus
+        let pair = (sender.clone(), belief_id);
+        let first_submission = !self.submitted_before.get(&pair, state)?.unwrap_or(false);
+        if first_submission
+            && self.agent_module.score_updater.get(state)?.is_some()
+            && self.score_cooldown_over(sender, state)?
+        {
+            self.agent_module.update_score(&self.id, sender.clone(), score_delta, state)?;
+            if score_delta > 0 {
+                self.record_score_earned(sender, state)?;
+            }
+        }
+        if first_submission {
+            self.submitted_before.set(&pair, &true, state)?;
+        }
         
         // Record submission for historical tracking
         let submission = Submission {
@@ -224,34 +755,325 @@ impl<S: Spec> SubmissionModule<S> {
             belief_id,
             value,
             weight,
-            timestamp: 0,  // TODO: Get actual timestamp from context
+            timestamp: self.belief_module.block_timestamp(state)?,
+            block_height: self.belief_module.current_block(state)?,
+            fee,
+            retracted: false,
         };
         
+        match previous {
+            Some(position) => self.submissions.set(position, &submission, state)?,
+            None => self.record_submission(&submission, state)?,
+        }
+        self.count_submissions(1, state)?;
+        if per_block > 0 {
+            self.agent_block_submissions.set(&block_key, &(in_block + 1), state)?;
+        }
+
+        // Review heuristic only: it must never fail a submission
+        if let Err(e) = self.detect_collusion(sender, belief_id, value, state) {
+            tracing::warn!(error = %e, belief_id, "Veritas collusion detection failed");
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `sender` may submit to `belief_id` and returns its agent record
+    ///
+    /// ERROR PRECEDENCE: the belief is validated before the agent.
+    /// A missing belief is reported even when the sender is also unregistered,
+    /// since no agent could submit to it anyway. Each case has its own typed error.
+    fn ensure_can_submit(
+        &self,
+        belief_id: BeliefId,
+        sender: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<veritas_agent::Agent> {
+        let Some(belief) = self.belief_module.beliefs.get(&belief_id, state)? else {
+            return Err(SubmissionError::BeliefNotFound { belief_id }.into());
+        };
+        let Some(agent) = self.agent_module.agents.get(sender, state)? else {
+            return Err(SubmissionError::AgentNotRegistered.into());
+        };
+
+        // Beliefs without a deadline never close this way
+        if let Some(deadline_block) = belief.deadline_block {
+            if self.belief_module.current_block(state)? > deadline_block {
+                return Err(SubmissionError::BeliefClosed { belief_id, deadline_block }.into());
+            }
+        }
+
+        // Permissioned beliefs only admit agents of a sufficient tier
+        if agent.tier < belief.min_tier {
+            return Err(SubmissionError::TierTooLow { required: belief.min_tier, actual: agent.tier }.into());
+        }
+
+        Ok(agent)
+    }
+
+    /// Re-ingests historical submissions, e.g. during a chain upgrade (admin only)
+    ///
+    /// Every record must reference an existing belief and a registered agent,
+    /// and a live (not retracted) record must not repeat an agent's live
+    /// submission to the same belief, in state or earlier in the batch; all
+    /// records are validated before any is written.
+    ///
+    /// With `recompute`, each record is also replayed through `update_aggregate`,
+    /// so aggregates, weights and counts end up exactly as if the submissions
+    /// had been made live in this order. Without it only the log is restored
+    /// (for migrations that carry beliefs over with their aggregates intact).
+    /// Retracted records are restored to the log only, in either case.
+    /// Imports are refused while submissions are paused.
+    ///
+    /// Returns the number of imported records.
+    pub fn import_submissions(
+        &mut self,
+        records: Vec<Submission<S>>,
+        recompute: bool,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+        if self.paused.get(state)?.unwrap_or(false) {
+            return Err(SubmissionError::Paused.into());
+        }
+
+        // Nothing is written while validating, so each belief and agent is read once
+        // however many records share it
+        let mut known_beliefs = HashSet::new();
+        let mut known_agents = HashSet::new();
+        let mut live_keys = HashSet::new();
+        for record in &records {
+            if record.value > SCALE {
+                return Err(SubmissionError::ValueOutOfRange { value: record.value }.into());
+            }
+            if !known_beliefs.contains(&record.belief_id) {
+                if self.belief_module.beliefs.get(&record.belief_id, state)?.is_none() {
+                    return Err(SubmissionError::BeliefNotFound { belief_id: record.belief_id }.into());
+                }
+                known_beliefs.insert(record.belief_id);
+            }
+            if !known_agents.contains(&record.agent) {
+                if self.agent_module.agents.get(&record.agent, state)?.is_none() {
+                    return Err(SubmissionError::AgentNotRegistered.into());
+                }
+                known_agents.insert(&record.agent);
+            }
+            // A second live submission would be counted next to the first on recompute
+            if !record.retracted {
+                let key = (record.agent.clone(), record.belief_id);
+                if live_keys.contains(&key) || self.agent_submissions.get(&key, state)?.is_some() {
+                    return Err(SubmissionError::AlreadySubmitted { belief_id: record.belief_id }.into());
+                }
+                live_keys.insert(key);
+            }
+        }
+
+        for record in &records {
+            if recompute && !record.retracted {
+                self.belief_module.update_aggregate(record.belief_id, record.value, record.weight, state)?;
+            }
+            self.record_submission(record, state)?;
+        }
+        self.count_submissions(records.len() as u64, state)?;
+
+        Ok(records.len() as u64)
+    }
+
+    /// Pauses or resumes submissions (admin only)
+    pub fn set_paused(
+        &mut self,
+        paused: bool,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+        self.paused.set(&paused, state)?;
+        Ok(())
+    }
+
+    /// Appends a submission to the log, its agent's index and, unless retracted, its belief's index
+    fn record_submission(
+        &mut self,
+        submission: &Submission<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
         // StateVec.push appends to the list
-        self.submissions.push(&submission, state)?;
+        let position = self.submissions.len(state)?;
+        self.submissions.push(submission, state)?;
+
+        let mut history = self.agent_submission_index.get(&submission.agent, state)?
+            .unwrap_or_default();
+        history.push(position);
+        self.agent_submission_index.set(&submission.agent, &history, state)?;
+        let count = self.agent_belief_counts.get(&submission.agent, state)?.unwrap_or(0);
+        self.agent_belief_counts.set(&submission.agent, &(count + 1), state)?;
+        // Imported records count as earlier submissions for the accuracy bonus too
+        self.submitted_before.set(&(submission.agent.clone(), submission.belief_id), &true, state)?;
+        if submission.retracted {
+            return Ok(());
+        }
+
+        // Keep the per-belief index in sync with the log
+        let mut index = self.belief_submission_index.get(&submission.belief_id, state)?
+            .unwrap_or_default();
+        index.push(position);
+        self.belief_submission_index.set(&submission.belief_id, &index, state)?;
+        self.agent_submissions.set(&(submission.agent.clone(), submission.belief_id), &position, state)?;
 
         Ok(())
     }
 
+    /// Swaps the agent's earlier submission at `position` for a new (value, weight)
+    ///
+    /// The belief's aggregate is recomputed from all of its current submissions
+    /// with the replacement in place, so the old contribution drops out exactly.
+    /// The log entry itself is overwritten by the caller. Returns the new aggregate.
+    ///
+    /// COST: reads every current submission to the belief, so an overwrite costs
+    /// O(n) state reads in the belief's submission count, unlike the O(1) first
+    /// submission. Deployments with crowded beliefs can bound how often one agent
+    /// pays it with `max_submissions_per_block`.
+    fn update_submission(
+        &mut self,
+        belief_id: BeliefId,
+        position: u64,
+        value: u64,
+        weight: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let records = self.records_replacing(belief_id, position, value, weight, state)?;
+        self.belief_module.recompute_aggregate(belief_id, &records, state)
+    }
+
+    /// A belief's current (value, weight) records in submission order, with
+    /// the one at `position` swapped for the given value and weight
+    fn records_replacing(
+        &self,
+        belief_id: BeliefId,
+        position: u64,
+        value: u64,
+        weight: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<Contribution>> {
+        let index = self.belief_submission_index.get(&belief_id, state)?
+            .unwrap_or_default();
+
+        let mut records = Vec::with_capacity(index.len());
+        for p in index {
+            if p == position {
+                records.push(Contribution { value, weight });
+            } else if let Some(submission) = self.submissions.get(p, state)? {
+                records.push(Contribution { value: submission.value, weight: submission.weight });
+            }
+        }
+        Ok(records)
+    }
+
+    /// Lists every CallMessage variant with the permission `call` enforces for it
+    #[cfg(feature = "native")]
+    pub fn call_permissions(&self) -> Vec<CallPermissionEntry> {
+        vec![
+            CallPermissionEntry::new("submit_belief", CallPermission::Public),
+            CallPermissionEntry::new("submit_belief_at_risk", CallPermission::Public),
+            CallPermissionEntry::new("submit_categorical", CallPermission::Public),
+            CallPermissionEntry::new("fund_reward_pool", CallPermission::Public),
+            CallPermissionEntry::new("settle_fees", CallPermission::Public),
+            CallPermissionEntry::new("distribute_rewards", CallPermission::Public),
+            CallPermissionEntry::new("claim_all_rewards", CallPermission::Public),
+            CallPermissionEntry::new("settle_rewards", CallPermission::Public),
+            CallPermissionEntry::new("retract_submission", CallPermission::Public),
+            CallPermissionEntry::new("set_paused", CallPermission::Admin),
+            CallPermissionEntry::new("merge_beliefs", CallPermission::Admin),
+            CallPermissionEntry::new("enable_commit_reveal", CallPermission::Admin),
+            CallPermissionEntry::new("commit_submission", CallPermission::Public),
+            CallPermissionEntry::new("reveal_submission", CallPermission::Public),
+            CallPermissionEntry::new("import_submissions", CallPermission::Admin),
+            CallPermissionEntry::new("repair_belief", CallPermission::Admin),
+            CallPermissionEntry::new("rollback_belief", CallPermission::Admin),
+        ]
+    }
+
     pub fn get_submissions(
         &self,
         belief_id: BeliefId,
         state: &mut impl TxState<S>,
     ) -> Result<Vec<Submission<S>>> {
         let mut result = Vec::new();
-        let len = self.submissions.len(state)?;
+        let index = self.belief_submission_index.get(&belief_id, state)?
+            .unwrap_or_default();
         
-        for i in 0..len {
-            if let Some(submission) = self.submissions.get(i, state)? {
-                if submission.belief_id == belief_id {
-                    result.push(submission);
-                }
+        // Only touch the positions recorded for this belief instead of the whole log
+        for position in index {
+            if let Some(submission) = self.submissions.get(position, state)? {
+                result.push(submission);
             }
         }
         
         Ok(result)
     }
 
+    /// Every submission an agent made, retracted ones included, oldest first
+    ///
+    /// A resubmission replaces the earlier record, so there is one entry per
+    /// belief the agent submitted to.
+    pub fn get_submissions_by_agent(
+        &self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<Submission<S>>> {
+        let mut result = Vec::new();
+        let index = self.agent_submission_index.get(address, state)?
+            .unwrap_or_default();
+
+        for position in index {
+            if let Some(submission) = self.submissions.get(position, state)? {
+                result.push(submission);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Open beliefs with IDs offset+1 ..= offset+limit that `address` has no
+    /// current submission to
+    ///
+    /// `limit` is clamped to MAX_BELIEF_PAGE_SIZE. Like `list_beliefs`, pages
+    /// are defined over the ID range, so a page can hold fewer than `limit` IDs;
+    /// keep going until `offset` reaches `total`. A retracted submission
+    /// doesn't count as a vote.
+    pub fn get_unvoted_beliefs(
+        &self,
+        address: &S::Address,
+        offset: u64,
+        limit: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<BeliefIdPage> {
+        let limit = limit.min(MAX_BELIEF_PAGE_SIZE);
+        let total = self.belief_module.next_belief_id.get(state)?.unwrap_or(1).saturating_sub(1);
+
+        let first = offset.saturating_add(1);
+        let last = offset.saturating_add(limit).min(total);
+        let mut belief_ids = Vec::new();
+        for belief_id in first..=last {
+            let Some(belief) = self.belief_module.beliefs.get(&belief_id, state)? else {
+                continue;
+            };
+            if BeliefStatus::Open.matches(&belief)
+                && self.agent_submissions.get(&(address.clone(), belief_id), state)?.is_none()
+            {
+                belief_ids.push(belief_id);
+            }
+        }
+
+        Ok(BeliefIdPage { total, offset, limit, belief_ids })
+    }
+
+    /// Every entry of the submission log, in insertion order (oldest first)
+    ///
+    /// The log is append-only and a resubmission overwrites its entry in
+    /// place, so an entry keeps the position of the agent's first submission
+    /// to the belief. Reads the whole log; see `get_recent_submissions`.
     pub fn get_all_submissions(
         &self,
         state: &mut impl TxState<S>,
@@ -267,15 +1089,123 @@ impl<S: Spec> SubmissionModule<S> {
         
         Ok(result)
     }
+
+    /// The last `limit` entries of the submission log, newest first
+    ///
+    /// `limit` is clamped to MAX_RECENT_SUBMISSIONS. Only those entries are
+    /// read, however long the log. Retracted entries are included, like in
+    /// `get_all_submissions`.
+    pub fn get_recent_submissions(
+        &self,
+        limit: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<Submission<S>>> {
+        let len = self.submissions.len(state)?;
+        let first = len.saturating_sub(limit.min(MAX_RECENT_SUBMISSIONS));
+
+        let mut result = Vec::with_capacity((len - first) as usize);
+        for i in (first..len).rev() {
+            if let Some(submission) = self.submissions.get(i, state)? {
+                result.push(submission);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Events emitted by SubmissionModule
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+#[serde(rename_all = "snake_case")]
+pub enum Event<S: Spec> {
+    /// A belief's totals were rebuilt from its recorded submissions
+    /// Equal old and new values mean there was no drift
+    BeliefRepaired {
+        belief_id: BeliefId,
+        old_aggregate: u64,
+        new_aggregate: u64,
+        old_total_weight: u128,
+        new_total_weight: u128,
+        old_submission_count: u64,
+        new_submission_count: u64,
+    },
+    /// The integrity sweep found a belief whose stored totals (`actual`) disagree with
+    /// its recorded submissions (`expected`), or whose aggregate is above SCALE
+    IntegrityViolationDetected {
+        belief_id: BeliefId,
+        expected: IntegrityTotals,
+        actual: IntegrityTotals,
+        aggregate: u64,
+    },
+    /// The integrity sweep found violations and paused submissions (admin must investigate)
+    SubmissionsAutoPaused { violations: Vec<IntegrityViolation> },
+    /// Agents repeatedly submitted nearly identical values together (admin review, no penalty)
+    CollusionSuspected {
+        belief_id: BeliefId,
+        block_height: u64,
+        value: u64,
+        agents: Vec<S::Address>,
+    },
+    /// An agent withdrew its submission and the belief was recomputed without it
+    SubmissionRetracted {
+        belief_id: BeliefId,
+        agent: S::Address,
+        value: u64,
+        weight: u64,
+        new_aggregate: u64,
+    },
+    /// A duplicate belief was folded into `dest_id` and deleted
+    BeliefsMerged {
+        source_id: BeliefId,
+        dest_id: BeliefId,
+        moved_submissions: u64,
+        new_aggregate: u64,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, JsonSchema, UniversalWallet)]
 #[serialize(Borsh, Serde)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
 #[serde(rename_all = "snake_case")]
-pub enum CallMessage {
+pub enum CallMessage<S: Spec> {
     SubmitBelief { 
         belief_id: BeliefId, 
         value: u64  // Fixed-point value: 0-10000 representing 0.0-1.0
     },
+    /// Submit with an explicit amount of free stake at risk (stake-at-risk weight basis)
+    SubmitBeliefAtRisk { belief_id: BeliefId, value: u64, stake_at_risk: u64 },
+    /// Submit a probability per outcome to a categorical belief (summing to SCALE)
+    SubmitCategorical { belief_id: BeliefId, distribution: Vec<u64> },
+    /// Deposit gas tokens into a belief's reward pool
+    FundRewardPool { belief_id: BeliefId, amount: u64 },
+    /// Rebate accurate submitters' fees and forfeit the rest to the pool (anyone may trigger it)
+    SettleFees { belief_id: BeliefId },
+    /// Credit a resolved belief's pool to its participants (anyone may trigger it)
+    DistributeRewards { belief_id: BeliefId },
+    /// Withdraw the sender's entire rewards ledger balance in one transfer
+    ClaimAllRewards,
+    /// Score a resolved belief's participants against its outcome (anyone may trigger it)
+    SettleRewards { belief_id: BeliefId },
+    /// Withdraw the sender's current submission to a belief from its aggregate
+    RetractSubmission { belief_id: BeliefId },
+    /// Admin-only: stop or resume accepting submissions
+    SetPaused { paused: bool },
+    /// Admin-only: fold a duplicate belief's submissions into another and delete it
+    MergeBeliefs { source_id: BeliefId, dest_id: BeliefId },
+    /// Admin-only: make a belief take its submissions through commit-reveal
+    EnableCommitReveal { belief_id: BeliefId, window: CommitRevealWindow },
+    /// Commit to a value for a commit-reveal belief without disclosing it
+    CommitSubmission { belief_id: BeliefId, commitment: [u8; 32] },
+    /// Disclose a committed value (and its nonce) so it is folded into the aggregate
+    RevealSubmission { belief_id: BeliefId, value: u64, nonce: [u8; 32] },
+    /// Admin-only: restore submission records from a migrated chain (see `import_submissions`)
+    ImportSubmissions { records: Vec<Submission<S>>, recompute: bool },
+    /// Admin-only: rebuild a drifted belief's totals from its recorded submissions
+    RepairBelief { belief_id: BeliefId },
+    /// Admin-only: restore a belief and its submissions to its snapshot at block `height` (see rollback.rs)
+    RollbackBelief { belief_id: BeliefId, height: u64 },
 }
 
@@ -0,0 +1,101 @@
+//! Contribution matrix - who put how much weight into which beliefs
+//!
+//! FILE PURPOSE:
+//! Research and manipulation forensics want to see influence patterns across
+//! beliefs (e.g. the same few agents dominating a cluster of markets) without
+//! scanning the whole submission log. `get_contribution_matrix` walks only the
+//! requested beliefs' submission indexes and returns a sparse
+//! (agent, belief) -> weight matrix.
+//!
+//! Requests are bounded: at most `MAX_MATRIX_BELIEFS` beliefs, and the dense
+//! size (distinct agents × beliefs) may not exceed `MAX_MATRIX_CELLS`.
+//!
+//! Native-only: a read-only query, never part of the zk guest.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{ApiStateAccessor, Spec};
+use veritas_belief::BeliefId;
+
+use crate::SubmissionModule;
+
+/// Most beliefs a single matrix request may cover
+pub const MAX_MATRIX_BELIEFS: usize = 100;
+
+/// Largest allowed distinct agents × beliefs for a single matrix request
+pub const MAX_MATRIX_CELLS: usize = 10_000;
+
+/// One non-empty cell of the contribution matrix
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(bound(serialize = "S::Address: serde::Serialize",
+              deserialize = "S::Address: serde::de::DeserializeOwned"))]
+pub struct ContributionEntry<S: Spec> {
+    pub agent: S::Address,
+    pub belief_id: BeliefId,
+
+    /// Sum of the weights of the agent's submissions to the belief (saturating)
+    pub weight: u64,
+
+    /// Number of submissions behind `weight`
+    pub submissions: u64,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Returns the sparse (agent, belief) -> contributed weight matrix for `belief_ids`
+    ///
+    /// Entries are ordered by belief (in request order), then by each agent's
+    /// first submission to it. Unknown beliefs and duplicates contribute nothing.
+    pub fn get_contribution_matrix(
+        &self,
+        belief_ids: &[BeliefId],
+        state: &mut ApiStateAccessor<S>,
+    ) -> Result<Vec<ContributionEntry<S>>> {
+        if belief_ids.len() > MAX_MATRIX_BELIEFS {
+            bail!("At most {} beliefs per matrix request", MAX_MATRIX_BELIEFS);
+        }
+
+        let mut entries: Vec<ContributionEntry<S>> = Vec::new();
+        let mut agents: Vec<S::Address> = Vec::new();
+        let mut seen: Vec<BeliefId> = Vec::new();
+
+        for belief_id in belief_ids {
+            if seen.contains(belief_id) {
+                continue;
+            }
+            seen.push(*belief_id);
+
+            let first_entry = entries.len();
+            let index = self.belief_submission_index.get(belief_id, state)?
+                .unwrap_or_default();
+            for position in index {
+                let Some(submission) = self.submissions.get(position, state)? else {
+                    continue;
+                };
+                if !agents.contains(&submission.agent) {
+                    agents.push(submission.agent.clone());
+                    if agents.len() * belief_ids.len() > MAX_MATRIX_CELLS {
+                        bail!(
+                            "Matrix exceeds {} cells (agents × beliefs); request fewer beliefs",
+                            MAX_MATRIX_CELLS
+                        );
+                    }
+                }
+
+                match entries[first_entry..].iter_mut().find(|e| e.agent == submission.agent) {
+                    Some(entry) => {
+                        entry.weight = entry.weight.saturating_add(submission.weight);
+                        entry.submissions += 1;
+                    }
+                    None => entries.push(ContributionEntry {
+                        agent: submission.agent,
+                        belief_id: *belief_id,
+                        weight: submission.weight,
+                        submissions: 1,
+                    }),
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+}
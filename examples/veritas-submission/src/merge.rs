@@ -0,0 +1,125 @@
+//! Belief merging - folding a duplicate belief into the one that stays
+//!
+//! FILE PURPOSE:
+//! Governance can create the same question twice, splitting its forecasters
+//! across two beliefs. `merge_beliefs` moves the source belief's submissions
+//! onto the destination, rebuilds the destination from the combined log and
+//! deletes the source.
+//!
+//! HOW IT WORKS:
+//! - Both beliefs must be open binary beliefs, and different
+//! - The source's submissions are re-pointed at the destination in the log and
+//!   the destination is replayed from both, in submission order, through
+//!   `BeliefModule::merge_beliefs`: the same weighted-average math as live
+//!   submissions, so the result is the weighted mean of both beliefs. A destination
+//!   without weight simply ends up where the source was
+//! - An agent that submitted to both keeps its destination submission; the
+//!   source one is flagged retracted and its fee goes to the destination's pool,
+//!   as with a retraction
+//! - The source's reward pool, and who paid into it, move to the destination
+//!
+//! WHAT IS NOT MOVED:
+//! Stake locks on the source are released, since a deleted belief no longer
+//! holds stake (see veritas_agent's stake_lock.rs). The destination keeps its
+//! own settings: weighting, deadline, quorum.
+
+use anyhow::{bail, Result};
+use sov_modules_api::{Context, EventEmitter, Spec, TxState};
+use veritas_belief::{BeliefError, BeliefId, BeliefStatus, Contribution};
+
+use crate::{Event, SubmissionModule};
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Moves `source_id`'s submissions and reward pool to `dest_id` and deletes
+    /// `source_id` (admin only)
+    ///
+    /// Returns the destination's new aggregate.
+    pub fn merge_beliefs(
+        &mut self,
+        source_id: BeliefId,
+        dest_id: BeliefId,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+
+        // Checked up front so nothing is re-pointed for a merge that can't happen
+        if source_id == dest_id {
+            bail!("Belief {} can't be merged into itself", source_id);
+        }
+        for belief_id in [source_id, dest_id] {
+            let belief = self.belief_module.beliefs.get(&belief_id, state)?
+                .ok_or(BeliefError::NotFound { belief_id })?;
+            if !BeliefStatus::Open.matches(&belief) {
+                bail!("Belief {} is closed and can't be merged", belief_id);
+            }
+        }
+
+        let source_index = self.belief_submission_index.get(&source_id, state)?
+            .unwrap_or_default();
+        let mut dest_index = self.belief_submission_index.get(&dest_id, state)?
+            .unwrap_or_default();
+        let mut moved_submissions = 0;
+        for position in source_index {
+            let Some(mut submission) = self.submissions.get(position, state)? else {
+                continue;
+            };
+            self.agent_submissions.delete(&(submission.agent.clone(), source_id), state)?;
+
+            let dest_key = (submission.agent.clone(), dest_id);
+            self.submitted_before.set(&dest_key, &true, state)?;
+            if self.agent_submissions.get(&dest_key, state)?.is_some() {
+                // The agent's own destination submission stands
+                if submission.fee > 0 {
+                    self.add_to_reward_pool(dest_id, &submission.agent, submission.fee, state)?;
+                }
+                submission.retracted = true;
+            } else {
+                self.agent_submissions.set(&dest_key, &position, state)?;
+                dest_index.push(position);
+                moved_submissions += 1;
+            }
+            submission.belief_id = dest_id;
+            self.submissions.set(position, &submission, state)?;
+        }
+        // Log positions are in submission order
+        dest_index.sort_unstable();
+        self.belief_submission_index.set(&dest_id, &dest_index, state)?;
+        self.belief_submission_index.delete(&source_id, state)?;
+        self.block_submissions.delete(&source_id, state)?;
+        self.integrity_violations.delete(&source_id, state)?;
+
+        let pool = self.reward_pools.get(&source_id, state)?.unwrap_or(0);
+        let mut attributed = 0u64;
+        for (contributor, amount) in self.pool_contributions.get(&source_id, state)?.unwrap_or_default() {
+            self.add_to_reward_pool(dest_id, &contributor, amount, state)?;
+            attributed = attributed.saturating_add(amount);
+        }
+        // Whatever wasn't recorded against a contributor moves unattributed
+        let unattributed = pool.saturating_sub(attributed);
+        if unattributed > 0 {
+            let dest_pool = self.reward_pools.get(&dest_id, state)?.unwrap_or(0);
+            self.reward_pools.set(&dest_id, &dest_pool.saturating_add(unattributed), state)?;
+        }
+        self.reward_pools.delete(&source_id, state)?;
+        self.pool_contributions.delete(&source_id, state)?;
+
+        let records: Vec<Contribution> = self.get_submissions(dest_id, state)?
+            .iter()
+            .map(|s| Contribution { value: s.value, weight: s.weight })
+            .collect();
+        let (_, after) = self.belief_module.merge_beliefs(source_id, dest_id, &records, state)?;
+
+        self.emit_event(
+            state,
+            Event::BeliefsMerged {
+                source_id,
+                dest_id,
+                moved_submissions,
+                new_aggregate: after.aggregate,
+            },
+        );
+
+        Ok(after.aggregate)
+    }
+}
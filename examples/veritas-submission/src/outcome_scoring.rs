@@ -0,0 +1,151 @@
+//! Outcome scoring - score rewards against the resolved outcome
+//!
+//! FILE PURPOSE:
+//! The bonus applied at submission time measures closeness to the *running*
+//! aggregate, which rewards herding as much as insight. Once a belief resolves,
+//! `settle_rewards` scores every participant against the true outcome instead.
+//!
+//! SCORE RULE:
+//! delta = OUTCOME_SCORE_BONUS × accuracy² / SCALE² (the Quadratic curve of scoring.rs)
+//! where accuracy = SCALE - |value - outcome|, using each agent's latest
+//! submission to the belief. Squaring makes the bonus fall off quickly: with an
+//! outcome of SCALE, 9000 earns 81 points, a 5000 hedge 25 and 2000 only 4.
+//!
+//! DETERMINISM:
+//! Agents are scored in the order of their first submission to the belief,
+//! walking the submission log, so every node applies identical updates.
+//! Each belief is settled once. Voided beliefs have no outcome to score against:
+//! settling one only rebates fees and refunds the pool (see rewards.rs).
+//! Agents that have since deregistered are skipped (reported with a delta of 0).
+//!
+//! ALL-TIME ACCURACY:
+//! `get_agent_accuracy` summarizes an agent's record for reputation displays:
+//! SCALE minus the mean |value - outcome| over every resolved belief it
+//! submitted to. It reads live state only, so archived beliefs drop out.
+//!
+//! REWARD POOL:
+//! The same pass settles submission fees and pays out a funded reward pool
+//! (see rewards.rs), so resolving a belief needs a single settlement call.
+//! A pool nobody earned anything from stays put for `DistributeRewards`.
+//!
+//! SLASHING:
+//! When enabled, the same pass slashes the stake of agents whose final value
+//! was too far from the outcome (see slashing.rs).
+
+use anyhow::{bail, Result};
+use sov_modules_api::{Spec, TxState};
+use veritas_belief::{BeliefId, SCALE};
+
+use crate::{compute_score_delta, ScoreCurve, ScoringParams, SubmissionError, SubmissionModule};
+
+/// Score bonus for a submission that matched the outcome exactly
+pub const OUTCOME_SCORE_BONUS: u64 = 100;
+
+/// Score bonus earned by a final prediction of `value` on a belief that resolved to `outcome`
+pub fn outcome_score_delta(value: u64, outcome: u64) -> u64 {
+    let params = ScoringParams { max_bonus: OUTCOME_SCORE_BONUS, curve: ScoreCurve::Quadratic };
+    compute_score_delta(value, outcome, &params)
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Raises each participant's score by how close they came to the outcome, once
+    ///
+    /// Also slashes badly wrong participants when slashing is enabled, and
+    /// credits the belief's reward pool to the rewards ledger.
+    /// A voided belief is only refunded: no scores, no slashing.
+    /// Calling it again for the same belief does nothing.
+    /// Returns the (agent, delta) pairs applied, in settlement order.
+    pub fn settle_rewards(
+        &mut self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<Vec<(S::Address, u64)>> {
+        if self.scores_settled.get(&belief_id, state)?.unwrap_or(false) {
+            return Ok(Vec::new());
+        }
+
+        let belief = self.belief_module.beliefs.get(&belief_id, state)?
+            .ok_or(SubmissionError::BeliefNotFound { belief_id })?;
+        if belief.voided {
+            self.settle_fees(belief_id, state)?;
+            self.refund_reward_pool(belief_id, state)?;
+            self.scores_settled.set(&belief_id, &true, state)?;
+            return Ok(Vec::new());
+        }
+        let Some(outcome) = belief.outcome else {
+            bail!("Belief {} is not resolved", belief_id);
+        };
+
+        // Latest value per agent, kept in order of first appearance
+        let mut finals: Vec<(S::Address, u64)> = Vec::new();
+        for submission in self.get_submissions(belief_id, state)? {
+            match finals.iter_mut().find(|(agent, _)| *agent == submission.agent) {
+                Some(entry) => entry.1 = submission.value,
+                None => finals.push((submission.agent, submission.value)),
+            }
+        }
+
+        let slashing = self.slashing.get(state)?.unwrap_or_default();
+        let mut applied = Vec::with_capacity(finals.len());
+        for (agent, value) in finals {
+            // Deregistered agents have no score or stake left to touch
+            let Some(record) = self.agent_module.agents.get(&agent, state)? else {
+                applied.push((agent, 0));
+                continue;
+            };
+
+            let delta = outcome_score_delta(value, outcome);
+            if delta > 0 {
+                self.agent_module.update_score(&self.id, agent.clone(), delta, state)?;
+            }
+            let penalty = slashing.penalty_for(record.stake, value, outcome);
+            if penalty > 0 {
+                self.agent_module.slash_agent(&self.id, &agent, penalty, state)?;
+            }
+            applied.push((agent, delta));
+        }
+
+        self.settle_fees(belief_id, state)?;
+        let pool = self.reward_pools.get(&belief_id, state)?.unwrap_or(0);
+        if pool > 0 {
+            self.credit_reward_pool(belief_id, outcome, pool, state)?;
+        }
+        self.scores_settled.set(&belief_id, &true, state)?;
+
+        Ok(applied)
+    }
+
+    /// An agent's all-time accuracy: SCALE minus its mean distance from the outcome
+    ///
+    /// One value per resolved belief (resubmissions replace earlier ones).
+    /// Retracted submissions and voided, open or archived beliefs don't count.
+    /// The mean distance is rounded down.
+    /// Returns None while the agent has no submission to a resolved belief.
+    pub fn get_agent_accuracy(
+        &self,
+        address: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<Option<u64>> {
+        let mut total_distance: u128 = 0;
+        let mut resolved: u128 = 0;
+        for submission in self.get_submissions_by_agent(address, state)? {
+            if submission.retracted {
+                continue;
+            }
+            let Some(belief) = self.belief_module.beliefs.get(&submission.belief_id, state)? else {
+                continue;
+            };
+            let Some(outcome) = belief.outcome else {
+                continue;
+            };
+            total_distance += submission.value.abs_diff(outcome) as u128;
+            resolved += 1;
+        }
+
+        if resolved == 0 {
+            return Ok(None);
+        }
+        // Each distance is at most SCALE, so the mean is too
+        Ok(Some(SCALE - (total_distance / resolved) as u64))
+    }
+}
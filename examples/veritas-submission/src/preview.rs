@@ -0,0 +1,64 @@
+//! Aggregate preview - what a submission would do, before it is signed
+//!
+//! FILE PURPOSE:
+//! Wallets want to tell a user "submitting 0.8 would move the consensus to X"
+//! before the transaction is sent. `preview_aggregate` runs the same checks
+//! and weight pipeline as `submit_belief` (weight from AgentModule, weight cap,
+//! value granularity, resubmission overwrite) and asks BeliefModule for the
+//! aggregate its update would produce, all through `&self`: nothing is written.
+//! Also served as `GET /preview` (see http.rs).
+//!
+//! Only the TotalStake weight basis can be previewed: under StakeAtRisk the
+//! weight depends on an amount the caller hasn't committed yet.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+use veritas_belief::{BeliefId, SCALE};
+
+use crate::{SubmissionError, SubmissionModule, WeightBasis};
+
+/// Aggregate before and after a hypothetical submission
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct AggregatePreview {
+    pub belief_id: BeliefId,
+    pub current_aggregate: u64,
+    pub new_aggregate: u64,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// The aggregate `belief_id` would have if `agent` submitted `value` now
+    ///
+    /// Fails with the same errors the real submission would.
+    pub fn preview_aggregate(
+        &self,
+        belief_id: BeliefId,
+        value: u64,
+        agent: &S::Address,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        if self.paused.get(state)?.unwrap_or(false) {
+            return Err(SubmissionError::Paused.into());
+        }
+        if value > SCALE {
+            return Err(SubmissionError::ValueOutOfRange { value }.into());
+        }
+        self.ensure_can_submit(belief_id, agent, state)?;
+
+        if self.weight_basis.get(state)?.unwrap_or_default() != WeightBasis::TotalStake {
+            bail!("Previews are only available with the total stake weight basis");
+        }
+        let weight = self.agent_module.get_weight(agent, state)
+            .map_err(SubmissionError::from_agent)?;
+        let weight = self.apply_weight_cap(weight, state)?;
+        let value = self.belief_module.normalize_submission_value(belief_id, value, state)?;
+
+        match self.agent_submissions.get(&(agent.clone(), belief_id), state)? {
+            None => self.belief_module.preview_update(belief_id, value, weight, state),
+            Some(position) => {
+                let records = self.records_replacing(belief_id, position, value, weight, state)?;
+                self.belief_module.preview_recompute(belief_id, &records, state)
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Retraction - taking back a submission made by mistake
+//!
+//! FILE PURPOSE:
+//! Each agent has at most one current submission per belief (a resubmission
+//! overwrites it). `retract_submission` withdraws that submission from the
+//! aggregate entirely, as if it had never been made.
+//!
+//! HOW IT WORKS:
+//! - The belief is recomputed from its remaining submissions via
+//!   `BeliefModule::recompute_aggregate`, so aggregate, total weight and
+//!   submission count drop the retracted one exactly, in every weighting mode
+//! - Retracting the only submission returns the aggregate to the belief's initial value
+//! - The log entry stays for audit, flagged `retracted`, but leaves the belief's
+//!   index, so rewards, fee rebates and integrity checks no longer see it
+//! - Like new submissions, retractions need the belief to be open and before its deadline
+//!
+//! WHAT IS NOT UNDONE:
+//! - The submission fee is forfeited to the belief's reward pool
+//! - The accuracy bonus already applied to the agent's score stays
+//! - Stake locks and stake at risk committed by the submission stay until they
+//!   would have been released anyway, so retracting can't be used to free stake early
+
+use anyhow::Result;
+use sov_modules_api::{Context, EventEmitter, Spec, TxState};
+use veritas_belief::{BeliefId, Contribution};
+
+use crate::{Event, SubmissionError, SubmissionModule};
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Removes the sender's current submission to `belief_id` from the aggregate
+    ///
+    /// Returns the belief's new aggregate.
+    pub fn retract_submission(
+        &mut self,
+        belief_id: BeliefId,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        if self.paused.get(state)?.unwrap_or(false) {
+            return Err(SubmissionError::Paused.into());
+        }
+
+        let sender = context.sender();
+        if self.belief_module.beliefs.get(&belief_id, state)?.is_none() {
+            return Err(SubmissionError::BeliefNotFound { belief_id }.into());
+        }
+        let key = (sender.clone(), belief_id);
+        let Some(position) = self.agent_submissions.get(&key, state)? else {
+            return Err(SubmissionError::NothingToRetract { belief_id }.into());
+        };
+        let mut submission = self.submissions.get(position, state)?
+            .ok_or_else(|| anyhow::anyhow!("Submission {} missing from the log", position))?;
+
+        // Everything except the retracted submission, in submission order
+        let mut index = self.belief_submission_index.get(&belief_id, state)?
+            .unwrap_or_default();
+        index.retain(|p| *p != position);
+        let mut records = Vec::with_capacity(index.len());
+        for p in &index {
+            if let Some(remaining) = self.submissions.get(*p, state)? {
+                records.push(Contribution { value: remaining.value, weight: remaining.weight });
+            }
+        }
+
+        // Fails for closed beliefs, before anything is written
+        let new_aggregate = self.belief_module.recompute_aggregate(belief_id, &records, state)?;
+
+        self.belief_submission_index.set(&belief_id, &index, state)?;
+        self.agent_submissions.delete(&key, state)?;
+
+        // The fee sits in this module's account already; only the pool accounting moves
+        if submission.fee > 0 {
+            self.add_to_reward_pool(belief_id, sender, submission.fee, state)?;
+        }
+
+        submission.retracted = true;
+        self.submissions.set(position, &submission, state)?;
+
+        self.emit_event(
+            state,
+            Event::SubmissionRetracted {
+                belief_id,
+                agent: sender.clone(),
+                value: submission.value,
+                weight: submission.weight,
+                new_aggregate,
+            },
+        );
+
+        Ok(new_aggregate)
+    }
+}
@@ -0,0 +1,313 @@
+//! Rewards - per-belief reward pools and the agent rewards ledger
+//!
+//! FILE PURPOSE:
+//! Lets anyone fund a belief with gas tokens and pays that pool out to the
+//! belief's participants once it resolves. Payouts are credited to a ledger
+//! rather than transferred immediately, so an agent active in many markets
+//! collects everything with a single `ClaimAllRewards` bank transfer.
+//!
+//! FLOW:
+//! 1. `FundRewardPool`: tokens move from the sender into this module's account
+//! 2. Belief is resolved in BeliefModule (admin)
+//! 3. `SettleFees` (permissionless, also run by step 4): submission fees of accurate
+//!    forecasters are rebated to the ledger, the others are forfeited to the pool
+//! 4. `DistributeRewards` (permissionless): the pool is split across the belief's
+//!    submissions and credited to `rewards_ledger`. `SettleRewards` (see
+//!    outcome_scoring.rs) runs steps 3 and 4 too, so one call settles everything
+//! 5. `ClaimAllRewards`: the sender's whole ledger balance is transferred out and zeroed
+//!
+//! SPLIT RULE:
+//! share = pool × weight × accuracy / Σ(weight × accuracy)
+//! where accuracy = SCALE - |value - outcome|. Integer division leaves a little
+//! dust, which stays in the pool for a later distribution.
+//!
+//! FEE REBATES:
+//! A submission is accurate if |value - outcome| <= `rebate_threshold`.
+//! Voided beliefs have no outcome, so every fee is rebated.
+//! With `FeeDestination::RewardPool` fees skip all of this: each one is added
+//! to the belief's pool as it is paid and is never rebated.
+//!
+//! VOIDED BELIEFS:
+//! Every pool inflow before settlement (funding, pooled or retracted fees) is
+//! recorded against whoever paid it. A voided belief has no outcome to split
+//! the pool by, so `settle_rewards` refunds each contributor's amount to the
+//! ledger instead, leaving the pool empty.
+
+use anyhow::{bail, Result};
+use schemars::JsonSchema;
+use sov_bank::{config_gas_token_id, Coins, IntoPayable};
+use sov_modules_api::{Amount, Context, Spec, TxState};
+use veritas_belief::{BeliefId, SCALE};
+
+use crate::{SubmissionError, SubmissionModule};
+
+/// Submission fee settings
+#[derive(Clone, Debug, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct FeeConfig {
+    /// Gas tokens charged per submission (0 disables fees)
+    pub submission_fee: u64,
+
+    /// Largest distance from the outcome (SCALE units) that still earns a rebate
+    pub rebate_threshold: u64,
+
+    /// Where paid fees go (held for rebates by default)
+    #[serde(default)]
+    pub destination: FeeDestination,
+}
+
+/// What happens to a submission fee once it is paid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeDestination {
+    /// Held until settlement, then rebated or forfeited to the pool
+    #[default]
+    Rebate,
+    /// Added to the belief's reward pool right away
+    RewardPool,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Moves `amount` gas tokens from the sender into a belief's reward pool
+    pub fn fund_reward_pool(
+        &mut self,
+        belief_id: BeliefId,
+        amount: u64,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if amount == 0 {
+            bail!("Reward amount must be greater than zero");
+        }
+
+        let belief = self.belief_module.beliefs.get(&belief_id, state)?
+            .ok_or(SubmissionError::BeliefNotFound { belief_id })?;
+        if belief.voided {
+            bail!("Belief {} has been voided", belief_id);
+        }
+
+        let coins = Coins {
+            amount: Amount::new(amount as u128),
+            token_id: config_gas_token_id(),
+        };
+        self.bank.transfer_from(context.sender(), self.id.to_payable(), coins, state)?;
+        self.add_to_reward_pool(belief_id, context.sender(), amount, state)?;
+
+        Ok(())
+    }
+
+    /// Adds `amount` (already in this module's account) to a belief's pool on behalf of `contributor`
+    pub(crate) fn add_to_reward_pool(
+        &mut self,
+        belief_id: BeliefId,
+        contributor: &S::Address,
+        amount: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        let pool = self.reward_pools.get(&belief_id, state)?.unwrap_or(0);
+        self.reward_pools.set(&belief_id, &pool.saturating_add(amount), state)?;
+
+        let mut contributions = self.pool_contributions.get(&belief_id, state)?.unwrap_or_default();
+        match contributions.iter_mut().find(|(address, _)| address == contributor) {
+            Some(entry) => entry.1 = entry.1.saturating_add(amount),
+            None => contributions.push((contributor.clone(), amount)),
+        }
+        self.pool_contributions.set(&belief_id, &contributions, state)?;
+
+        Ok(())
+    }
+
+    /// Credits every recorded pool contribution back to its contributor's ledger balance
+    ///
+    /// Used for voided beliefs. Returns the amount refunded.
+    pub(crate) fn refund_reward_pool(
+        &mut self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let contributions = self.pool_contributions.get(&belief_id, state)?.unwrap_or_default();
+        let mut refunded: u64 = 0;
+        for (contributor, amount) in contributions {
+            let balance = self.rewards_ledger.get(&contributor, state)?.unwrap_or(0);
+            self.rewards_ledger.set(&contributor, &balance.saturating_add(amount), state)?;
+            refunded = refunded.saturating_add(amount);
+        }
+
+        let pool = self.reward_pools.get(&belief_id, state)?.unwrap_or(0);
+        self.reward_pools.set(&belief_id, &pool.saturating_sub(refunded), state)?;
+        self.pool_contributions.delete(&belief_id, state)?;
+
+        Ok(refunded)
+    }
+
+    /// Charges the configured submission fee, moving it into this module's account
+    ///
+    /// Fails if the sender can't cover it. Returns the fee held for settlement:
+    /// 0 when fees are disabled or go straight to `belief_id`'s reward pool.
+    pub fn collect_submission_fee(
+        &mut self,
+        belief_id: BeliefId,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let config = self.fee_config.get(state)?.unwrap_or_default();
+        if config.submission_fee == 0 {
+            return Ok(0);
+        }
+
+        let coins = Coins {
+            amount: Amount::new(config.submission_fee as u128),
+            token_id: config_gas_token_id(),
+        };
+        self.bank.transfer_from(context.sender(), self.id.to_payable(), coins, state)?;
+
+        match config.destination {
+            FeeDestination::Rebate => Ok(config.submission_fee),
+            FeeDestination::RewardPool => {
+                self.add_to_reward_pool(belief_id, context.sender(), config.submission_fee, state)?;
+                Ok(0)
+            }
+        }
+    }
+
+    /// Rebates or forfeits the submission fees of a closed belief, once
+    ///
+    /// Accurate submitters get their fee credited to the rewards ledger;
+    /// inaccurate ones' fees are added to the belief's reward pool.
+    /// Calling it again for the same belief does nothing.
+    pub fn settle_fees(
+        &mut self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<()> {
+        if self.fees_settled.get(&belief_id, state)?.unwrap_or(false) {
+            return Ok(());
+        }
+
+        let belief = self.belief_module.beliefs.get(&belief_id, state)?
+            .ok_or(SubmissionError::BeliefNotFound { belief_id })?;
+        if !belief.resolved && !belief.voided {
+            bail!("Belief {} is not resolved", belief_id);
+        }
+
+        let threshold = self.fee_config.get(state)?.unwrap_or_default().rebate_threshold;
+        let mut forfeited: u64 = 0;
+        for submission in self.get_submissions(belief_id, state)? {
+            if submission.fee == 0 {
+                continue;
+            }
+
+            let accurate = match belief.outcome {
+                Some(outcome) => submission.value.abs_diff(outcome) <= threshold,
+                None => true,
+            };
+            if accurate {
+                let balance = self.rewards_ledger.get(&submission.agent, state)?.unwrap_or(0);
+                self.rewards_ledger.set(&submission.agent, &balance.saturating_add(submission.fee), state)?;
+            } else {
+                forfeited = forfeited.saturating_add(submission.fee);
+            }
+        }
+
+        if forfeited > 0 {
+            let pool = self.reward_pools.get(&belief_id, state)?.unwrap_or(0);
+            self.reward_pools.set(&belief_id, &pool.saturating_add(forfeited), state)?;
+        }
+        self.fees_settled.set(&belief_id, &true, state)?;
+
+        Ok(())
+    }
+
+    /// Credits a resolved belief's reward pool to its participants' ledger balances
+    ///
+    /// Settles submission fees first, since forfeited fees feed the pool.
+    /// Returns the total amount credited (the pool minus rounding dust).
+    pub fn distribute_rewards(
+        &mut self,
+        belief_id: BeliefId,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        self.settle_fees(belief_id, state)?;
+
+        let belief = self.belief_module.beliefs.get(&belief_id, state)?
+            .ok_or(SubmissionError::BeliefNotFound { belief_id })?;
+        let Some(outcome) = belief.outcome else {
+            bail!("Belief {} is not resolved", belief_id);
+        };
+
+        let pool = self.reward_pools.get(&belief_id, state)?.unwrap_or(0);
+        if pool == 0 {
+            bail!("Belief {} has no rewards to distribute", belief_id);
+        }
+
+        match self.credit_reward_pool(belief_id, outcome, pool, state)? {
+            Some(distributed) => Ok(distributed),
+            None => bail!("Belief {} has no rewardable submissions", belief_id),
+        }
+    }
+
+    /// Splits `pool` across a resolved belief's submissions by weight × accuracy
+    ///
+    /// Credits the shares to the ledger and keeps the rounding dust in the pool.
+    /// Returns the amount credited, or None (crediting nothing) when every
+    /// submission's points are zero.
+    pub(crate) fn credit_reward_pool(
+        &mut self,
+        belief_id: BeliefId,
+        outcome: u64,
+        pool: u64,
+        state: &mut impl TxState<S>,
+    ) -> Result<Option<u64>> {
+        let submissions = self.get_submissions(belief_id, state)?;
+
+        // u128 intermediates: weight (u64) × accuracy (<= SCALE) summed over many submissions
+        let points: Vec<u128> = submissions
+            .iter()
+            .map(|s| s.weight as u128 * (SCALE - s.value.abs_diff(outcome)) as u128)
+            .collect();
+        let total_points: u128 = points.iter().sum();
+        if total_points == 0 {
+            return Ok(None);
+        }
+
+        let mut distributed: u64 = 0;
+        for (submission, points) in submissions.iter().zip(points) {
+            let share = (pool as u128 * points / total_points) as u64;
+            if share == 0 {
+                continue;
+            }
+
+            let balance = self.rewards_ledger.get(&submission.agent, state)?.unwrap_or(0);
+            self.rewards_ledger.set(&submission.agent, &balance.saturating_add(share), state)?;
+            distributed += share;
+        }
+
+        self.reward_pools.set(&belief_id, &(pool - distributed), state)?;
+
+        Ok(Some(distributed))
+    }
+
+    /// Transfers the sender's entire rewards ledger balance to them and zeroes it
+    ///
+    /// One bank transfer regardless of how many beliefs contributed to the balance.
+    /// Returns the amount claimed.
+    pub fn claim_all_rewards(
+        &mut self,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        let sender = context.sender();
+        let balance = self.rewards_ledger.get(sender, state)?.unwrap_or(0);
+        if balance == 0 {
+            bail!("No rewards to claim");
+        }
+
+        let coins = Coins {
+            amount: Amount::new(balance as u128),
+            token_id: config_gas_token_id(),
+        };
+        self.bank.transfer_from(self.id.to_payable(), sender, coins, state)?;
+        self.rewards_ledger.delete(sender, state)?;
+
+        Ok(balance)
+    }
+}
@@ -0,0 +1,88 @@
+//! Rollback - restoring a belief and its submission log to a snapshot
+//!
+//! FILE PURPOSE:
+//! BeliefModule can restore a belief to one of its snapshots (see veritas_belief's
+//! snapshot.rs), but that alone doesn't last: the submission index still holds
+//! the later submissions, and the next resubmission or retraction replays them
+//! straight back in. `rollback_belief` rewinds both together.
+//!
+//! HOW IT WORKS:
+//! - The snapshot at height H holds the belief as it was before block H changed it,
+//!   so every submission made at H or later is undone
+//! - The belief's index must split cleanly: its first `submission_count` entries
+//!   (the snapshot's) made before H, the rest at H or later. An earlier submission
+//!   overwritten or retracted since H can't be restored, so such rollbacks are refused
+//! - The later entries leave the index and are flagged retracted in the log; their
+//!   fees go to the belief's reward pool, as with a retraction
+//! - Beliefs whose fees, rewards or scores were already settled are refused too:
+//!   payouts, slashing and settlement bonuses can't be taken back
+//!
+//! WHAT IS NOT UNDONE:
+//! Like a retraction, accuracy bonuses already applied to the undone submissions'
+//! agents stay, and so do their stake locks until they would have been released anyway.
+
+use anyhow::{bail, Result};
+use sov_modules_api::{Context, Spec, TxState};
+use veritas_belief::BeliefId;
+
+use crate::{SubmissionError, SubmissionModule};
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Restores a belief to its snapshot at block `height` and drops the
+    /// submissions made since from its index (admin only)
+    ///
+    /// Returns the restored aggregate.
+    pub fn rollback_belief(
+        &mut self,
+        belief_id: BeliefId,
+        height: u64,
+        context: &Context<S>,
+        state: &mut impl TxState<S>,
+    ) -> Result<u64> {
+        self.belief_module.ensure_admin(context.sender(), state)?;
+
+        if self.fees_settled.get(&belief_id, state)?.unwrap_or(false)
+            || self.scores_settled.get(&belief_id, state)?.unwrap_or(false)
+        {
+            return Err(SubmissionError::AlreadySettled { belief_id }.into());
+        }
+        let Some(snapshot) = self.belief_module.snapshots.get(&(belief_id, height), state)? else {
+            bail!("No snapshot of belief {} at block {}", belief_id, height);
+        };
+
+        // Checked in full before anything is written
+        let mut index = self.belief_submission_index.get(&belief_id, state)?
+            .unwrap_or_default();
+        let kept = snapshot.submission_count as usize;
+        if kept > index.len() {
+            return Err(SubmissionError::ChangedSinceSnapshot { belief_id, height }.into());
+        }
+        let mut undone = Vec::with_capacity(index.len() - kept);
+        for (i, position) in index.iter().enumerate() {
+            let submission = self.submissions.get(*position, state)?
+                .ok_or_else(|| anyhow::anyhow!("Submission {} missing from the log", position))?;
+            if (submission.block_height >= height) != (i >= kept) {
+                return Err(SubmissionError::ChangedSinceSnapshot { belief_id, height }.into());
+            }
+            if i >= kept {
+                undone.push((*position, submission));
+            }
+        }
+
+        let aggregate = self.belief_module.rollback_belief(belief_id, height, state)?;
+
+        index.truncate(kept);
+        self.belief_submission_index.set(&belief_id, &index, state)?;
+        for (position, mut submission) in undone {
+            self.agent_submissions.delete(&(submission.agent.clone(), belief_id), state)?;
+            if submission.fee > 0 {
+                self.add_to_reward_pool(belief_id, &submission.agent, submission.fee, state)?;
+            }
+            submission.retracted = true;
+            self.submissions.set(position, &submission, state)?;
+        }
+        self.integrity_violations.delete(&belief_id, state)?;
+
+        Ok(aggregate)
+    }
+}
@@ -0,0 +1,97 @@
+//! Scoring - the accuracy bonus applied to an agent's score when it joins a belief
+//!
+//! FILE PURPOSE:
+//! `submit_belief` raises the submitter's score by how close its value landed
+//! to the new aggregate. The size of that bonus and how fast it falls off with
+//! distance are deployment settings (`ScoringParams`, set at genesis).
+//! Only an agent's first submission to each belief is scored: the new aggregate
+//! already includes the agent's own value, so a lone or dominant agent could
+//! otherwise climb to the maximum score by resubmitting (or retracting and
+//! resubmitting) over and over.
+//!
+//! CURVES (d = distance in SCALE units, at most SCALE):
+//! - Inverse:   max_bonus × SCALE / (SCALE + d)   (half the bonus at d = SCALE)
+//! - Linear:    max_bonus × (SCALE - d) / SCALE   (nothing at d = SCALE)
+//! - Quadratic: max_bonus × (SCALE - d)² / SCALE² (falls off fastest near the reference)
+//!
+//! Integer math only, rounded down, so every node computes identical scores.
+//!
+//! COOLDOWN:
+//! Many quick submissions right at the aggregate would each earn close to the
+//! full bonus. With `score_cooldown_blocks` set, an agent that earned a bonus at
+//! block H earns the next one at H + score_cooldown_blocks at the earliest;
+//! submissions in between still move the aggregate, they just earn nothing.
+//! Outcome-based settlement (outcome_scoring.rs) isn't gated: it pays once per
+//! resolved belief and can't be farmed by submitting often.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+use veritas_belief::SCALE;
+
+use crate::SubmissionModule;
+
+/// Shape of the bonus as the distance to the reference grows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoreCurve {
+    Linear,
+    /// The original Veritas curve
+    #[default]
+    Inverse,
+    Quadratic,
+}
+
+/// Settings for the per-submission accuracy bonus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct ScoringParams {
+    /// Bonus for a value exactly at the reference
+    pub max_bonus: u64,
+
+    pub curve: ScoreCurve,
+}
+
+impl Default for ScoringParams {
+    fn default() -> Self {
+        Self { max_bonus: 100, curve: ScoreCurve::Inverse }
+    }
+}
+
+/// Score bonus for submitting `value` when the reference (e.g. the new aggregate) is `reference`
+pub fn compute_score_delta(value: u64, reference: u64, params: &ScoringParams) -> u64 {
+    let distance = value.abs_diff(reference).min(SCALE) as u128;
+    let max_bonus = params.max_bonus as u128;
+    let scale = SCALE as u128;
+
+    // u128 intermediates: max_bonus × SCALE² overflows u64 for large bonuses
+    let delta = match params.curve {
+        ScoreCurve::Inverse => max_bonus * scale / (scale + distance),
+        ScoreCurve::Linear => max_bonus * (scale - distance) / scale,
+        ScoreCurve::Quadratic => max_bonus * (scale - distance) * (scale - distance) / (scale * scale),
+    };
+    delta as u64
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Whether `agent` may earn an accuracy bonus in the current block
+    pub(crate) fn score_cooldown_over(&self, agent: &S::Address, state: &mut impl TxState<S>) -> Result<bool> {
+        let cooldown = self.score_cooldown_blocks.get(state)?.unwrap_or(0);
+        if cooldown == 0 {
+            return Ok(true);
+        }
+        let Some(last) = self.last_score_height.get(agent, state)? else {
+            return Ok(true);
+        };
+        Ok(self.belief_module.current_block(state)? >= last.saturating_add(cooldown))
+    }
+
+    /// Starts `agent`'s score cooldown at the current block (no-op without a cooldown)
+    pub(crate) fn record_score_earned(&mut self, agent: &S::Address, state: &mut impl TxState<S>) -> Result<()> {
+        if self.score_cooldown_blocks.get(state)?.unwrap_or(0) == 0 {
+            return Ok(());
+        }
+        let height = self.belief_module.current_block(state)?;
+        self.last_score_height.set(agent, &height, state)?;
+        Ok(())
+    }
+}
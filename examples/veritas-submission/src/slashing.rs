@@ -0,0 +1,43 @@
+//! Slashing - stake penalties for predictions that were badly wrong
+//!
+//! FILE PURPOSE:
+//! Score bonuses only ever reward; without a downside, staking costs nothing
+//! but opportunity. With slashing enabled, settling a resolved belief
+//! (`settle_rewards`) also takes a share of the stake of every agent whose
+//! final value ended up more than `threshold` away from the outcome.
+//!
+//! PENALTY RULE:
+//! penalty = stake × penalty_rate / SCALE, using the agent's stake at settlement.
+//! Outcomes are 0 or SCALE, so a threshold of 5000 or more only catches
+//! predictions on the wrong side of 50%. AgentModule never slashes more than
+//! is staked; the slashed stake accumulates in `AgentModule::slashed_pool`.
+//!
+//! Voided beliefs have no outcome and never slash. Each belief is settled once,
+//! so nobody is slashed twice for the same prediction.
+
+use schemars::JsonSchema;
+use veritas_belief::SCALE;
+
+/// Settings for slashing at settlement, disabled by default
+#[derive(Clone, Debug, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SlashingConfig {
+    /// Whether settlement slashes stake at all
+    pub enabled: bool,
+
+    /// Largest distance from the outcome (SCALE units) that is never slashed
+    pub threshold: u64,
+
+    /// Share of the stake slashed beyond the threshold (SCALE units, 2000 = 20%)
+    pub penalty_rate: u64,
+}
+
+impl SlashingConfig {
+    /// Stake to slash from an agent holding `stake` whose final value was `value`
+    pub fn penalty_for(&self, stake: u64, value: u64, outcome: u64) -> u64 {
+        if !self.enabled || value.abs_diff(outcome) <= self.threshold {
+            return 0;
+        }
+        // u128 intermediate: stake × rate can exceed u64; the rate is capped at 100%
+        (stake as u128 * self.penalty_rate.min(SCALE) as u128 / SCALE as u128) as u64
+    }
+}
@@ -0,0 +1,51 @@
+//! System stats - participation totals for operators
+//!
+//! FILE PURPOSE:
+//! Dashboards want headline numbers without scanning every agent, belief or
+//! submission. Each total here is a counter maintained incrementally by the
+//! owning module, so `get_system_stats` is a handful of reads whatever the
+//! size of the state. Also served as `GET /stats` (see http.rs).
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::{Spec, TxState};
+
+use crate::SubmissionModule;
+
+/// Participation totals across the three Veritas modules
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct SystemStats {
+    /// Currently registered agents
+    pub total_agents: u64,
+
+    /// Belief IDs issued so far, binary and categorical (archived ones included)
+    pub total_beliefs: u64,
+
+    /// Accepted submissions, resubmissions and categorical ones included
+    /// Retractions don't lower it
+    pub total_submissions: u64,
+
+    /// Stake held by all registered agents
+    pub total_stake: u128,
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Reads the participation counters of all three modules
+    pub fn get_system_stats(&self, state: &mut impl TxState<S>) -> Result<SystemStats> {
+        let next_belief_id = self.belief_module.next_belief_id.get(state)?.unwrap_or(1);
+
+        Ok(SystemStats {
+            total_agents: self.agent_module.total_agents.get(state)?.unwrap_or(0),
+            total_beliefs: next_belief_id.saturating_sub(1),
+            total_submissions: self.total_submissions.get(state)?.unwrap_or(0),
+            total_stake: self.agent_module.total_stake.get(state)?.unwrap_or(0),
+        })
+    }
+
+    /// Adds `count` accepted submissions to `total_submissions`
+    pub(crate) fn count_submissions(&mut self, count: u64, state: &mut impl TxState<S>) -> Result<()> {
+        let total = self.total_submissions.get(state)?.unwrap_or(0);
+        self.total_submissions.set(&total.saturating_add(count), state)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//! Weight basis - what part of an agent's stake a submission's weight derives from
+//!
+//! FILE PURPOSE:
+//! By default a submission weighs the agent's whole stake × score, whatever
+//! the belief. In `StakeAtRisk` mode the agent instead names an amount of
+//! free stake with each submission (`SubmitBeliefAtRisk`); that amount × score
+//! becomes the weight, and AgentModule locks it until the belief is closed.
+//! Influence then scales with what the agent actually puts on the line per belief.
+
+use schemars::JsonSchema;
+
+/// Which stake a submission's weight is based on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightBasis {
+    /// The agent's entire stake (plain `SubmitBelief`)
+    #[default]
+    TotalStake,
+    /// An explicit, locked amount per submission (`SubmitBeliefAtRisk`)
+    StakeAtRisk,
+}
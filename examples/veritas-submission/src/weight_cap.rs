@@ -0,0 +1,75 @@
+//! Weight cap - bounds a single submission's share of total registered weight
+//!
+//! FILE PURPOSE:
+//! A newly funded whale could otherwise move an aggregate in one shot.
+//! When enabled, a submission whose weight exceeds `max_fraction` of the
+//! total registered weight (AgentModule's running sum of stake × score,
+//! the submitter included) is either rejected or scaled down to the cap.
+
+use anyhow::Result;
+use schemars::JsonSchema;
+use sov_modules_api::macros::{serialize, UniversalWallet};
+use sov_modules_api::{Spec, TxState};
+use veritas_belief::SCALE;
+
+use crate::{SubmissionError, SubmissionModule};
+
+/// What happens to a submission above the cap
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, UniversalWallet)]
+#[serialize(Borsh, Serde)]
+#[serde(rename_all = "snake_case")]
+pub enum WeightCapMode {
+    /// Reject the submission
+    #[default]
+    Reject,
+    /// Accept it with its weight reduced to the cap
+    Scale,
+}
+
+/// Settings for the per-submission weight cap
+#[derive(Clone, Debug, PartialEq, Eq, borsh::BorshSerialize, borsh::BorshDeserialize, serde::Serialize, serde::Deserialize, JsonSchema)]
+pub struct WeightCapConfig {
+    /// Whether the cap is enforced at all
+    pub enabled: bool,
+
+    /// Largest allowed share of total registered weight, in SCALE units
+    /// (2500 = a single submission may carry at most 25% of the total)
+    pub max_fraction: u64,
+
+    pub mode: WeightCapMode,
+}
+
+impl Default for WeightCapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_fraction: SCALE,
+            mode: WeightCapMode::default(),
+        }
+    }
+}
+
+impl<S: Spec> SubmissionModule<S> {
+    /// Applies the configured weight cap to a submission's weight
+    ///
+    /// Returns the weight to aggregate with (unchanged when under the cap),
+    /// or `SubmissionError::WeightAboveCap` in Reject mode.
+    pub fn apply_weight_cap(&self, weight: u64, state: &mut impl TxState<S>) -> Result<u64> {
+        let config = self.weight_cap.get(state)?.unwrap_or_default();
+        if !config.enabled {
+            return Ok(weight);
+        }
+
+        let total = self.agent_module.total_registered_weight.get(state)?.unwrap_or(0);
+        let cap = total.saturating_mul(config.max_fraction as u128) / SCALE as u128;
+        if weight as u128 <= cap {
+            return Ok(weight);
+        }
+
+        match config.mode {
+            WeightCapMode::Reject => Err(SubmissionError::WeightAboveCap { weight, cap: cap as u64 }.into()),
+            // cap < weight, so it fits in a u64
+            WeightCapMode::Scale => Ok(cap as u64),
+        }
+    }
+}
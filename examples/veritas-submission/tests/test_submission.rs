@@ -0,0 +1,1688 @@
+#[cfg(test)]
+mod tests {
+    use veritas_agent::{AgentError, AgentTier, StakeLockConfig, TierThresholds};
+    use veritas_belief::{BeliefError, BeliefModule, BeliefOptions, SnapshotConfig, SCALE};
+    use veritas_submission::{
+        compute_score_delta, submission_commitment, CallMessage, CollusionDetectionConfig, CommitRevealWindow, Event, IntegritySweepConfig, IntegrityTotals, ScoreCurve, ScoringParams,
+        SlashingConfig, Submission, SubmissionError, SubmissionModule, SystemStats, VeritasError, WeightBasis, WeightCapConfig,
+        WeightCapMode,
+    };
+    use sov_modules_api::test_utils::generate_address;
+    use sov_modules_api::capabilities::RollupHeight;
+    use sov_modules_api::{Context, Module, WorkingSet};
+    use sov_test_utils::TestSpec;
+
+    type S = TestSpec;
+
+    /// Moves ChainState, and so every Veritas height check, to rollup height `height`
+    fn set_height(module: &BeliefModule<S>, height: u64, working_set: &mut WorkingSet<S>) {
+        module.chain_state.rollup_height.set(&RollupHeight::new(height), working_set).unwrap();
+    }
+
+    /// Registers an agent through AgentModule's public CallMessage
+    fn register_agent(
+        module: &mut SubmissionModule<S>,
+        name: &str,
+        stake: u64,
+        working_set: &mut WorkingSet<S>,
+    ) -> Context<S> {
+        let sender = generate_address::<S>(name);
+        let context = Context::new(sender, Default::default(), 1);
+        module.agent_module.call(
+            veritas_agent::CallMessage::RegisterAgent { initial_stake: stake },
+            &context,
+            working_set,
+        ).unwrap();
+        context
+    }
+
+    #[test]
+    fn test_integrity_sweep_flags_injected_inconsistency() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        // Check one belief per "block" so the rotation is observable
+        module.integrity_sweep.set(
+            &IntegritySweepConfig { enabled: true, beliefs_per_block: 1, ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+
+        for question in ["Q1", "Q2", "Q3"] {
+            module.belief_module
+                .create_belief(question.to_string(), 5000, &mut working_set)
+                .unwrap();
+        }
+
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        module.call(
+            CallMessage::SubmitBelief { belief_id: 3, value: 7000 },
+            &alice,
+            &mut working_set,
+        ).unwrap();
+
+        // Healthy state: a full rotation finds nothing
+        for _ in 0..3 {
+            assert!(module.run_integrity_sweep(&mut working_set).unwrap().is_empty());
+        }
+
+        // Inject drift: inflate belief 3's total_weight behind the log's back
+        let mut belief = module.belief_module.beliefs.get(&3, &mut working_set).unwrap().unwrap();
+        belief.total_weight += 1;
+        module.belief_module.beliefs.set(&3, &belief, &mut working_set).unwrap();
+
+        // The sweep must flag it within one rotation (3 blocks)
+        let mut flagged = Vec::new();
+        for _ in 0..3 {
+            flagged.extend(module.run_integrity_sweep(&mut working_set).unwrap());
+        }
+
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].belief_id, 3);
+        assert_eq!(flagged[0].stored_total_weight, flagged[0].recorded_total_weight + 1);
+
+        let recorded = module.integrity_violations.get(&3, &mut working_set).unwrap();
+        assert_eq!(recorded, Some(flagged[0].clone()));
+    }
+
+    #[test]
+    fn test_integrity_sweep_detects_count_mismatch() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        module.integrity_sweep.set(
+            &IntegritySweepConfig { enabled: true, beliefs_per_block: 4, ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, &mut working_set)
+            .unwrap();
+
+        // A submission count with no backing index entry
+        module.belief_module.submission_counts.set(&1, &1, &mut working_set).unwrap();
+
+        let violations = module.run_integrity_sweep(&mut working_set).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].stored_submission_count, 1);
+        assert_eq!(violations[0].indexed_submission_count, 0);
+    }
+
+    #[test]
+    fn test_integrity_sweep_disabled_by_default() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, &mut working_set)
+            .unwrap();
+        module.belief_module.submission_counts.set(&1, &7, &mut working_set).unwrap();
+
+        assert!(module.run_integrity_sweep(&mut working_set).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_integrity_violation_auto_pauses_submissions() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = migration_target(&mut working_set);
+        let alice = Context::new(generate_address::<S>("alice"), Default::default(), 1);
+        module.integrity_sweep.set(
+            &IntegritySweepConfig { enabled: true, beliefs_per_block: 1, auto_pause: true },
+            &mut working_set,
+        ).unwrap();
+        let submit = |module: &mut SubmissionModule<S>, working_set: &mut WorkingSet<S>| {
+            module.call(CallMessage::SubmitBelief { belief_id: 2, value: 6000 }, &alice, working_set)
+        };
+
+        submit(&mut module, &mut working_set).unwrap();
+        assert!(module.run_integrity_sweep(&mut working_set).unwrap().is_empty());
+        assert!(module.run_integrity_sweep(&mut working_set).unwrap().is_empty());
+
+        // Out-of-range aggregate on belief 1, found within one rotation (2 beliefs, 1 per block)
+        let mut belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        belief.aggregate = SCALE + 1;
+        module.belief_module.beliefs.set(&1, &belief, &mut working_set).unwrap();
+        let mut found = Vec::new();
+        for _ in 0..2 {
+            found.extend(module.run_integrity_sweep(&mut working_set).unwrap());
+        }
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].aggregate, SCALE + 1);
+
+        // Every belief is closed to submissions, not just the broken one
+        assert_eq!(module.paused.get(&mut working_set).unwrap(), Some(true));
+        let err = submit(&mut module, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::Paused));
+
+        // Only the admin can resume
+        assert!(module.call(CallMessage::SetPaused { paused: false }, &alice, &mut working_set).is_err());
+        module.call(CallMessage::SetPaused { paused: false }, &admin, &mut working_set).unwrap();
+        submit(&mut module, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_admin_pause_stops_submissions_and_imports() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = migration_target(&mut working_set);
+        let alice = Context::new(generate_address::<S>("alice"), Default::default(), 1);
+        let submit = |module: &mut SubmissionModule<S>, working_set: &mut WorkingSet<S>| {
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value: 6000 }, &alice, working_set)
+        };
+
+        // Only the admin holds the switch
+        assert!(module.call(CallMessage::SetPaused { paused: true }, &alice, &mut working_set).is_err());
+        assert_eq!(module.paused.get(&mut working_set).unwrap(), None);
+        module.call(CallMessage::SetPaused { paused: true }, &admin, &mut working_set).unwrap();
+
+        let err = submit(&mut module, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::Paused));
+        let record = Submission::<S> {
+            agent: generate_address::<S>("bob"),
+            belief_id: 2,
+            value: 5000,
+            weight: 100,
+            timestamp: 0,
+            block_height: 0,
+            fee: 0,
+            retracted: false,
+        };
+        let err = module.import_submissions(vec![record], true, &admin, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::Paused));
+
+        // Reads still work
+        assert!(module.get_all_submissions(&mut working_set).unwrap().is_empty());
+        assert_eq!(module.belief_module.get_belief_state(1, &mut working_set).unwrap().aggregate, 5000);
+
+        module.call(CallMessage::SetPaused { paused: false }, &admin, &mut working_set).unwrap();
+        submit(&mut module, &mut working_set).unwrap();
+        assert_eq!(module.get_all_submissions(&mut working_set).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_integrity_violation_without_auto_pause() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.integrity_sweep.set(
+            &IntegritySweepConfig { enabled: true, beliefs_per_block: 4, auto_pause: false },
+            &mut working_set,
+        ).unwrap();
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, &mut working_set)
+            .unwrap();
+        module.belief_module.submission_counts.set(&1, &1, &mut working_set).unwrap();
+        working_set.take_events();
+
+        assert_eq!(module.run_integrity_sweep(&mut working_set).unwrap().len(), 1);
+        assert_eq!(module.paused.get(&mut working_set).unwrap(), None);
+
+        // Reported on-chain even though nothing was paused
+        let events: Vec<_> = working_set.take_events()
+            .into_iter()
+            .filter_map(|event| event.downcast::<Event<S>>())
+            .collect();
+        let zero_weight = |submission_count| IntegrityTotals { total_weight: 0, submission_count };
+        assert_eq!(
+            events,
+            vec![Event::IntegrityViolationDetected {
+                belief_id: 1,
+                expected: zero_weight(0),
+                actual: zero_weight(1),
+                aggregate: 5000,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_repair_belief_restores_corrupted_totals() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = migration_target(&mut working_set);
+        let alice = Context::new(generate_address::<S>("alice"), Default::default(), 1);
+        let bob = Context::new(generate_address::<S>("bob"), Default::default(), 1);
+        for (context, value) in [(&alice, 8000), (&bob, 2000), (&alice, 9000)] {
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, context, &mut working_set).unwrap();
+        }
+        let expected = module.belief_module.get_belief_state(1, &mut working_set).unwrap();
+
+        // Corrupt every derived total
+        let mut belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        belief.aggregate = 100;
+        belief.total_weight += 12345;
+        module.belief_module.beliefs.set(&1, &belief, &mut working_set).unwrap();
+        module.belief_module.submission_counts.set(&1, &9, &mut working_set).unwrap();
+        assert!(module.check_belief_integrity(1, &mut working_set).unwrap().is_some());
+
+        // Only the admin may repair
+        assert!(module.call(CallMessage::RepairBelief { belief_id: 1 }, &alice, &mut working_set).is_err());
+
+        module.call(CallMessage::RepairBelief { belief_id: 1 }, &admin, &mut working_set).unwrap();
+        let repaired = module.belief_module.get_belief_state(1, &mut working_set).unwrap();
+        assert_eq!(
+            (repaired.aggregate, repaired.total_weight, repaired.submission_count),
+            (expected.aggregate, expected.total_weight, expected.submission_count)
+        );
+        assert!(module.check_belief_integrity(1, &mut working_set).unwrap().is_none());
+
+        // A belief without submissions keeps its aggregate
+        module.repair_belief(2, &admin, &mut working_set).unwrap();
+        let untouched = module.belief_module.get_belief_state(2, &mut working_set).unwrap();
+        assert_eq!((untouched.aggregate, untouched.total_weight, untouched.submission_count), (5000, 0, 0));
+    }
+
+    /// Submits `value` to `belief_id` and returns the typed error, if any
+    fn submission_error(
+        module: &mut SubmissionModule<S>,
+        context: &Context<S>,
+        belief_id: u64,
+        working_set: &mut WorkingSet<S>,
+    ) -> Option<SubmissionError> {
+        module
+            .call(CallMessage::SubmitBelief { belief_id, value: 5000 }, context, working_set)
+            .err()
+            .map(|e| e.downcast_ref::<SubmissionError>().cloned().expect("expected a SubmissionError"))
+    }
+
+    #[test]
+    fn test_submission_after_deadline_is_closed() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module.create_belief_with_options(
+            "Q1".to_string(),
+            5000,
+            BeliefOptions { deadline_block: Some(10), ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+
+        // The deadline block itself still accepts submissions
+        set_height(&module.belief_module, 10, &mut working_set);
+        assert_eq!(submission_error(&mut module, &alice, 1, &mut working_set), None);
+
+        set_height(&module.belief_module, 11, &mut working_set);
+        assert_eq!(
+            submission_error(&mut module, &alice, 1, &mut working_set),
+            Some(SubmissionError::BeliefClosed { belief_id: 1, deadline_block: 10 })
+        );
+    }
+
+    #[test]
+    fn test_whole_percent_tick_size() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let whole_percent = BeliefOptions { value_step: 100, ..Default::default() };
+        module.belief_module
+            .create_belief_with_options("Q1".to_string(), 5000, whole_percent, &mut working_set)
+            .unwrap();
+        module.belief_module.create_belief("Q2".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let submit = |module: &mut SubmissionModule<S>, belief_id, value, working_set: &mut WorkingSet<S>| {
+            module.call(CallMessage::SubmitBelief { belief_id, value }, &alice, working_set)
+        };
+
+        let err = submit(&mut module, 1, 7525, &mut working_set).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<BeliefError>(),
+            Some(&BeliefError::ValueOffStep { belief_id: 1, value: 7525, value_step: 100 })
+        );
+        assert!(module.get_all_submissions(&mut working_set).unwrap().is_empty());
+        submit(&mut module, 1, 7500, &mut working_set).unwrap();
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 7500);
+
+        // The default tick of 1 keeps full precision
+        submit(&mut module, 2, 7525, &mut working_set).unwrap();
+        assert_eq!(module.belief_module.beliefs.get(&2, &mut working_set).unwrap().unwrap().aggregate, 7525);
+    }
+
+    #[test]
+    fn test_missing_agent_and_belief_errors() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, &mut working_set)
+            .unwrap();
+        let registered = register_agent(&mut module, "registered", 1000, &mut working_set);
+        let stranger = Context::new(generate_address::<S>("stranger"), Default::default(), 1);
+
+        // Neither exists: the belief error takes precedence
+        assert_eq!(
+            submission_error(&mut module, &stranger, 42, &mut working_set),
+            Some(SubmissionError::BeliefNotFound { belief_id: 42 })
+        );
+
+        // Only the belief is missing
+        assert_eq!(
+            submission_error(&mut module, &registered, 42, &mut working_set),
+            Some(SubmissionError::BeliefNotFound { belief_id: 42 })
+        );
+
+        // Only the agent is missing
+        assert_eq!(
+            submission_error(&mut module, &stranger, 1, &mut working_set),
+            Some(SubmissionError::AgentNotRegistered)
+        );
+
+        // Both exist
+        assert_eq!(submission_error(&mut module, &registered, 1, &mut working_set), None);
+    }
+
+    #[test]
+    fn test_veritas_error_variants() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, &mut working_set)
+            .unwrap();
+        let registered = register_agent(&mut module, "registered", 1000, &mut working_set);
+        let stranger = Context::new(generate_address::<S>("stranger"), Default::default(), 1);
+        let resolve = |belief_id| veritas_belief::CallMessage::ResolveBelief { belief_id, outcome: true };
+
+        let error = module.call(CallMessage::SubmitBelief { belief_id: 1, value: SCALE + 1 }, &registered, &mut working_set)
+            .unwrap_err();
+        assert_eq!(
+            VeritasError::from_anyhow(&error),
+            Some(VeritasError::Submission(SubmissionError::ValueOutOfRange { value: SCALE + 1 }))
+        );
+
+        let error = module.agent_module
+            .call(veritas_agent::CallMessage::AddStake { amount: 10 }, &stranger, &mut working_set)
+            .unwrap_err();
+        assert_eq!(VeritasError::from_anyhow(&error), Some(VeritasError::Agent(AgentError::NotRegistered)));
+
+        let error = module.belief_module.call(resolve(1), &stranger, &mut working_set).unwrap_err();
+        assert_eq!(VeritasError::from_anyhow(&error), Some(VeritasError::Belief(BeliefError::NoAdmin)));
+
+        module.belief_module.admin.set(registered.sender(), &mut working_set).unwrap();
+        let error = module.belief_module.call(resolve(1), &stranger, &mut working_set).unwrap_err();
+        assert_eq!(VeritasError::from_anyhow(&error), Some(VeritasError::Belief(BeliefError::NotAdmin)));
+        let error = module.belief_module.call(resolve(9), &registered, &mut working_set).unwrap_err();
+        assert_eq!(
+            VeritasError::from_anyhow(&error),
+            Some(VeritasError::Belief(BeliefError::NotFound { belief_id: 9 }))
+        );
+
+        module.belief_module.call(resolve(1), &registered, &mut working_set).unwrap();
+        let error = module.belief_module.call(resolve(1), &registered, &mut working_set).unwrap_err();
+        assert_eq!(
+            VeritasError::from_anyhow(&error),
+            Some(VeritasError::Belief(BeliefError::Resolved { belief_id: 1 }))
+        );
+        let error = module.call(CallMessage::SettleRewards { belief_id: 9 }, &registered, &mut working_set)
+            .unwrap_err();
+        assert_eq!(
+            VeritasError::from_anyhow(&error),
+            Some(VeritasError::Submission(SubmissionError::BeliefNotFound { belief_id: 9 }))
+        );
+    }
+
+    /// Enables stake locks and returns an agent that added stake, submitted to belief 1
+    /// and now tries to withdraw everything it added (a stake cycle)
+    fn stake_cycle_setup(
+        module: &mut SubmissionModule<S>,
+        lock_blocks: u64,
+        working_set: &mut WorkingSet<S>,
+    ) -> Context<S> {
+        module.agent_module.stake_lock.set(
+            &StakeLockConfig { enabled: true, lock_blocks },
+            working_set,
+        ).unwrap();
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, working_set)
+            .unwrap();
+
+        let attacker = register_agent(module, "attacker", 100, working_set);
+        module.agent_module.call(
+            veritas_agent::CallMessage::AddStake { amount: 9900 },
+            &attacker,
+            working_set,
+        ).unwrap();
+        module.call(
+            CallMessage::SubmitBelief { belief_id: 1, value: 10000 },
+            &attacker,
+            working_set,
+        ).unwrap();
+        attacker
+    }
+
+    fn withdraw(
+        module: &mut SubmissionModule<S>,
+        context: &Context<S>,
+        amount: u64,
+        working_set: &mut WorkingSet<S>,
+    ) -> anyhow::Result<()> {
+        module.agent_module.call(
+            veritas_agent::CallMessage::WithdrawStake { amount },
+            context,
+            working_set,
+        )
+    }
+
+    #[test]
+    fn test_stake_cycle_blocked_until_resolution() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let attacker = stake_cycle_setup(&mut module, 1000, &mut working_set);
+
+        // The whole stake backed the submission, not even the original 100 can leave
+        let result = withdraw(&mut module, &attacker, 9900, &mut working_set);
+        assert!(result.unwrap_err().to_string().contains("Stake is locked"));
+        assert!(withdraw(&mut module, &attacker, 1, &mut working_set).is_err());
+
+        // Stake added after the submission is free
+        module.agent_module.call(
+            veritas_agent::CallMessage::AddStake { amount: 500 },
+            &attacker,
+            &mut working_set,
+        ).unwrap();
+        withdraw(&mut module, &attacker, 500, &mut working_set).unwrap();
+
+        // Resolution releases the lock
+        module.belief_module.resolve_belief(1, true, &mut working_set).unwrap();
+        withdraw(&mut module, &attacker, 9900, &mut working_set).unwrap();
+        assert!(module.agent_module.stake_commitments.get(attacker.sender(), &mut working_set).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_stake_lock_expires_after_timeout() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let attacker = stake_cycle_setup(&mut module, 10, &mut working_set);
+
+        set_height(&module.belief_module, 9, &mut working_set);
+        assert!(withdraw(&mut module, &attacker, 9900, &mut working_set).is_err());
+
+        // Belief still open, but the holding period is over
+        set_height(&module.belief_module, 10, &mut working_set);
+        withdraw(&mut module, &attacker, 9900, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_stake_lock_disabled_by_default() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, &mut working_set)
+            .unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        module.call(
+            CallMessage::SubmitBelief { belief_id: 1, value: 7000 },
+            &alice,
+            &mut working_set,
+        ).unwrap();
+
+        withdraw(&mut module, &alice, 1000, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_withdrawal_cooldown_after_submission() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.agent_module.withdrawal_cooldown.set(&10, &mut working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+
+        // Nothing submitted yet: no cooldown
+        withdraw(&mut module, &alice, 100, &mut working_set).unwrap();
+
+        set_height(&module.belief_module, 5, &mut working_set);
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 7000 }, &alice, &mut working_set).unwrap();
+        assert_eq!(module.agent_module.locked_until.get(alice.sender(), &mut working_set).unwrap(), Some(15));
+
+        set_height(&module.belief_module, 14, &mut working_set);
+        let err = withdraw(&mut module, &alice, 100, &mut working_set).unwrap_err();
+        assert!(err.to_string().contains("until block 15"));
+
+        set_height(&module.belief_module, 15, &mut working_set);
+        withdraw(&mut module, &alice, 100, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_stake_at_risk_sets_weight_and_locks_amount() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.weight_basis.set(&WeightBasis::StakeAtRisk, &mut working_set).unwrap();
+        for question in ["Q1", "Q2"] {
+            module.belief_module
+                .create_belief(question.to_string(), 5000, &mut working_set)
+                .unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+
+        // Plain submissions must name an amount in this mode
+        assert!(module.call(
+            CallMessage::SubmitBelief { belief_id: 1, value: 8000 },
+            &alice,
+            &mut working_set,
+        ).is_err());
+
+        // Weight is the committed amount × score (100), not the whole stake
+        module.call(
+            CallMessage::SubmitBeliefAtRisk { belief_id: 1, value: 8000, stake_at_risk: 300 },
+            &alice,
+            &mut working_set,
+        ).unwrap();
+        let submissions = module.get_submissions(1, &mut working_set).unwrap();
+        assert_eq!(submissions[0].weight, 300 * 100);
+        let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.total_weight, 300 * 100);
+
+        // Only the remaining 700 is free, for other beliefs and for withdrawal
+        assert!(module.call(
+            CallMessage::SubmitBeliefAtRisk { belief_id: 2, value: 3000, stake_at_risk: 800 },
+            &alice,
+            &mut working_set,
+        ).is_err());
+        assert!(withdraw(&mut module, &alice, 701, &mut working_set).is_err());
+        withdraw(&mut module, &alice, 700, &mut working_set).unwrap();
+
+        // Resolution releases the amount
+        module.belief_module.resolve_belief(1, true, &mut working_set).unwrap();
+        withdraw(&mut module, &alice, 300, &mut working_set).unwrap();
+    }
+
+    /// Fresh module with two beliefs, an admin and two registered agents
+    fn migration_target(working_set: &mut WorkingSet<S>) -> (SubmissionModule<S>, Context<S>) {
+        let mut module = SubmissionModule::<S>::default();
+        let admin = Context::new(generate_address::<S>("admin"), Default::default(), 1);
+        module.belief_module.admin.set(admin.sender(), working_set).unwrap();
+
+        for question in ["Q1", "Q2"] {
+            module.belief_module
+                .create_belief(question.to_string(), 5000, working_set)
+                .unwrap();
+        }
+        register_agent(&mut module, "alice", 1000, working_set);
+        register_agent(&mut module, "bob", 3000, working_set);
+
+        (module, admin)
+    }
+
+    #[test]
+    fn test_import_with_recompute_matches_replay() {
+        // Old chain: submissions made live
+        let mut old_set = WorkingSet::<S>::new(Default::default());
+        let (mut old, _) = migration_target(&mut old_set);
+        let alice = Context::new(generate_address::<S>("alice"), Default::default(), 1);
+        let bob = Context::new(generate_address::<S>("bob"), Default::default(), 1);
+        for (context, belief_id, value) in [(&alice, 1, 8000), (&bob, 1, 2000), (&bob, 2, 6500), (&alice, 1, 9000)] {
+            old.call(CallMessage::SubmitBelief { belief_id, value }, context, &mut old_set).unwrap();
+        }
+        let records = old.get_all_submissions(&mut old_set).unwrap();
+
+        // New chain: same beliefs and agents, history re-ingested
+        let mut new_set = WorkingSet::<S>::new(Default::default());
+        let (mut new, admin) = migration_target(&mut new_set);
+        let imported = new.import_submissions(records.clone(), true, &admin, &mut new_set).unwrap();
+        // Alice's second submission to belief 1 replaced her first
+        assert_eq!(imported, 3);
+
+        for belief_id in [1, 2] {
+            let expected = old.belief_module.get_belief_state(belief_id, &mut old_set).unwrap();
+            let actual = new.belief_module.get_belief_state(belief_id, &mut new_set).unwrap();
+            assert_eq!(
+                (actual.aggregate, actual.total_weight, actual.submission_count),
+                (expected.aggregate, expected.total_weight, expected.submission_count)
+            );
+            assert_eq!(
+                new.get_submissions(belief_id, &mut new_set).unwrap().len(),
+                old.get_submissions(belief_id, &mut old_set).unwrap().len()
+            );
+        }
+
+        // Without recompute only the log is restored
+        let mut log_set = WorkingSet::<S>::new(Default::default());
+        let (mut log_only, admin) = migration_target(&mut log_set);
+        log_only.import_submissions(records, false, &admin, &mut log_set).unwrap();
+        let belief = log_only.belief_module.get_belief_state(1, &mut log_set).unwrap();
+        assert_eq!((belief.aggregate, belief.total_weight), (5000, 0));
+        assert_eq!(log_only.get_all_submissions(&mut log_set).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_batch_import_matches_record_by_record() {
+        let mut live_set = WorkingSet::<S>::new(Default::default());
+        let (mut live, _) = migration_target(&mut live_set);
+        let alice = Context::new(generate_address::<S>("alice"), Default::default(), 1);
+        let bob = Context::new(generate_address::<S>("bob"), Default::default(), 1);
+        for (context, belief_id, value) in [(&alice, 1, 8000), (&alice, 2, 3000), (&bob, 1, 2000), (&bob, 2, 6500)] {
+            live.call(CallMessage::SubmitBelief { belief_id, value }, context, &mut live_set).unwrap();
+        }
+        let records = live.get_all_submissions(&mut live_set).unwrap();
+
+        // One batch sharing agents and beliefs across records...
+        let mut batch_set = WorkingSet::<S>::new(Default::default());
+        let (mut batch, admin) = migration_target(&mut batch_set);
+        batch.import_submissions(records.clone(), true, &admin, &mut batch_set).unwrap();
+
+        // ...ends up exactly like importing one record at a time
+        let mut single_set = WorkingSet::<S>::new(Default::default());
+        let (mut single, admin) = migration_target(&mut single_set);
+        for record in &records {
+            single.import_submissions(vec![record.clone()], true, &admin, &mut single_set).unwrap();
+        }
+
+        for belief_id in [1, 2] {
+            let expected = single.belief_module.get_belief_state(belief_id, &mut single_set).unwrap();
+            let actual = batch.belief_module.get_belief_state(belief_id, &mut batch_set).unwrap();
+            assert_eq!(
+                (actual.aggregate, actual.total_weight, actual.submission_count),
+                (expected.aggregate, expected.total_weight, expected.submission_count)
+            );
+        }
+        let summary = |submissions: Vec<Submission<S>>| -> Vec<_> {
+            submissions.into_iter().map(|s| (s.agent, s.belief_id, s.value, s.weight)).collect()
+        };
+        assert_eq!(
+            summary(batch.get_all_submissions(&mut batch_set).unwrap()),
+            summary(single.get_all_submissions(&mut single_set).unwrap()),
+        );
+    }
+
+    #[test]
+    fn test_import_validates_every_record() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, admin) = migration_target(&mut working_set);
+
+        let record = |agent: &str, belief_id| Submission::<S> {
+            agent: generate_address::<S>(agent),
+            belief_id,
+            value: 5000,
+            weight: 100,
+            timestamp: 0,
+            block_height: 0,
+            fee: 0,
+            retracted: false,
+        };
+
+        // Non-admins can't import
+        let alice = Context::new(generate_address::<S>("alice"), Default::default(), 1);
+        let import = |records: Vec<Submission<S>>| CallMessage::ImportSubmissions { records, recompute: true };
+        assert!(module.call(import(vec![record("alice", 1)]), &alice, &mut working_set).is_err());
+
+        // One bad record rejects the whole batch before anything is written
+        let err = module
+            .import_submissions(vec![record("alice", 1), record("alice", 9)], true, &admin, &mut working_set)
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::BeliefNotFound { belief_id: 9 }));
+
+        let err = module
+            .import_submissions(vec![record("mallory", 1)], true, &admin, &mut working_set)
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::AgentNotRegistered));
+
+        // Two live submissions by one agent to one belief, within the batch
+        let err = module
+            .import_submissions(vec![record("alice", 1), record("alice", 1)], true, &admin, &mut working_set)
+            .unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::AlreadySubmitted { belief_id: 1 }));
+
+        assert!(module.get_all_submissions(&mut working_set).unwrap().is_empty());
+        assert_eq!(module.belief_module.get_belief_state(1, &mut working_set).unwrap().total_weight, 0);
+
+        // Or against one already in state: its weight would be counted twice
+        module.call(import(vec![record("alice", 1)]), &admin, &mut working_set).unwrap();
+        let err = module.call(import(vec![record("alice", 1)]), &admin, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::AlreadySubmitted { belief_id: 1 }));
+        assert_eq!(module.belief_module.get_belief_state(1, &mut working_set).unwrap().total_weight, 100);
+
+        // Retracted history for the same pair is still accepted
+        let retracted = Submission::<S> { retracted: true, ..record("alice", 1) };
+        module.call(import(vec![retracted]), &admin, &mut working_set).unwrap();
+        assert_eq!(module.get_all_submissions(&mut working_set).unwrap().len(), 2);
+        assert_eq!(module.belief_module.get_belief_state(1, &mut working_set).unwrap().total_weight, 100);
+    }
+
+    /// Alice (weight 100_000) and Bob (weight 300_000): Bob holds 75% of total weight
+    fn weight_cap_setup(
+        max_fraction: u64,
+        mode: WeightCapMode,
+        working_set: &mut WorkingSet<S>,
+    ) -> (SubmissionModule<S>, Context<S>) {
+        let mut module = SubmissionModule::<S>::default();
+        module.weight_cap.set(
+            &WeightCapConfig { enabled: true, max_fraction, mode },
+            working_set,
+        ).unwrap();
+        module.belief_module
+            .create_belief("Q1".to_string(), 5000, working_set)
+            .unwrap();
+
+        register_agent(&mut module, "alice", 1000, working_set);
+        let bob = register_agent(&mut module, "bob", 3000, working_set);
+        assert_eq!(module.agent_module.total_registered_weight.get(working_set).unwrap(), Some(400_000));
+
+        (module, bob)
+    }
+
+    #[test]
+    fn test_weight_cap_reject_threshold() {
+        // Just under: 75% share against a 75.01% cap
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, bob) = weight_cap_setup(7501, WeightCapMode::Reject, &mut working_set);
+        assert_eq!(submission_error(&mut module, &bob, 1, &mut working_set), None);
+
+        // Just over: 75% share against a 74.99% cap (299_960)
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, bob) = weight_cap_setup(7499, WeightCapMode::Reject, &mut working_set);
+        assert_eq!(
+            submission_error(&mut module, &bob, 1, &mut working_set),
+            Some(SubmissionError::WeightAboveCap { weight: 300_000, cap: 299_960 })
+        );
+        let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.total_weight, 0);
+    }
+
+    #[test]
+    fn test_weight_cap_scales_down() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, bob) = weight_cap_setup(7499, WeightCapMode::Scale, &mut working_set);
+        assert_eq!(submission_error(&mut module, &bob, 1, &mut working_set), None);
+
+        // Recorded and aggregated with the capped weight
+        let submissions = module.get_submissions(1, &mut working_set).unwrap();
+        assert_eq!(submissions[0].weight, 299_960);
+        let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.total_weight, 299_960);
+
+        // Total registered weight follows stake changes
+        module.agent_module.call(
+            veritas_agent::CallMessage::WithdrawStake { amount: 2000 },
+            &bob,
+            &mut working_set,
+        ).unwrap();
+        assert_eq!(module.agent_module.total_registered_weight.get(&mut working_set).unwrap(), Some(200_000));
+    }
+
+    #[test]
+    fn test_permissioned_belief_admits_only_tier() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+
+        let admin = Context::new(generate_address::<S>("admin"), Default::default(), 1);
+        module.belief_module.admin.set(admin.sender(), &mut working_set).unwrap();
+        module.agent_module.tier_thresholds.set(
+            &TierThresholds { verified_min_score: 150, expert_min_score: 300 },
+            &mut working_set,
+        ).unwrap();
+        module.belief_module.create_belief_with_options(
+            "Experts only".to_string(),
+            5000,
+            BeliefOptions { min_tier: AgentTier::Expert, ..Default::default() },
+            &mut working_set,
+        ).unwrap();
+
+        let novice = register_agent(&mut module, "novice", 1000, &mut working_set);
+        assert_eq!(
+            submission_error(&mut module, &novice, 1, &mut working_set),
+            Some(SubmissionError::TierTooLow { required: AgentTier::Expert, actual: AgentTier::Novice })
+        );
+
+        // Promoted by score, one tier short
+        module.agent_module.score_updater.set(&module.id, &mut working_set).unwrap();
+        module.agent_module.update_score(&module.id, novice.sender().clone(), 60, &mut working_set).unwrap();
+        assert_eq!(
+            submission_error(&mut module, &novice, 1, &mut working_set),
+            Some(SubmissionError::TierTooLow { required: AgentTier::Expert, actual: AgentTier::Verified })
+        );
+
+        // Admin override admits them regardless of score; agents can't pin their own tier
+        let pin = veritas_agent::CallMessage::SetTierOverride {
+            address: novice.sender().clone(),
+            tier: Some(AgentTier::Expert),
+        };
+        assert!(module.agent_module.call(pin.clone(), &novice, &mut working_set).is_err());
+        module.agent_module.call(pin, &admin, &mut working_set).unwrap();
+        assert_eq!(submission_error(&mut module, &novice, 1, &mut working_set), None);
+    }
+
+    #[test]
+    fn test_collusion_ring_flagged_but_independents_not() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.collusion_detection.set(
+            &CollusionDetectionConfig { enabled: true, value_tolerance: 10, min_cluster_size: 3, min_repeats: 2 },
+            &mut working_set,
+        ).unwrap();
+        for question in ["Q1", "Q2"] {
+            module.belief_module
+                .create_belief(question.to_string(), 5000, &mut working_set)
+                .unwrap();
+        }
+
+        let ring: Vec<Context<S>> = ["ring1", "ring2", "ring3"].iter()
+            .map(|name| register_agent(&mut module, name, 1000, &mut working_set))
+            .collect();
+        let independents: Vec<Context<S>> = ["ind1", "ind2", "ind3"].iter()
+            .map(|name| register_agent(&mut module, name, 1000, &mut working_set))
+            .collect();
+        let strikes = |module: &SubmissionModule<S>, agent: &Context<S>, working_set: &mut WorkingSet<S>| {
+            module.collusion_strikes.get(agent.sender(), working_set).unwrap().unwrap_or(0)
+        };
+
+        // Same beliefs, same blocks: the ring agrees to within 10, the others are spread out
+        for (block, belief_id) in [(1, 1), (2, 2)] {
+            set_height(&module.belief_module, block, &mut working_set);
+            for (agent, value) in ring.iter().zip([7000, 7005, 6998]) {
+                module.call(CallMessage::SubmitBelief { belief_id, value }, agent, &mut working_set).unwrap();
+            }
+            for (agent, value) in independents.iter().zip([3000, 5000, 9000]) {
+                module.call(CallMessage::SubmitBelief { belief_id, value }, agent, &mut working_set).unwrap();
+            }
+        }
+
+        // Each ring member has a strike per (belief, block), reaching the review threshold
+        for agent in &ring {
+            assert_eq!(strikes(&module, agent, &mut working_set), 2);
+        }
+        for agent in &independents {
+            assert_eq!(strikes(&module, agent, &mut working_set), 0);
+        }
+
+        // Agreement spread across blocks is not a cluster
+        let late = register_agent(&mut module, "late", 1000, &mut working_set);
+        set_height(&module.belief_module, 3, &mut working_set);
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 7000 }, &late, &mut working_set).unwrap();
+        assert_eq!(strikes(&module, &late, &mut working_set), 0);
+    }
+
+    #[test]
+    fn test_settle_rewards_scores_against_outcome() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let updater = module.id;
+        module.agent_module.score_updater.set(&updater, &mut working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+        let carol = register_agent(&mut module, "carol", 1000, &mut working_set);
+
+        // Alice changes her mind; only her final 9000 counts. Bob hedges, Carol is wrong.
+        for (agent, value) in [(&alice, 6000), (&bob, 5000), (&carol, 2000), (&alice, 9000)] {
+            module.call(
+                CallMessage::SubmitBelief { belief_id: 1, value },
+                agent,
+                &mut working_set,
+            ).unwrap();
+        }
+
+        // Only resolved beliefs can be settled
+        assert!(module.settle_rewards(1, &mut working_set).is_err());
+        module.belief_module.resolve_belief(1, true, &mut working_set).unwrap();
+
+        let score = |module: &SubmissionModule<S>, agent: &Context<S>, ws: &mut WorkingSet<S>| {
+            module.agent_module.agents.get(agent.sender(), ws).unwrap().unwrap().score
+        };
+        let before: Vec<u64> = [&alice, &bob, &carol].iter().map(|a| score(&module, a, &mut working_set)).collect();
+
+        let applied = module.settle_rewards(1, &mut working_set).unwrap();
+        let expected = vec![
+            (alice.sender().clone(), 81),
+            (bob.sender().clone(), 25),
+            (carol.sender().clone(), 4),
+        ];
+        assert_eq!(applied, expected);
+
+        for ((agent, before), (_, delta)) in [&alice, &bob, &carol].iter().zip(&before).zip(&expected) {
+            assert_eq!(score(&module, agent, &mut working_set), before + delta);
+        }
+
+        // Settling twice changes nothing
+        assert!(module.settle_rewards(1, &mut working_set).unwrap().is_empty());
+        assert_eq!(score(&module, &alice, &mut working_set), before[0] + 81);
+    }
+
+    #[test]
+    fn test_settle_rewards_pays_out_pool() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let updater = module.id;
+        module.agent_module.score_updater.set(&updater, &mut working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+
+        let agents: Vec<Context<S>> = ["alice", "bob", "carol"].iter()
+            .map(|name| register_agent(&mut module, name, 1000, &mut working_set))
+            .collect();
+        for (agent, value) in agents.iter().zip([9000, 5000, 2000]) {
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, agent, &mut working_set).unwrap();
+        }
+
+        // The balance FundRewardPool would have recorded after its bank transfer
+        let pool = 1_000_000;
+        module.reward_pools.set(&1, &pool, &mut working_set).unwrap();
+        module.belief_module.resolve_belief(1, true, &mut working_set).unwrap();
+        module.settle_rewards(1, &mut working_set).unwrap();
+
+        let payouts: Vec<u64> = agents.iter()
+            .map(|a| module.rewards_ledger.get(a.sender(), &mut working_set).unwrap().unwrap_or(0))
+            .collect();
+        // Closer to the outcome earns more
+        assert!(payouts[0] > payouts[1] && payouts[1] > payouts[2] && payouts[2] > 0);
+
+        // Everything but the rounding dust (under one unit per submission) is paid out
+        let dust = module.reward_pools.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(payouts.iter().sum::<u64>() + dust, pool);
+        assert!(dust < agents.len() as u64);
+    }
+
+    #[test]
+    fn test_resubmission_overwrites_previous() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+
+        for (agent, value) in [(&alice, 8000), (&bob, 4000), (&alice, 2000)] {
+            module.call(
+                CallMessage::SubmitBelief { belief_id: 1, value },
+                agent,
+                &mut working_set,
+            ).unwrap();
+        }
+
+        // Alice's weight counts once, behind her latest value: (2000 + 4000) / 2
+        let belief = module.belief_module.get_belief_state(1, &mut working_set).unwrap();
+        assert_eq!(belief.total_weight, 2 * 100_000);
+        assert_eq!(belief.submission_count, 2);
+        assert_eq!(belief.aggregate, 3000);
+
+        // Her log entry was replaced in place
+        let values: Vec<u64> = module.get_submissions(1, &mut working_set).unwrap()
+            .iter()
+            .map(|s| s.value)
+            .collect();
+        assert_eq!(values, vec![2000, 4000]);
+        assert!(module.check_belief_integrity(1, &mut working_set).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_retraction_restores_aggregate() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+        let totals = |module: &SubmissionModule<S>, working_set: &mut WorkingSet<S>| {
+            let belief = module.belief_module.get_belief_state(1, working_set).unwrap();
+            (belief.aggregate, belief.total_weight, belief.submission_count)
+        };
+
+        for (agent, value) in [(&alice, 8000), (&bob, 4000)] {
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, agent, &mut working_set).unwrap();
+        }
+        assert_eq!(totals(&module, &mut working_set), (6000, 200_000, 2));
+
+        // Bob's weight drops out: back to Alice's value alone
+        module.call(CallMessage::RetractSubmission { belief_id: 1 }, &bob, &mut working_set).unwrap();
+        assert_eq!(totals(&module, &mut working_set), (8000, 100_000, 1));
+        assert!(module.check_belief_integrity(1, &mut working_set).unwrap().is_none());
+
+        // The log keeps the retracted entry, per-belief queries don't
+        let log = module.get_all_submissions(&mut working_set).unwrap();
+        assert_eq!(log.iter().map(|s| s.retracted).collect::<Vec<_>>(), vec![false, true]);
+        assert_eq!(module.get_submissions(1, &mut working_set).unwrap().len(), 1);
+
+        let err = module.retract_submission(1, &bob, &mut working_set).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SubmissionError>(),
+            Some(&SubmissionError::NothingToRetract { belief_id: 1 })
+        );
+
+        // Retracting the only submission restores the initial value
+        module.call(CallMessage::RetractSubmission { belief_id: 1 }, &alice, &mut working_set).unwrap();
+        assert_eq!(totals(&module, &mut working_set), (5000, 0, 0));
+
+        // A retracted agent can submit afresh
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 3000 }, &bob, &mut working_set).unwrap();
+        assert_eq!(totals(&module, &mut working_set), (3000, 100_000, 1));
+        assert!(module.check_belief_integrity(1, &mut working_set).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rollback_survives_resubmission() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let admin = Context::new(generate_address::<S>("admin"), Default::default(), 1);
+        module.belief_module.admin.set(admin.sender(), &mut working_set).unwrap();
+        module.belief_module.snapshot_config.set(
+            &SnapshotConfig { enabled: true, interval_blocks: 1, max_snapshots: 4 },
+            &mut working_set,
+        ).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+        let submit = |module: &mut SubmissionModule<S>, agent, value, height, working_set: &mut WorkingSet<S>| {
+            set_height(&module.belief_module, height, working_set);
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, agent, working_set).unwrap();
+        };
+        let totals = |module: &SubmissionModule<S>, working_set: &mut WorkingSet<S>| {
+            let belief = module.belief_module.get_belief_state(1, working_set).unwrap();
+            (belief.aggregate, belief.submission_count)
+        };
+
+        // Bob's block-2 submission turns out to be fraudulent
+        submit(&mut module, &alice, 6000, 1, &mut working_set);
+        submit(&mut module, &bob, SCALE, 2, &mut working_set);
+        assert_eq!(totals(&module, &mut working_set), (8000, 2));
+
+        let rollback = CallMessage::RollbackBelief { belief_id: 1, height: 2 };
+        assert!(module.call(rollback.clone(), &alice, &mut working_set).is_err());
+        module.call(rollback, &admin, &mut working_set).unwrap();
+        assert_eq!(totals(&module, &mut working_set), (6000, 1));
+        assert!(module.check_belief_integrity(1, &mut working_set).unwrap().is_none());
+
+        // Bob's entry left the belief, so replays can't bring it back
+        let log = module.get_all_submissions(&mut working_set).unwrap();
+        assert_eq!(log.iter().map(|s| s.retracted).collect::<Vec<_>>(), vec![false, true]);
+        submit(&mut module, &alice, 6000, 3, &mut working_set);
+        assert_eq!(totals(&module, &mut working_set), (6000, 1));
+
+        // Alice's submission counted by the block-3 snapshot was overwritten since
+        let err = module.rollback_belief(1, 3, &admin, &mut working_set).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SubmissionError>(),
+            Some(&SubmissionError::ChangedSinceSnapshot { belief_id: 1, height: 3 })
+        );
+
+        // Settled beliefs stay settled
+        set_height(&module.belief_module, 4, &mut working_set);
+        module.belief_module.resolve_belief(1, true, &mut working_set).unwrap();
+        module.settle_fees(1, &mut working_set).unwrap();
+        let err = module.rollback_belief(1, 4, &admin, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::AlreadySettled { belief_id: 1 }));
+        assert!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().resolved);
+    }
+
+    #[test]
+    fn test_submissions_by_agent() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        for question in ["Q1", "Q2", "Q3"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+
+        for (agent, belief_id, value) in [(&alice, 1, 7000), (&bob, 1, 3000), (&bob, 2, 4000), (&alice, 3, 9000)] {
+            module.call(CallMessage::SubmitBelief { belief_id, value }, agent, &mut working_set).unwrap();
+        }
+
+        let history = |agent: &Context<S>, working_set: &mut WorkingSet<S>| -> Vec<(u64, u64)> {
+            module.get_submissions_by_agent(agent.sender(), working_set).unwrap()
+                .iter()
+                .inspect(|s| assert_eq!(&s.agent, agent.sender()))
+                .map(|s| (s.belief_id, s.value))
+                .collect()
+        };
+        assert_eq!(history(&alice, &mut working_set), vec![(1, 7000), (3, 9000)]);
+        assert_eq!(history(&bob, &mut working_set), vec![(1, 3000), (2, 4000)]);
+
+        let stranger = Context::<S>::new(generate_address::<S>("stranger"), Default::default(), 1);
+        assert!(history(&stranger, &mut working_set).is_empty());
+    }
+
+    #[test]
+    fn test_settlement_slashes_wrong_predictions() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let updater = module.id;
+        module.agent_module.score_updater.set(&updater, &mut working_set).unwrap();
+        module.slashing.set(
+            &SlashingConfig { enabled: true, threshold: 5000, penalty_rate: 2000 },
+            &mut working_set,
+        ).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+        for (agent, value) in [(&alice, 9000), (&bob, 1000)] {
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, agent, &mut working_set).unwrap();
+        }
+        module.belief_module.resolve_belief(1, false, &mut working_set).unwrap();
+
+        let stake = |module: &SubmissionModule<S>, agent: &Context<S>, ws: &mut WorkingSet<S>| {
+            module.agent_module.agents.get(agent.sender(), ws).unwrap().unwrap().stake
+        };
+
+        // Alice was 9000 away from the outcome: 20% of 1000 slashed. Bob was within the threshold.
+        module.settle_rewards(1, &mut working_set).unwrap();
+        assert_eq!(stake(&module, &alice, &mut working_set), 800);
+        assert_eq!(stake(&module, &bob, &mut working_set), 1000);
+        assert_eq!(module.agent_module.slashed_pool.get(&mut working_set).unwrap(), Some(200));
+
+        // Settling again slashes no one twice
+        module.settle_rewards(1, &mut working_set).unwrap();
+        assert_eq!(stake(&module, &alice, &mut working_set), 800);
+
+        // Slashing never takes more than is staked, and only the updater may slash
+        let slashed = module.agent_module.slash_agent(&updater, alice.sender(), 5000, &mut working_set).unwrap();
+        assert_eq!((slashed, stake(&module, &alice, &mut working_set)), (800, 0));
+        assert_eq!(module.agent_module.slashed_pool.get(&mut working_set).unwrap(), Some(1000));
+        let other = module.agent_module.id;
+        assert!(module.agent_module.slash_agent(&other, bob.sender(), 1, &mut working_set).is_err());
+    }
+
+    #[test]
+    fn test_score_curves() {
+        // Bonus at distances 0, 2500, 5000 and 10000 from the reference
+        let cases = [
+            (ScoreCurve::Inverse, [100, 80, 66, 50]),
+            (ScoreCurve::Linear, [100, 75, 50, 0]),
+            // 100 × 0.75² = 56.25, rounded down
+            (ScoreCurve::Quadratic, [100, 56, 25, 0]),
+        ];
+
+        for (curve, expected) in cases {
+            let params = ScoringParams { max_bonus: 100, curve };
+            let deltas = [0, 2500, 5000, 10000].map(|distance| compute_score_delta(distance, 0, &params));
+            assert_eq!(deltas, expected, "{:?}", curve);
+
+            // Symmetric around the reference
+            assert_eq!(compute_score_delta(2500, 5000, &params), compute_score_delta(7500, 5000, &params));
+        }
+
+        // The default is the original curve with a 100 point maximum
+        assert_eq!(ScoringParams::default(), ScoringParams { max_bonus: 100, curve: ScoreCurve::Inverse });
+        let large = ScoringParams { max_bonus: u64::MAX, curve: ScoreCurve::Quadratic };
+        assert_eq!(compute_score_delta(5000, 5000, &large), u64::MAX);
+    }
+
+    #[test]
+    fn test_categorical_submissions() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let outcomes = vec!["Red".to_string(), "Green".to_string(), "Blue".to_string()];
+        let id = module.belief_module.create_categorical_belief("Which?".to_string(), outcomes, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 3000, &mut working_set);
+
+        // Weights 100_000 and 300_000
+        for (agent, distribution) in [(&alice, vec![6000, 3000, 1000]), (&bob, vec![2000, 2000, 6000])] {
+            module.call(CallMessage::SubmitCategorical { belief_id: id, distribution }, agent, &mut working_set).unwrap();
+        }
+        let belief = module.belief_module.categorical_beliefs.get(&id, &mut working_set).unwrap().unwrap();
+        assert_eq!(belief.aggregates, vec![3000, 2250, 4750]);
+        assert_eq!(module.categorical_submissions.len(&mut working_set).unwrap(), 2);
+
+        // One submission per agent; invalid distributions and binary-only IDs are rejected
+        let err = module.submit_categorical(id, vec![0, 0, 10000], &alice, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::AlreadySubmitted { belief_id: id }));
+        let carol = register_agent(&mut module, "carol", 1000, &mut working_set);
+        assert!(module.submit_categorical(id, vec![5000, 5000], &carol, &mut working_set).is_err());
+        let binary = module.belief_module.create_belief("Binary?".to_string(), 5000, &mut working_set).unwrap();
+        let err = module.submit_categorical(binary, vec![5000, 5000], &carol, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::BeliefNotFound { belief_id: binary }));
+        assert_eq!(module.categorical_submissions.len(&mut working_set).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_system_stats() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        for question in ["Q1", "Q2"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 3000, &mut working_set);
+
+        for (agent, belief_id, value) in [(&alice, 1, 7000), (&bob, 1, 3000), (&alice, 1, 6000), (&bob, 2, 4000)] {
+            module.call(CallMessage::SubmitBelief { belief_id, value }, agent, &mut working_set).unwrap();
+        }
+        // Retracting doesn't lower the count
+        module.call(CallMessage::RetractSubmission { belief_id: 2 }, &bob, &mut working_set).unwrap();
+
+        assert_eq!(
+            module.get_system_stats(&mut working_set).unwrap(),
+            SystemStats { total_agents: 2, total_beliefs: 2, total_submissions: 4, total_stake: 4000 }
+        );
+
+        // Withdrawing everything deregisters alice
+        module.agent_module.call(
+            veritas_agent::CallMessage::WithdrawStake { amount: 1000 },
+            &alice,
+            &mut working_set,
+        ).unwrap();
+        let stats = module.get_system_stats(&mut working_set).unwrap();
+        assert_eq!((stats.total_agents, stats.total_stake), (1, 3000));
+    }
+
+    #[test]
+    fn test_zero_weight_errors() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+
+        fn error_for(
+            module: &mut SubmissionModule<S>,
+            agent: &Context<S>,
+            working_set: &mut WorkingSet<S>,
+        ) -> Option<SubmissionError> {
+            let submit = CallMessage::SubmitBelief { belief_id: 1, value: 7000 };
+            let err = module.call(submit, agent, working_set).unwrap_err();
+            err.downcast_ref::<SubmissionError>().cloned()
+        }
+
+        let stranger = Context::<S>::new(generate_address::<S>("stranger"), Default::default(), 1);
+        assert_eq!(error_for(&mut module, &stranger, &mut working_set), Some(SubmissionError::AgentNotRegistered));
+
+        let scoreless = register_agent(&mut module, "scoreless", 1000, &mut working_set);
+        let mut agent = module.agent_module.agents.get(scoreless.sender(), &mut working_set).unwrap().unwrap();
+        agent.score = 0;
+        module.agent_module.agents.set(scoreless.sender(), &agent, &mut working_set).unwrap();
+
+        let stakeless = register_agent(&mut module, "stakeless", 1000, &mut working_set);
+        let mut agent = module.agent_module.agents.get(stakeless.sender(), &mut working_set).unwrap().unwrap();
+        agent.stake = 0;
+        module.agent_module.agents.set(stakeless.sender(), &agent, &mut working_set).unwrap();
+
+        assert_eq!(error_for(&mut module, &scoreless, &mut working_set), Some(SubmissionError::AgentZeroScore));
+        assert_eq!(error_for(&mut module, &stakeless, &mut working_set), Some(SubmissionError::AgentZeroStake));
+
+        // AgentModule reports the same cases with its own error type
+        let weight_error = |address, working_set: &mut WorkingSet<S>| {
+            module.agent_module.get_weight(address, working_set).unwrap_err().downcast_ref::<AgentError>().copied()
+        };
+        assert_eq!(weight_error(stranger.sender(), &mut working_set), Some(AgentError::NotRegistered));
+        assert_eq!(weight_error(scoreless.sender(), &mut working_set), Some(AgentError::ZeroScore));
+        assert_eq!(weight_error(stakeless.sender(), &mut working_set), Some(AgentError::ZeroStake));
+
+        // A normal agent submits fine
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 7000 }, &alice, &mut working_set).unwrap();
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 7000);
+    }
+
+    #[test]
+    fn test_preview_matches_submission() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 3000, &mut working_set);
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 8000 }, &alice, &mut working_set).unwrap();
+
+        // A first submission by bob, then alice overwriting hers
+        for (agent, value) in [(&bob, 2000), (&alice, 6000)] {
+            let preview = module.preview_aggregate(1, value, agent.sender(), &mut working_set).unwrap();
+            // Nothing was written
+            let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+            assert_ne!(belief.aggregate, preview);
+
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, agent, &mut working_set).unwrap();
+            let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+            assert_eq!(belief.aggregate, preview);
+        }
+        // (8000 × 1 + 2000 × 3) / 4 = 3500, then (6000 × 1 + 2000 × 3) / 4 = 3000
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 3000);
+
+        let stranger = generate_address::<S>("stranger");
+        let err = module.preview_aggregate(1, 5000, &stranger, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::AgentNotRegistered));
+    }
+
+    #[test]
+    fn test_agent_accuracy() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        for question in ["Q1", "Q2", "Q3"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        for (belief_id, value) in [(1, 9000), (2, 3000), (3, 5000)] {
+            module.call(CallMessage::SubmitBelief { belief_id, value }, &alice, &mut working_set).unwrap();
+        }
+
+        // Nothing resolved yet
+        assert_eq!(module.get_agent_accuracy(alice.sender(), &mut working_set).unwrap(), None);
+
+        module.belief_module.resolve_belief(1, true, &mut working_set).unwrap();
+        module.belief_module.resolve_belief(2, false, &mut working_set).unwrap();
+
+        // Distances 1000 and 3000; the open belief 3 doesn't count
+        assert_eq!(module.get_agent_accuracy(alice.sender(), &mut working_set).unwrap(), Some(SCALE - 2000));
+
+        let stranger = generate_address::<S>("stranger");
+        assert_eq!(module.get_agent_accuracy(&stranger, &mut working_set).unwrap(), None);
+    }
+
+    #[test]
+    fn test_max_beliefs_per_agent() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.max_beliefs_per_agent.set(&2, &mut working_set).unwrap();
+        for question in ["Q1", "Q2", "Q3"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+
+        fn submit(
+            module: &mut SubmissionModule<S>,
+            belief_id: u64,
+            agent: &Context<S>,
+            working_set: &mut WorkingSet<S>,
+        ) -> anyhow::Result<()> {
+            module.call(CallMessage::SubmitBelief { belief_id, value: 7000 }, agent, working_set)
+        }
+
+        // Up to the cap
+        submit(&mut module, 1, &alice, &mut working_set).unwrap();
+        submit(&mut module, 2, &alice, &mut working_set).unwrap();
+        assert_eq!(module.agent_belief_counts.get(alice.sender(), &mut working_set).unwrap(), Some(2));
+
+        // One more belief is rejected, before touching it
+        let err = submit(&mut module, 3, &alice, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::BeliefLimitReached { limit: 2 }));
+        assert_eq!(module.belief_module.submission_counts.get(&3, &mut working_set).unwrap(), Some(0));
+
+        // Resubmitting to a joined belief is still fine and doesn't count again
+        submit(&mut module, 2, &alice, &mut working_set).unwrap();
+        assert_eq!(module.agent_belief_counts.get(alice.sender(), &mut working_set).unwrap(), Some(2));
+
+        // The cap is per agent
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+        submit(&mut module, 3, &bob, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_max_submissions_per_block() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.max_submissions_per_block.set(&2, &mut working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let submit = |value| CallMessage::SubmitBelief { belief_id: 1, value };
+
+        // Up to the limit within block 0, then one more is rejected
+        module.call(submit(7000), &alice, &mut working_set).unwrap();
+        module.call(submit(7100), &alice, &mut working_set).unwrap();
+        let err = module.call(submit(7200), &alice, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::BlockLimitReached { limit: 2 }));
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 7100);
+
+        // Other agents have their own budget
+        let bob = register_agent(&mut module, "bob", 1000, &mut working_set);
+        module.call(submit(7000), &bob, &mut working_set).unwrap();
+
+        // The next block starts afresh
+        set_height(&module.belief_module, 1, &mut working_set);
+        module.call(submit(7200), &alice, &mut working_set).unwrap();
+    }
+
+    #[test]
+    fn test_recompute_aggregate_within_tolerance() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+
+        // Uneven stakes and values so most updates have to round
+        let mut agents = Vec::new();
+        for i in 0..25u64 {
+            let agent = register_agent(&mut module, &format!("agent{}", i), 1000 + i * 137, &mut working_set);
+            let value = (i * 3217 + 1234) % (SCALE + 1);
+            module.call(CallMessage::SubmitBelief { belief_id: 1, value }, &agent, &mut working_set).unwrap();
+            agents.push(agent);
+        }
+        // Resubmissions and a retraction replay the belief; the audit follows the current set
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 9999 }, &agents[3], &mut working_set).unwrap();
+        module.call(CallMessage::RetractSubmission { belief_id: 1 }, &agents[7], &mut working_set).unwrap();
+
+        let audit = module.recompute_aggregate(1, &mut working_set).unwrap();
+        let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!(audit.stored_aggregate, belief.aggregate);
+        assert_eq!(audit.tolerance, 24);
+        assert!(audit.is_consistent(), "{:?}", audit);
+
+        // Drift beyond rounding is reported
+        let mut drifted = belief.clone();
+        drifted.aggregate -= 25;
+        module.belief_module.beliefs.set(&1, &drifted, &mut working_set).unwrap();
+        assert!(!module.recompute_aggregate(1, &mut working_set).unwrap().is_consistent());
+
+        // Medians aren't running means
+        module.belief_module.aggregation_mode.set(&veritas_belief::AggregationMode::WeightedMedian, &mut working_set).unwrap();
+        let median = module.belief_module.create_belief("Q2".to_string(), 5000, &mut working_set).unwrap();
+        assert!(module.recompute_aggregate(median, &mut working_set).is_err());
+    }
+
+    #[test]
+    fn test_merge_beliefs_combines_weighted_mean() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let admin = register_agent(&mut module, "admin", 1000, &mut working_set);
+        module.belief_module.admin.set(admin.sender(), &mut working_set).unwrap();
+        for question in ["Q1", "Q1 (duplicate)", "Q1 (empty)"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        let carol = register_agent(&mut module, "carol", 2000, &mut working_set);
+        let bob = register_agent(&mut module, "bob", 3000, &mut working_set);
+        let submit = |belief_id, value| CallMessage::SubmitBelief { belief_id, value };
+        module.call(submit(1, 8000), &alice, &mut working_set).unwrap();
+        module.call(submit(1, 2000), &carol, &mut working_set).unwrap();
+        module.call(submit(2, 4000), &bob, &mut working_set).unwrap();
+        module.call(submit(2, 9000), &alice, &mut working_set).unwrap();
+
+        // Admin only, distinct beliefs only
+        let merge = |source_id, dest_id| CallMessage::MergeBeliefs { source_id, dest_id };
+        assert!(module.call(merge(1, 2), &alice, &mut working_set).is_err());
+        assert!(module.call(merge(2, 2), &admin, &mut working_set).is_err());
+
+        // Carol's submission moves; alice keeps her submission to belief 2
+        module.call(merge(1, 2), &admin, &mut working_set).unwrap();
+        let merged = module.get_submissions(2, &mut working_set).unwrap();
+        let values: Vec<u64> = merged.iter().map(|s| s.value).collect();
+        assert_eq!(values, vec![2000, 4000, 9000]);
+        let weighted_sum: u128 = merged.iter().map(|s| s.value as u128 * s.weight as u128).sum();
+        let total_weight: u128 = merged.iter().map(|s| s.weight as u128).sum();
+        let dest = module.belief_module.beliefs.get(&2, &mut working_set).unwrap().unwrap();
+        assert_eq!(dest.total_weight, total_weight);
+        assert_eq!(dest.aggregate, (weighted_sum / total_weight) as u64);
+        assert_eq!(module.belief_module.submission_counts.get(&2, &mut working_set).unwrap(), Some(3));
+
+        // The source is gone, along with its index entries
+        assert!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().is_none());
+        assert!(module.get_submissions(1, &mut working_set).unwrap().is_empty());
+        assert!(module.agent_submissions.get(&(carol.sender().clone(), 1), &mut working_set).unwrap().is_none());
+        assert!(module.agent_submissions.get(&(carol.sender().clone(), 2), &mut working_set).unwrap().is_some());
+
+        // A destination without weight takes the source's aggregate as is
+        module.call(merge(2, 3), &admin, &mut working_set).unwrap();
+        let empty = module.belief_module.beliefs.get(&3, &mut working_set).unwrap().unwrap();
+        assert_eq!(empty.aggregate, dest.aggregate);
+        assert_eq!(empty.total_weight, dest.total_weight);
+
+        // Closed beliefs can't be merged
+        let closed = module.belief_module.create_belief("Q4".to_string(), 5000, &mut working_set).unwrap();
+        module.belief_module.void_belief(closed, &mut working_set).unwrap();
+        assert!(module.call(merge(closed, 3), &admin, &mut working_set).is_err());
+    }
+
+    /// A commit-reveal belief committing until block 10 and revealing until block 20
+    fn commit_reveal_setup(working_set: &mut WorkingSet<S>) -> (SubmissionModule<S>, Context<S>) {
+        let mut module = SubmissionModule::<S>::default();
+        let admin = register_agent(&mut module, "admin", 1000, working_set);
+        module.belief_module.admin.set(admin.sender(), working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, working_set).unwrap();
+        let window = CommitRevealWindow { commit_deadline: 10, reveal_deadline: 20 };
+        module.call(CallMessage::EnableCommitReveal { belief_id: 1, window }, &admin, working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, working_set);
+        (module, alice)
+    }
+
+    #[test]
+    fn test_commit_then_reveal() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, alice) = commit_reveal_setup(&mut working_set);
+        let nonce = [7u8; 32];
+        let commitment = submission_commitment::<S>(8000, &nonce);
+
+        // Plain submissions are refused
+        let err = module.call(CallMessage::SubmitBelief { belief_id: 1, value: 8000 }, &alice, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::CommitRevealRequired { belief_id: 1 }));
+
+        // Committing leaves the aggregate alone, and reveals wait for the commit phase to end
+        module.call(CallMessage::CommitSubmission { belief_id: 1, commitment }, &alice, &mut working_set).unwrap();
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 5000);
+        let reveal = CallMessage::RevealSubmission { belief_id: 1, value: 8000, nonce };
+        let err = module.call(reveal.clone(), &alice, &mut working_set).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SubmissionError>(),
+            Some(&SubmissionError::RevealNotOpen { belief_id: 1, commit_deadline: 10 }),
+        );
+
+        set_height(&module.belief_module, 11, &mut working_set);
+        let late = CallMessage::CommitSubmission { belief_id: 1, commitment };
+        assert!(module.call(late, &alice, &mut working_set).is_err());
+        module.call(reveal.clone(), &alice, &mut working_set).unwrap();
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 8000);
+
+        // The commitment is spent
+        let err = module.call(reveal, &alice, &mut working_set).unwrap_err();
+        assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::NoCommitment { belief_id: 1 }));
+    }
+
+    #[test]
+    fn test_reveal_with_mismatched_value_rejected() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, alice) = commit_reveal_setup(&mut working_set);
+        let nonce = [7u8; 32];
+        let commitment = submission_commitment::<S>(8000, &nonce);
+        module.call(CallMessage::CommitSubmission { belief_id: 1, commitment }, &alice, &mut working_set).unwrap();
+        set_height(&module.belief_module, 11, &mut working_set);
+
+        for (value, nonce) in [(8001, nonce), (8000, [8u8; 32])] {
+            let err = module.call(CallMessage::RevealSubmission { belief_id: 1, value, nonce }, &alice, &mut working_set)
+                .unwrap_err();
+            assert_eq!(err.downcast_ref::<SubmissionError>(), Some(&SubmissionError::CommitmentMismatch { belief_id: 1 }));
+        }
+        let belief = module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap();
+        assert_eq!((belief.aggregate, belief.total_weight), (5000, 0));
+    }
+
+    #[test]
+    fn test_reveal_after_deadline_rejected() {
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        let (mut module, alice) = commit_reveal_setup(&mut working_set);
+        let nonce = [7u8; 32];
+        let commitment = submission_commitment::<S>(8000, &nonce);
+        module.call(CallMessage::CommitSubmission { belief_id: 1, commitment }, &alice, &mut working_set).unwrap();
+
+        set_height(&module.belief_module, 21, &mut working_set);
+        let err = module.call(CallMessage::RevealSubmission { belief_id: 1, value: 8000, nonce }, &alice, &mut working_set)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SubmissionError>(),
+            Some(&SubmissionError::RevealClosed { belief_id: 1, reveal_deadline: 20 }),
+        );
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().total_weight, 0);
+    }
+
+    #[test]
+    fn test_score_cooldown_gates_bonus_only() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.agent_module.score_updater.set(&module.id, &mut working_set).unwrap();
+        module.score_cooldown_blocks.set(&5, &mut working_set).unwrap();
+        for question in ["Q1", "Q2", "Q3"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        fn score(module: &SubmissionModule<S>, agent: &Context<S>, working_set: &mut WorkingSet<S>) -> u64 {
+            module.agent_module.agents.get(agent.sender(), working_set).unwrap().unwrap().score
+        }
+        let submit = |belief_id| CallMessage::SubmitBelief { belief_id, value: 8000 };
+
+        // A lone first submission lands on the aggregate: full bonus
+        module.call(submit(1), &alice, &mut working_set).unwrap();
+        assert_eq!(score(&module, &alice, &mut working_set), 200);
+
+        // Right after, the value still counts but earns nothing
+        set_height(&module.belief_module, 4, &mut working_set);
+        module.call(submit(2), &alice, &mut working_set).unwrap();
+        assert_eq!(module.belief_module.beliefs.get(&2, &mut working_set).unwrap().unwrap().aggregate, 8000);
+        assert_eq!(score(&module, &alice, &mut working_set), 200);
+
+        // Once the cooldown has passed it earns again
+        set_height(&module.belief_module, 5, &mut working_set);
+        module.call(submit(3), &alice, &mut working_set).unwrap();
+        assert_eq!(score(&module, &alice, &mut working_set), 300);
+    }
+
+    #[test]
+    fn test_resubmission_earns_no_bonus() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        module.agent_module.score_updater.set(&module.id, &mut working_set).unwrap();
+        module.belief_module.create_belief("Q1".to_string(), 5000, &mut working_set).unwrap();
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        fn score(module: &SubmissionModule<S>, agent: &Context<S>, working_set: &mut WorkingSet<S>) -> u64 {
+            module.agent_module.agents.get(agent.sender(), working_set).unwrap().unwrap().score
+        }
+
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 8000 }, &alice, &mut working_set).unwrap();
+        assert_eq!(score(&module, &alice, &mut working_set), 200);
+
+        // Overwriting with the same value lands on the aggregate again but earns nothing
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 8000 }, &alice, &mut working_set).unwrap();
+        assert_eq!(score(&module, &alice, &mut working_set), 200);
+
+        // Neither does retracting and submitting again
+        module.call(CallMessage::RetractSubmission { belief_id: 1 }, &alice, &mut working_set).unwrap();
+        module.call(CallMessage::SubmitBelief { belief_id: 1, value: 8000 }, &alice, &mut working_set).unwrap();
+        assert_eq!(score(&module, &alice, &mut working_set), 200);
+        assert_eq!(module.belief_module.beliefs.get(&1, &mut working_set).unwrap().unwrap().aggregate, 8000);
+    }
+
+    #[test]
+    fn test_unvoted_beliefs() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        for question in ["Q1", "Q2", "Q3"] {
+            module.belief_module.create_belief(question.to_string(), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        module.call(CallMessage::SubmitBelief { belief_id: 2, value: 7000 }, &alice, &mut working_set).unwrap();
+
+        let page = module.get_unvoted_beliefs(alice.sender(), 0, 10, &mut working_set).unwrap();
+        assert_eq!(page.belief_ids, vec![1, 3]);
+        assert_eq!(page.total, 3);
+
+        // Closed beliefs aren't offered
+        let closed = module.belief_module.create_belief("Q4".to_string(), 5000, &mut working_set).unwrap();
+        module.belief_module.void_belief(closed, &mut working_set).unwrap();
+        assert_eq!(module.get_unvoted_beliefs(alice.sender(), 0, 10, &mut working_set).unwrap().belief_ids, vec![1, 3]);
+
+        // Pages cover disjoint ID ranges
+        let first = module.get_unvoted_beliefs(alice.sender(), 0, 2, &mut working_set).unwrap();
+        let second = module.get_unvoted_beliefs(alice.sender(), 2, 2, &mut working_set).unwrap();
+        assert_eq!((first.belief_ids, second.belief_ids), (vec![1], vec![3]));
+    }
+
+    #[test]
+    fn test_recent_submissions_newest_first() {
+        let mut module = SubmissionModule::<S>::default();
+        let mut working_set = WorkingSet::<S>::new(Default::default());
+        for i in 1..=4u64 {
+            module.belief_module.create_belief(format!("Q{}", i), 5000, &mut working_set).unwrap();
+        }
+        let alice = register_agent(&mut module, "alice", 1000, &mut working_set);
+        for belief_id in 1..=4u64 {
+            module.call(CallMessage::SubmitBelief { belief_id, value: 1000 * belief_id }, &alice, &mut working_set).unwrap();
+        }
+        let belief_ids = |submissions: Vec<Submission<S>>| -> Vec<u64> {
+            submissions.iter().map(|s| s.belief_id).collect()
+        };
+
+        // The full log is oldest first, recent entries newest first
+        assert_eq!(belief_ids(module.get_all_submissions(&mut working_set).unwrap()), vec![1, 2, 3, 4]);
+        assert_eq!(belief_ids(module.get_recent_submissions(2, &mut working_set).unwrap()), vec![4, 3]);
+        assert_eq!(belief_ids(module.get_recent_submissions(10, &mut working_set).unwrap()), vec![4, 3, 2, 1]);
+        assert!(module.get_recent_submissions(0, &mut working_set).unwrap().is_empty());
+
+        // A resubmission keeps its place in the log
+        module.call(CallMessage::SubmitBelief { belief_id: 2, value: 9000 }, &alice, &mut working_set).unwrap();
+        let recent = module.get_recent_submissions(3, &mut working_set).unwrap();
+        assert_eq!(recent[2].value, 9000);
+        assert_eq!(belief_ids(recent), vec![4, 3, 2]);
+    }
+}
@@ -0,0 +1,998 @@
+use sov_modules_api::Spec;
+use sov_test_utils::{generate_optimistic_runtime, TestSpec};
+use veritas_agent::AgentModule;
+use veritas_belief::BeliefModule;
+use veritas_submission::{SubmissionModule, VeritasGenesisBuilder};
+
+type S = TestSpec;
+
+// A temporary runtime containing only the three Veritas modules.
+generate_optimistic_runtime!(
+    TestRuntime <=
+    veritas_agent: AgentModule<S>,
+    veritas_belief: BeliefModule<S>,
+    veritas_submission: SubmissionModule<S>
+);
+
+use sov_test_utils::runtime::genesis::optimistic::HighLevelOptimisticGenesisConfig;
+use sov_test_utils::runtime::TestRunner;
+use sov_test_utils::TestUser;
+
+// Test users: an admin plus a handful of funded accounts that act as agents.
+pub struct TestData<S: Spec> {
+    pub admin: TestUser<S>,
+    pub agents: Vec<TestUser<S>>,
+}
+
+/// Creates `num_agents + 1` funded users and lets the caller describe the
+/// Veritas genesis state with `VeritasGenesisBuilder` (admin is pre-set).
+pub fn setup(
+    num_agents: usize,
+    configure: impl FnOnce(VeritasGenesisBuilder<S>, &TestData<S>) -> VeritasGenesisBuilder<S>,
+) -> (TestData<S>, TestRunner<TestRuntime<S>, S>) {
+    let genesis_config =
+        HighLevelOptimisticGenesisConfig::generate().add_accounts_with_default_balance(num_agents + 1);
+
+    let mut users = genesis_config.additional_accounts().to_vec();
+    let admin = users.pop().unwrap();
+    let test_data = TestData { admin, agents: users };
+
+    let builder = VeritasGenesisBuilder::<S>::new().with_admin(test_data.admin.address());
+    let veritas = configure(builder, &test_data).build();
+
+    let genesis = GenesisConfig::from_minimal_config(
+        genesis_config.into(),
+        veritas.agent,
+        veritas.belief,
+        veritas.submission,
+    );
+
+    let runner =
+        TestRunner::new_with_genesis(genesis.into_genesis_params(), TestRuntime::default());
+
+    (test_data, runner)
+}
+
+use sov_test_utils::{AsUser, TransactionTestCase};
+use veritas_submission::CallMessage;
+
+#[test]
+fn test_builder_multi_agent_multi_belief() {
+    let (test_data, mut runner) = setup(3, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 500, 100)
+            .with_agent(data.agents[2].address(), 2000, 150)
+            .with_belief("Will ETH exceed $5000?", 5000)
+            .with_belief("Will BTC hit $100k?", 4000)
+    });
+
+    let alice = &test_data.agents[0];
+    let bob = &test_data.agents[1];
+    let carol_address = test_data.agents[2].address();
+
+    // First submission to belief 2 replaces the initial value
+    runner.execute_transaction(TransactionTestCase {
+        input: alice.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 2, value: 8000 },
+        ),
+        assert: Box::new(move |result, state| {
+            assert!(result.tx_receipt.is_successful());
+
+            let belief_module = BeliefModule::<S>::default();
+            let belief = belief_module.beliefs.get(&2, state).unwrap().unwrap();
+            assert_eq!(belief.aggregate, 8000);
+            assert_eq!(belief.total_weight, 100_000);
+
+            // IDs were auto-assigned and belief 1 is untouched
+            let belief = belief_module.beliefs.get(&1, state).unwrap().unwrap();
+            assert_eq!(belief.question, "Will ETH exceed $5000?");
+            assert_eq!(belief.aggregate, 5000);
+            assert_eq!(belief_module.next_belief_id.get(state).unwrap(), Some(3));
+
+            // Genesis agents keep their configured score
+            let carol = AgentModule::<S>::default().agents.get(&carol_address, state).unwrap().unwrap();
+            assert_eq!((carol.stake, carol.score), (2000, 150));
+        }),
+    });
+
+    // Weighted average: (8000 × 100000 + 2000 × 50000) / 150000 = 6000
+    runner.execute_transaction(TransactionTestCase {
+        input: bob.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 2, value: 2000 },
+        ),
+        assert: Box::new(move |result, state| {
+            assert!(result.tx_receipt.is_successful());
+
+            let belief = BeliefModule::<S>::default().beliefs.get(&2, state).unwrap().unwrap();
+            assert_eq!(belief.aggregate, 6000);
+            assert_eq!(belief.total_weight, 150_000);
+        }),
+    });
+}
+
+#[test]
+fn test_resolved_belief_archive_query() {
+    let (test_data, mut runner) = setup(1, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+            .with_belief("Will it snow?", 5000)
+    });
+    let admin = &test_data.admin;
+
+    runner.execute_transaction(TransactionTestCase {
+        input: test_data.agents[0].create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 8000 },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    runner.execute_transaction(TransactionTestCase {
+        input: admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::ResolveBelief { belief_id: 1, outcome: true },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    let expected = veritas_belief::ResolvedBeliefView {
+        id: 1,
+        question: "Will it rain?".to_string(),
+        outcome: Some(veritas_belief::SCALE),
+        voided: false,
+        final_aggregate: 8000,
+        total_weight: 100_000,
+        participant_count: 1,
+        archived: false,
+    };
+
+    // Resolved but still in live state
+    let live = expected.clone();
+    runner.query_visible_state(move |state| {
+        let view = BeliefModule::<S>::default().get_resolved_belief(1, state).unwrap();
+        assert_eq!(view, Some(live));
+    });
+
+    runner.execute_transaction(TransactionTestCase {
+        input: admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::ArchiveBelief { belief_id: 1 },
+        ),
+        assert: Box::new(|result, state| {
+            assert!(result.tx_receipt.is_successful());
+            // Gone from hot state
+            assert!(BeliefModule::<S>::default().beliefs.get(&1, state).unwrap().is_none());
+        }),
+    });
+
+    runner.query_visible_state(move |state| {
+        let belief_module = BeliefModule::<S>::default();
+
+        // Served from the archive with identical final stats
+        let view = belief_module.get_resolved_belief(1, state).unwrap();
+        assert_eq!(view, Some(veritas_belief::ResolvedBeliefView { archived: true, ..expected }));
+
+        // Open belief: exists, not resolved
+        assert_eq!(belief_module.get_resolved_belief(2, state).unwrap(), None);
+
+        // Never existed
+        assert!(belief_module.get_resolved_belief(99, state).is_err());
+    });
+}
+
+#[test]
+fn test_claim_all_rewards_across_beliefs() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+            .with_belief("Will it snow?", 5000)
+    });
+    let admin = &test_data.admin;
+    let alice = &test_data.agents[0];
+    let bob = &test_data.agents[1];
+    let alice_address = alice.address();
+
+    let submit = |user: &TestUser<S>, msg: CallMessage<S>| TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(msg),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    };
+
+    // Alice is right on both beliefs, Bob is wrong on belief 1
+    runner.execute_transaction(submit(alice, CallMessage::SubmitBelief { belief_id: 1, value: 10000 }));
+    runner.execute_transaction(submit(bob, CallMessage::SubmitBelief { belief_id: 1, value: 0 }));
+    runner.execute_transaction(submit(alice, CallMessage::SubmitBelief { belief_id: 2, value: 0 }));
+
+    runner.execute_transaction(submit(admin, CallMessage::FundRewardPool { belief_id: 1, amount: 1_000_000 }));
+    runner.execute_transaction(submit(admin, CallMessage::FundRewardPool { belief_id: 2, amount: 500_000 }));
+
+    for (belief_id, outcome) in [(1, true), (2, false)] {
+        runner.execute_transaction(TransactionTestCase {
+            input: admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+                veritas_belief::CallMessage::ResolveBelief { belief_id, outcome },
+            ),
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+        runner.execute_transaction(submit(bob, CallMessage::DistributeRewards { belief_id }));
+    }
+
+    let ledger_address = alice_address.clone();
+    runner.query_visible_state(move |state| {
+        let module = SubmissionModule::<S>::default();
+        // Both pools fully credited to Alice (Bob's accuracy was 0)
+        assert_eq!(module.rewards_ledger.get(&ledger_address, state).unwrap(), Some(1_500_000));
+        assert_eq!(module.reward_pools.get(&1, state).unwrap(), Some(0));
+        assert_eq!(module.reward_pools.get(&2, state).unwrap(), Some(0));
+    });
+
+    // One transaction sweeps rewards from both beliefs
+    runner.execute_transaction(TransactionTestCase {
+        input: alice.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::ClaimAllRewards,
+        ),
+        assert: Box::new(move |result, state| {
+            assert!(result.tx_receipt.is_successful());
+            let module = SubmissionModule::<S>::default();
+            assert_eq!(module.rewards_ledger.get(&alice_address, state).unwrap(), None);
+        }),
+    });
+
+    // Nothing left to claim
+    for user in [alice, bob] {
+        runner.execute_transaction(TransactionTestCase {
+            input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+                CallMessage::ClaimAllRewards,
+            ),
+            assert: Box::new(|result, _state| assert!(!result.tx_receipt.is_successful())),
+        });
+    }
+}
+
+/// Runs one submission on a fresh chain and returns its recorded timestamp
+fn submission_timestamp_on_fresh_chain() -> u64 {
+    let (test_data, mut runner) = setup(1, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+
+    runner.execute_transaction(TransactionTestCase {
+        input: test_data.agents[0].create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 8000 },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    runner.query_visible_state(move |state| {
+        let submission = SubmissionModule::<S>::default().submissions.get(0, state).unwrap().unwrap();
+        let belief_module = BeliefModule::<S>::default();
+
+        // Read from ChainState: the submission's block is still the latest one
+        assert_eq!(submission.timestamp, belief_module.block_timestamp(state).unwrap());
+        assert_eq!(submission.block_height, belief_module.current_block(state).unwrap());
+
+        sender.send(submission.timestamp).unwrap();
+    });
+    receiver.recv().unwrap()
+}
+
+#[test]
+fn test_submission_timestamp_is_deterministic() {
+    let first = submission_timestamp_on_fresh_chain();
+    let second = submission_timestamp_on_fresh_chain();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_submission_timestamps_increase_across_blocks() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+
+    // Each transaction lands in its own block; note ChainState's time and height after each
+    let mut chain_times = Vec::new();
+    for (user, value) in test_data.agents.iter().zip([6000, 7000]) {
+        runner.execute_transaction(TransactionTestCase {
+            input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+                CallMessage::SubmitBelief { belief_id: 1, value },
+            ),
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        runner.query_visible_state(move |state| {
+            let belief_module = BeliefModule::<S>::default();
+            let time = belief_module.block_timestamp(state).unwrap();
+            let height = belief_module.current_block(state).unwrap();
+            sender.send((time, height)).unwrap();
+        });
+        chain_times.push(receiver.recv().unwrap());
+    }
+
+    runner.query_visible_state(move |state| {
+        let module = SubmissionModule::<S>::default();
+        let recorded: Vec<(u64, u64)> = module
+            .get_all_submissions(state)
+            .unwrap()
+            .iter()
+            .map(|s| (s.timestamp, s.block_height))
+            .collect();
+
+        // Each submission carries its own block's slot time and rollup height
+        assert_eq!(recorded, chain_times);
+        assert!(recorded[1].0 > recorded[0].0);
+        assert!(recorded[1].1 > recorded[0].1);
+
+        // The per-belief view reports the same values
+        let per_belief: Vec<(u64, u64)> = module
+            .get_submissions(1, state)
+            .unwrap()
+            .iter()
+            .map(|s| (s.timestamp, s.block_height))
+            .collect();
+        assert_eq!(per_belief, recorded);
+    });
+}
+
+#[test]
+fn test_submission_fee_rebate_and_forfeit() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_fee(veritas_submission::FeeConfig {
+                submission_fee: 1_000,
+                rebate_threshold: 1_000,
+                destination: veritas_submission::FeeDestination::Rebate,
+            })
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+    let admin = &test_data.admin;
+    let alice_address = test_data.agents[0].address();
+    let bob_address = test_data.agents[1].address();
+
+    // Alice ends up 500 from the outcome (rebated), Bob 8000 (forfeited)
+    for (user, value) in [(&test_data.agents[0], 9500), (&test_data.agents[1], 2000)] {
+        runner.execute_transaction(TransactionTestCase {
+            input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+                CallMessage::SubmitBelief { belief_id: 1, value },
+            ),
+            assert: Box::new(|result, state| {
+                assert!(result.tx_receipt.is_successful());
+                let module = SubmissionModule::<S>::default();
+                let count = module.submissions.len(state).unwrap();
+                assert_eq!(module.submissions.get(count - 1, state).unwrap().unwrap().fee, 1_000);
+            }),
+        });
+    }
+
+    runner.execute_transaction(TransactionTestCase {
+        input: admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::ResolveBelief { belief_id: 1, outcome: true },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    runner.execute_transaction(TransactionTestCase {
+        input: admin.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SettleFees { belief_id: 1 },
+        ),
+        assert: Box::new(move |result, state| {
+            assert!(result.tx_receipt.is_successful());
+            let module = SubmissionModule::<S>::default();
+            assert_eq!(module.rewards_ledger.get(&alice_address, state).unwrap(), Some(1_000));
+            assert_eq!(module.rewards_ledger.get(&bob_address, state).unwrap(), None);
+            assert_eq!(module.reward_pools.get(&1, state).unwrap(), Some(1_000));
+        }),
+    });
+
+    // Settling twice changes nothing
+    runner.execute_transaction(TransactionTestCase {
+        input: admin.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SettleFees { belief_id: 1 },
+        ),
+        assert: Box::new(|result, state| {
+            assert!(result.tx_receipt.is_successful());
+            let module = SubmissionModule::<S>::default();
+            assert_eq!(module.reward_pools.get(&1, state).unwrap(), Some(1_000));
+        }),
+    });
+}
+
+#[test]
+fn test_contribution_matrix_matches_recorded_weights() {
+    let (test_data, mut runner) = setup(3, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 500, 100)
+            .with_agent(data.agents[2].address(), 2000, 100)
+            .with_belief("Q1", 5000)
+            .with_belief("Q2", 5000)
+            .with_belief("Q3", 5000)
+    });
+    let (alice, bob, carol) = (&test_data.agents[0], &test_data.agents[1], &test_data.agents[2]);
+
+    // Overlapping participation; alice's second submission to belief 1 replaces her first
+    for (user, belief_id, value) in [
+        (alice, 1, 7000),
+        (alice, 2, 6000),
+        (bob, 2, 4000),
+        (bob, 3, 3000),
+        (carol, 3, 8000),
+        (alice, 1, 7500),
+    ] {
+        runner.execute_transaction(TransactionTestCase {
+            input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+                CallMessage::SubmitBelief { belief_id, value },
+            ),
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+    }
+
+    // Weights grow with each agent's accuracy bonuses:
+    // alice 100 -> 200 -> 300, bob 100 -> 186 (1600 from consensus)
+    let expected = [
+        (alice.address(), 1, 300_000, 1),
+        (alice.address(), 2, 200_000, 1),
+        (bob.address(), 2, 50_000, 1),
+        (bob.address(), 3, 93_000, 1),
+        (carol.address(), 3, 200_000, 1),
+    ];
+    runner.query_visible_state(move |state| {
+        let module = SubmissionModule::<S>::default();
+        let matrix = module.get_contribution_matrix(&[1, 2, 3], state).unwrap();
+        let cells: Vec<_> = matrix
+            .into_iter()
+            .map(|e| (e.agent, e.belief_id, e.weight, e.submissions))
+            .collect();
+        assert_eq!(cells, expected);
+
+        // A subset only touches the requested beliefs
+        let subset = module.get_contribution_matrix(&[3], state).unwrap();
+        assert!(subset.iter().all(|e| e.belief_id == 3));
+        assert_eq!(subset.len(), 2);
+
+        // Request size is bounded
+        let too_many: Vec<u64> = (1..=veritas_submission::MAX_MATRIX_BELIEFS as u64 + 1).collect();
+        assert!(module.get_contribution_matrix(&too_many, state).is_err());
+    });
+}
+
+#[test]
+fn test_list_beliefs_pages() {
+    let (_test_data, runner) = setup(0, |builder, _data| {
+        ["Q1", "Q2", "Q3", "Q4", "Q5"]
+            .into_iter()
+            .fold(builder, |builder, question| builder.with_belief(question, 5000))
+    });
+
+    runner.query_visible_state(|state| {
+        let module = BeliefModule::<S>::default();
+
+        let first = module.list_beliefs(0, 3, None, None, state).unwrap();
+        let second = module.list_beliefs(3, 3, None, None, state).unwrap();
+        assert_eq!((first.total, second.total), (5, 5));
+
+        let ids = |page: &veritas_belief::BeliefPage| page.beliefs.iter().map(|b| b.id).collect::<Vec<_>>();
+        assert_eq!(ids(&first), vec![1, 2, 3]);
+        assert_eq!(ids(&second), vec![4, 5]);
+        assert_eq!(second.beliefs[1].question, "Q5");
+
+        // Past the end is empty, oversized requests are clamped
+        assert!(module.list_beliefs(5, 3, None, None, state).unwrap().beliefs.is_empty());
+        let all = module.list_beliefs(0, 1_000, None, None, state).unwrap();
+        assert_eq!(all.limit, veritas_belief::MAX_BELIEF_PAGE_SIZE);
+        assert_eq!(all.beliefs.len(), 5);
+    });
+}
+
+#[test]
+fn test_withdrawal_cooldown_spans_blocks() {
+    let (test_data, mut runner) = setup(1, |builder, data| {
+        builder
+            .with_withdrawal_cooldown(3)
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+    let alice = &test_data.agents[0];
+
+    let withdraw = |succeeds: bool| TransactionTestCase {
+        input: alice.create_plain_message::<TestRuntime<S>, AgentModule<S>>(
+            veritas_agent::CallMessage::WithdrawStake { amount: 100 },
+        ),
+        assert: Box::new(move |result, _state| assert_eq!(result.tx_receipt.is_successful(), succeeds)),
+    };
+
+    runner.execute_transaction(TransactionTestCase {
+        input: alice.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 8000 },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    // Next block: still cooling down
+    runner.execute_transaction(withdraw(false));
+
+    // Produce blocks until the cooldown is over
+    for _ in 0..3 {
+        runner.execute_transaction(TransactionTestCase {
+            input: test_data.admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+                veritas_belief::CallMessage::NoOp,
+            ),
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+    }
+    runner.execute_transaction(withdraw(true));
+}
+
+#[test]
+fn test_agent_lifecycle_events() {
+    let (test_data, mut runner) = setup(1, |builder, _data| builder);
+    let user = &test_data.agents[0];
+
+    // Only this module's events: gas handling emits bank events of its own
+    fn agent_events(events: &[TestRuntimeEvent<S>]) -> Vec<veritas_agent::Event<S>> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                TestRuntimeEvent::VeritasAgent(event) => Some(event.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let address = user.address();
+    runner.execute_transaction(TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, AgentModule<S>>(
+            veritas_agent::CallMessage::RegisterAgent { initial_stake: 1000 },
+        ),
+        assert: Box::new(move |result, _state| {
+            assert!(result.tx_receipt.is_successful());
+            assert_eq!(
+                agent_events(&result.events),
+                vec![veritas_agent::Event::AgentRegistered { address, stake: 1000 }]
+            );
+        }),
+    });
+
+    let address = user.address();
+    runner.execute_transaction(TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, AgentModule<S>>(
+            veritas_agent::CallMessage::AddStake { amount: 500 },
+        ),
+        assert: Box::new(move |result, _state| {
+            assert!(result.tx_receipt.is_successful());
+            assert_eq!(
+                agent_events(&result.events),
+                vec![veritas_agent::Event::StakeAdded { address, amount: 500, new_total: 1500 }]
+            );
+        }),
+    });
+}
+
+#[test]
+fn test_initial_score_from_genesis() {
+    let (test_data, mut runner) = setup(1, |builder, _data| {
+        builder.with_initial_score(veritas_agent::InitialScorePolicy::Flat { score: 50 })
+    });
+    let user = &test_data.agents[0];
+
+    runner.execute_transaction(TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, AgentModule<S>>(
+            veritas_agent::CallMessage::RegisterAgent { initial_stake: 1000 },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    let address = user.address();
+    runner.query_visible_state(move |state| {
+        let module = AgentModule::<S>::default();
+        let agent = module.agents.get(&address, state).unwrap().unwrap();
+        assert_eq!(agent.score, 50);
+        assert_eq!(module.get_weight(&address, state).unwrap(), 1000 * 50);
+    });
+}
+
+#[test]
+fn test_aggregate_updated_events() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 3000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+
+    // Emitted under BeliefModule although SubmissionModule made the call
+    fn belief_events(events: &[TestRuntimeEvent<S>]) -> Vec<veritas_belief::Event> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                TestRuntimeEvent::VeritasBelief(event) => Some(event.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let submit = |user: &TestUser<S>, value: u64, expected: veritas_belief::Event| TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value },
+        ),
+        assert: Box::new(move |result, _state| {
+            assert!(result.tx_receipt.is_successful());
+            assert_eq!(belief_events(&result.events), vec![expected]);
+        }),
+    };
+
+    // The first submission replaces the initial value
+    runner.execute_transaction(submit(
+        &test_data.agents[0],
+        8000,
+        veritas_belief::Event::AggregateUpdated {
+            belief_id: 1,
+            old_aggregate: 5000,
+            new_aggregate: 8000,
+            total_weight: 100_000,
+        },
+    ));
+    // (8000 × 100_000 + 2000 × 300_000) / 400_000 = 3500
+    runner.execute_transaction(submit(
+        &test_data.agents[1],
+        2000,
+        veritas_belief::Event::AggregateUpdated {
+            belief_id: 1,
+            old_aggregate: 8000,
+            new_aggregate: 3500,
+            total_weight: 400_000,
+        },
+    ));
+}
+
+#[test]
+fn test_belief_resolved_event_carries_final_state() {
+    let (test_data, mut runner) = setup(3, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 3000, 100)
+            .with_agent(data.agents[2].address(), 2000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+
+    for (agent, value) in test_data.agents.iter().zip([8000, 2000, 6000]) {
+        runner.execute_transaction(TransactionTestCase {
+            input: agent.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+                CallMessage::SubmitBelief { belief_id: 1, value },
+            ),
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+    }
+
+    // (8000 × 100_000 + 2000 × 300_000) / 400_000 = 3500, then
+    // (3500 × 400_000 + 6000 × 200_000) / 600_000 = 4333
+    runner.execute_transaction(TransactionTestCase {
+        input: test_data.admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::ResolveBelief { belief_id: 1, outcome: true },
+        ),
+        assert: Box::new(|result, _state| {
+            assert!(result.tx_receipt.is_successful());
+            let resolved: Vec<_> = result.events.iter()
+                .filter_map(|event| match event {
+                    TestRuntimeEvent::VeritasBelief(event) => Some(event.clone()),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                resolved,
+                vec![veritas_belief::Event::BeliefResolved {
+                    belief_id: 1,
+                    outcome: veritas_belief::SCALE,
+                    final_aggregate: 4333,
+                    total_weight: 600_000,
+                    submission_count: 3,
+                }]
+            );
+        }),
+    });
+}
+
+#[test]
+fn test_submissions_rejected_after_deadline() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief_options(
+                "Will it rain?",
+                5000,
+                veritas_belief::BeliefOptions { deadline_block: Some(2), ..Default::default() },
+            )
+            .with_belief("Open-ended", 5000)
+    });
+    let (alice, bob) = (&test_data.agents[0], &test_data.agents[1]);
+
+    let submit = |user: &TestUser<S>, belief_id, succeeds: bool| TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id, value: 7000 },
+        ),
+        assert: Box::new(move |result, _state| assert_eq!(result.tx_receipt.is_successful(), succeeds)),
+    };
+
+    // Before the deadline
+    runner.execute_transaction(submit(alice, 1, true));
+
+    // Produce blocks past it
+    for _ in 0..3 {
+        runner.execute_transaction(TransactionTestCase {
+            input: test_data.admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+                veritas_belief::CallMessage::NoOp,
+            ),
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+    }
+
+    runner.execute_transaction(submit(bob, 1, false));
+    runner.query_visible_state(|state| {
+        let belief = BeliefModule::<S>::default().beliefs.get(&1, state).unwrap().unwrap();
+        assert_eq!(belief.total_weight, 100_000);
+    });
+
+    // A belief without a deadline never closes
+    runner.execute_transaction(submit(bob, 2, true));
+}
+
+#[test]
+fn test_fees_routed_to_reward_pool() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_fee(veritas_submission::FeeConfig {
+                submission_fee: 1_000,
+                rebate_threshold: 0,
+                destination: veritas_submission::FeeDestination::RewardPool,
+            })
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+
+    for (i, user) in test_data.agents.iter().enumerate() {
+        let expected_pool = 1_000 * (i as u64 + 1);
+        runner.execute_transaction(TransactionTestCase {
+            input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+                CallMessage::SubmitBelief { belief_id: 1, value: 7000 },
+            ),
+            assert: Box::new(move |result, state| {
+                assert!(result.tx_receipt.is_successful());
+                let module = SubmissionModule::<S>::default();
+                assert_eq!(module.reward_pools.get(&1, state).unwrap(), Some(expected_pool));
+                // Nothing is held back for a rebate
+                let count = module.submissions.len(state).unwrap();
+                assert_eq!(module.submissions.get(count - 1, state).unwrap().unwrap().fee, 0);
+            }),
+        });
+    }
+}
+
+#[test]
+fn test_voided_belief_refunds_reward_pool() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_fee(veritas_submission::FeeConfig {
+                submission_fee: 1_000,
+                rebate_threshold: 0,
+                destination: veritas_submission::FeeDestination::RewardPool,
+            })
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will the match be played?", 5000)
+    });
+    let admin = &test_data.admin;
+    let alice = &test_data.agents[0];
+    let bob = &test_data.agents[1];
+
+    let submit = |user: &TestUser<S>, msg: CallMessage<S>| TransactionTestCase {
+        input: user.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(msg),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    };
+
+    // Pool: 300_000 + 200_000 funded, plus Alice's pooled 1_000 fee
+    runner.execute_transaction(submit(alice, CallMessage::SubmitBelief { belief_id: 1, value: 9000 }));
+    runner.execute_transaction(submit(admin, CallMessage::FundRewardPool { belief_id: 1, amount: 300_000 }));
+    runner.execute_transaction(submit(bob, CallMessage::FundRewardPool { belief_id: 1, amount: 200_000 }));
+
+    runner.execute_transaction(TransactionTestCase {
+        input: admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::VoidBelief { belief_id: 1 },
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    // Frozen like a resolved belief
+    runner.execute_transaction(TransactionTestCase {
+        input: bob.create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 1000 },
+        ),
+        assert: Box::new(|result, _state| assert!(!result.tx_receipt.is_successful())),
+    });
+
+    runner.execute_transaction(submit(bob, CallMessage::SettleRewards { belief_id: 1 }));
+
+    let (admin_address, alice_address, bob_address) = (admin.address(), alice.address(), bob.address());
+    runner.query_visible_state(move |state| {
+        let module = SubmissionModule::<S>::default();
+        assert_eq!(module.reward_pools.get(&1, state).unwrap(), Some(0));
+        assert_eq!(module.rewards_ledger.get(&admin_address, state).unwrap(), Some(300_000));
+        assert_eq!(module.rewards_ledger.get(&bob_address, state).unwrap(), Some(200_000));
+        assert_eq!(module.rewards_ledger.get(&alice_address, state).unwrap(), Some(1_000));
+        // No outcome, so no score change or slashing
+        let agent = AgentModule::<S>::default().agents.get(&alice_address, state).unwrap().unwrap();
+        assert_eq!(agent.stake, 1000);
+    });
+}
+
+#[test]
+fn test_unaffordable_fee_reverts_submission() {
+    let (test_data, mut runner) = setup(1, |builder, data| {
+        builder
+            .with_fee(veritas_submission::FeeConfig {
+                submission_fee: u64::MAX,
+                rebate_threshold: 0,
+                destination: veritas_submission::FeeDestination::RewardPool,
+            })
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+    });
+
+    runner.execute_transaction(TransactionTestCase {
+        input: test_data.agents[0].create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 9000 },
+        ),
+        assert: Box::new(|result, state| {
+            assert!(!result.tx_receipt.is_successful());
+            let belief = BeliefModule::<S>::default().beliefs.get(&1, state).unwrap().unwrap();
+            assert_eq!(belief.aggregate, 5000);
+            assert_eq!(belief.total_weight, 0);
+            let module = SubmissionModule::<S>::default();
+            assert_eq!(module.reward_pools.get(&1, state).unwrap(), None);
+            assert_eq!(module.submissions.len(state).unwrap(), 0);
+        }),
+    });
+}
+
+#[test]
+fn test_aggregate_history_per_block() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_aggregate_history(true)
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will it rain?", 5000)
+            .with_belief("Untouched", 4000)
+    });
+
+    // First block: Alice moves belief 1; second: Bob pulls it back; third: no submission
+    let messages = [
+        test_data.agents[0].create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 8000 },
+        ),
+        test_data.agents[1].create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 2000 },
+        ),
+        test_data.admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::NoOp,
+        ),
+    ];
+    let mut heights = Vec::new();
+    for input in messages {
+        runner.execute_transaction(TransactionTestCase {
+            input,
+            assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+        });
+
+        // History is keyed by ChainState's rollup height of each block
+        let (sender, receiver) = std::sync::mpsc::channel();
+        runner.query_visible_state(move |state| {
+            sender.send(BeliefModule::<S>::default().current_block(state).unwrap()).unwrap();
+        });
+        heights.push(receiver.recv().unwrap());
+    }
+
+    runner.query_visible_state(move |state| {
+        let module = BeliefModule::<S>::default();
+        let point = |belief_id, aggregate| veritas_belief::AggregatePoint { belief_id, aggregate };
+        let expected = [
+            vec![point(1, 8000), point(2, 4000)],
+            vec![point(1, 5000), point(2, 4000)],
+            vec![point(1, 5000), point(2, 4000)],
+        ];
+        for (height, points) in heights.iter().zip(expected) {
+            assert_eq!(module.get_aggregate_history(*height, state).unwrap(), Some(points));
+        }
+        assert_eq!(module.get_aggregate_history(heights[2] + 1, state).unwrap(), None);
+    });
+}
+
+#[test]
+fn test_config_matches_genesis() {
+    let initial_score = veritas_agent::InitialScorePolicy::CappedLog { base: 50, per_doubling: 10, max_score: 400 };
+    let scoring = veritas_submission::ScoringParams {
+        max_bonus: 40,
+        curve: veritas_submission::ScoreCurve::Quadratic,
+    };
+    let (_test_data, mut runner) = setup(1, |builder, _data| {
+        builder
+            .with_stake_weighting(veritas_agent::StakeWeighting::Sqrt)
+            .with_initial_score(initial_score.clone())
+            .with_max_score(5000)
+            .with_min_stake(10)
+            .with_aggregation_mode(veritas_belief::AggregationMode::WeightedMedian)
+            .with_scoring(scoring)
+    });
+
+    runner.query_visible_state(|state| {
+        let config = SubmissionModule::<S>::default().get_config(state).unwrap();
+        assert_eq!(
+            config,
+            veritas_submission::VeritasConfig {
+                stake_weighting: veritas_agent::StakeWeighting::Sqrt,
+                ignore_score: false,
+                weight_basis: veritas_submission::WeightBasis::TotalStake,
+                weight_cap: veritas_submission::WeightCapConfig::default(),
+                aggregation_mode: veritas_belief::AggregationMode::WeightedMedian,
+                initial_score,
+                max_score: 5000,
+                min_stake: 10,
+                scoring,
+                score_cooldown_blocks: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn test_genesis_submissions_pass_integrity_sweep() {
+    let (test_data, mut runner) = setup(2, |builder, data| {
+        builder
+            .with_integrity_sweep(veritas_submission::IntegritySweepConfig {
+                enabled: true,
+                beliefs_per_block: 10,
+                auto_pause: true,
+            })
+            .with_agent(data.agents[0].address(), 1000, 100)
+            .with_agent(data.agents[1].address(), 1000, 100)
+            .with_belief("Will it rain?", 8000)
+            .with_submission(data.agents[0].address(), 1, 8000, 100_000)
+    });
+
+    // A full sweep runs at the end of this block
+    runner.execute_transaction(TransactionTestCase {
+        input: test_data.admin.create_plain_message::<TestRuntime<S>, BeliefModule<S>>(
+            veritas_belief::CallMessage::NoOp,
+        ),
+        assert: Box::new(|result, _state| assert!(result.tx_receipt.is_successful())),
+    });
+
+    runner.query_visible_state(|state| {
+        let module = SubmissionModule::<S>::default();
+        assert_eq!(module.paused.get(state).unwrap(), None);
+        assert_eq!(module.integrity_violations.get(&1, state).unwrap(), None);
+
+        let belief = module.belief_module.beliefs.get(&1, state).unwrap().unwrap();
+        assert_eq!((belief.aggregate, belief.total_weight), (8000, 100_000));
+        assert_eq!(module.belief_module.submission_counts.get(&1, state).unwrap(), Some(1));
+    });
+
+    // Still open to submissions, which average with the genesis one
+    runner.execute_transaction(TransactionTestCase {
+        input: test_data.agents[1].create_plain_message::<TestRuntime<S>, SubmissionModule<S>>(
+            CallMessage::SubmitBelief { belief_id: 1, value: 6000 },
+        ),
+        assert: Box::new(|result, state| {
+            assert!(result.tx_receipt.is_successful());
+            let belief = BeliefModule::<S>::default().beliefs.get(&1, state).unwrap().unwrap();
+            assert_eq!((belief.aggregate, belief.total_weight), (7000, 200_000));
+        }),
+    });
+}